@@ -0,0 +1,33 @@
+use std::hint::black_box;
+use std::time::Instant;
+
+use dsp_lib::algorithms::points;
+use dsp_lib::algorithms::soa::Vector2Soa;
+use dsp_lib::algorithms::vector2::Vector2;
+
+const POINTS: usize = 100_000;
+const QUERIES: usize = 200;
+
+fn main() {
+    let aos: Vec<Vector2> = (0..POINTS)
+        .map(|i| Vector2::new(((i * 7919) % 10007) as f32 * 0.1f32, ((i * 104729) % 9973) as f32 * 0.1f32))
+        .collect();
+    let soa = Vector2Soa::from(aos.as_slice());
+    let queries: Vec<Vector2> = (0..QUERIES).map(|i| Vector2::new(i as f32 * 5f32, 1000f32 - i as f32 * 5f32)).collect();
+
+    let start = Instant::now();
+    for &query in &queries {
+        black_box(points::nearest(black_box(&aos), query));
+    }
+    let aos_time = start.elapsed();
+
+    let start = Instant::now();
+    for &query in &queries {
+        black_box(black_box(&soa).nearest(query));
+    }
+    let soa_time = start.elapsed();
+
+    println!("nearest over {} points x {} queries", POINTS, QUERIES);
+    println!("  aos: {:?}", aos_time);
+    println!("  soa: {:?}", soa_time);
+}
@@ -0,0 +1,110 @@
+use super::matrix2x2::Matrix2x2;
+use super::matrix3x2::Matrix3x2;
+use super::vector2::Vector2;
+use super::vector3::Vector3;
+
+/// Equality within an absolute tolerance, compared per component.
+pub trait ApproxEq {
+    fn approx_eq(&self, other: &Self, eps: f32) -> bool;
+}
+
+impl ApproxEq for f32 {
+    fn approx_eq(&self, other: &Self, eps: f32) -> bool {
+        (self - other).abs() <= eps
+    }
+}
+
+impl ApproxEq for Vector2 {
+    fn approx_eq(&self, other: &Self, eps: f32) -> bool {
+        self.x.approx_eq(&other.x, eps) && self.y.approx_eq(&other.y, eps)
+    }
+}
+
+impl ApproxEq for Vector3 {
+    fn approx_eq(&self, other: &Self, eps: f32) -> bool {
+        self.x.approx_eq(&other.x, eps) && self.y.approx_eq(&other.y, eps) && self.z.approx_eq(&other.z, eps)
+    }
+}
+
+impl ApproxEq for Matrix2x2 {
+    fn approx_eq(&self, other: &Self, eps: f32) -> bool {
+        [self.m00 - other.m00, self.m01 - other.m01, self.m10 - other.m10, self.m11 - other.m11].iter().all(|d| d.abs() <= eps)
+    }
+}
+
+impl ApproxEq for Matrix3x2 {
+    fn approx_eq(&self, other: &Self, eps: f32) -> bool {
+        [self.m00 - other.m00, self.m01 - other.m01, self.m02 - other.m02, self.m10 - other.m10, self.m11 - other.m11, self.m12 - other.m12]
+            .iter()
+            .all(|d| d.abs() <= eps)
+    }
+}
+
+/// Per-component equality within `rel_tol` times the larger of the two magnitudes, so the
+/// tolerance grows with the size of the coordinates.
+pub fn approx_eq_relative(a: Vector2, b: Vector2, rel_tol: f32) -> bool {
+    let tolerance = rel_tol * a.magnitude().max(b.magnitude());
+    (a.x - b.x).abs() <= tolerance && (a.y - b.y).abs() <= tolerance
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_f32_approx_eq() {
+        assert!(1f32.approx_eq(&1.05f32, 0.1f32));
+        assert!(!1f32.approx_eq(&1.2f32, 0.1f32));
+        assert!(1f32.approx_eq(&1f32, 0f32));
+        assert!(!f32::NAN.approx_eq(&f32::NAN, 1f32));
+    }
+
+    #[test]
+    fn test_vector2_approx_eq() {
+        let a = Vector2::new(1f32, 2f32);
+        assert!(a.approx_eq(&Vector2::new(1.05f32, 1.95f32), 0.1f32));
+        assert!(!a.approx_eq(&Vector2::new(1.05f32, 2.2f32), 0.1f32));
+        assert!(!a.approx_eq(&Vector2::new(0.8f32, 2f32), 0.1f32));
+    }
+
+    #[test]
+    fn test_approx_eq_relative() {
+        let large = Vector2::new(1E+06f32, -2E+06f32);
+        let large_shifted = large + Vector2::new(1f32, 1f32);
+        assert!(approx_eq_relative(large, large_shifted, 1E-05f32));
+        let small = Vector2::new(1f32, -2f32);
+        let small_shifted = small + Vector2::new(1f32, 1f32);
+        assert!(!approx_eq_relative(small, small_shifted, 1E-05f32));
+        assert!(!large.approx_eq(&large_shifted, 1E-05f32));
+    }
+
+    #[test]
+    fn test_approx_eq_relative_zero() {
+        assert!(approx_eq_relative(Vector2::zero(), Vector2::zero(), 1E-05f32));
+        assert!(!approx_eq_relative(Vector2::zero(), Vector2::new(1E-10f32, 0f32), 1E-05f32));
+    }
+
+    #[test]
+    fn test_vector3_approx_eq() {
+        let a = Vector3::new(1f32, 2f32, 3f32);
+        assert!(a.approx_eq(&Vector3::new(1.01f32, 1.99f32, 3f32), 0.02f32));
+        assert!(!a.approx_eq(&Vector3::new(1f32, 2f32, 3.1f32), 0.02f32));
+    }
+
+    #[test]
+    fn test_matrix2x2_approx_eq() {
+        let m = Matrix2x2::rotation(25f32);
+        let n = Matrix2x2::new(m.m00 + 0.05f32, m.m01, m.m10, m.m11);
+        assert!(m.approx_eq(&n, 0.1f32));
+        assert!(!m.approx_eq(&n, 0.01f32));
+    }
+
+    #[test]
+    fn test_matrix3x2_approx_eq() {
+        let m = Matrix3x2::rotation(10f32);
+        let mut n = m;
+        n.m12 += 0.05f32;
+        assert!(m.approx_eq(&n, 0.1f32));
+        assert!(!m.approx_eq(&n, 0.01f32));
+    }
+}
@@ -0,0 +1,191 @@
+use super::vector2::Vector2;
+
+/// Launch angles in degrees, measured counter-clockwise from +x, for a projectile fired at
+/// `speed` under `gravity` pulling towards -y. Returns the `(low, high)` arcs, or `None`
+/// when the target is out of range.
+pub fn launch_angle(origin: Vector2, target: Vector2, speed: f32, gravity: f32) -> Option<(f32, f32)> {
+    let delta = target - origin;
+    let x = delta.x.abs();
+    let y = delta.y;
+    let v2 = speed * speed;
+    if x == 0f32 {
+        return if y <= 0f32 {
+            Some((-90f32, 90f32))
+        } else if v2 >= 2f32 * gravity * y {
+            Some((90f32, 90f32))
+        } else {
+            None
+        };
+    }
+    if gravity == 0f32 {
+        let angle = delta.y.atan2(delta.x).to_degrees();
+        return Some((angle, angle));
+    }
+    let discriminant = v2 * v2 - gravity * (gravity * x * x + 2f32 * y * v2);
+    if discriminant < 0f32 {
+        return None;
+    }
+    let root = discriminant.sqrt();
+    let low = ((v2 - root) / (gravity * x)).atan().to_degrees();
+    let high = ((v2 + root) / (gravity * x)).atan().to_degrees();
+    if delta.x < 0f32 {
+        Some((180f32 - low, 180f32 - high))
+    } else {
+        Some((low, high))
+    }
+}
+
+/// Aim point for a projectile fired at `projectile_speed` to hit a target moving with constant
+/// velocity, using the earliest non-negative impact time. Returns `None` when the projectile
+/// can never catch the target.
+pub fn intercept_point(shooter: Vector2, projectile_speed: f32, target_pos: Vector2, target_vel: Vector2) -> Option<Vector2> {
+    let offset = target_pos - shooter;
+    let a = target_vel.sqr_magnitude() - projectile_speed * projectile_speed;
+    let b = 2f32 * Vector2::dot(offset, target_vel);
+    let c = offset.sqr_magnitude();
+    if c == 0f32 {
+        return Some(target_pos);
+    }
+    let t = if a.abs() < 1E-06f32 {
+        if b >= 0f32 {
+            return None;
+        }
+        -c / b
+    } else {
+        let discriminant = b * b - 4f32 * a * c;
+        if discriminant < 0f32 {
+            return None;
+        }
+        let root = discriminant.sqrt();
+        let t1 = (-b - root) / (2f32 * a);
+        let t2 = (-b + root) / (2f32 * a);
+        match (t1 >= 0f32, t2 >= 0f32) {
+            (true, true) => t1.min(t2),
+            (true, false) => t1,
+            (false, true) => t2,
+            (false, false) => return None,
+        }
+    };
+    Some(target_pos + target_vel * t)
+}
+
+/// Time at which two points moving with constant velocity are nearest. The result is negative
+/// when they were nearest in the past. Points with equal velocities keep a constant distance;
+/// for those the time is reported as zero.
+pub fn time_of_closest_approach(p1: Vector2, v1: Vector2, p2: Vector2, v2: Vector2) -> f32 {
+    let relative_velocity = v2 - v1;
+    let speed2 = relative_velocity.sqr_magnitude();
+    if speed2 == 0f32 {
+        return 0f32;
+    }
+    -Vector2::dot(p2 - p1, relative_velocity) / speed2
+}
+
+/// Distance between the two points at [`time_of_closest_approach`].
+pub fn closest_approach_distance(p1: Vector2, v1: Vector2, p2: Vector2, v2: Vector2) -> f32 {
+    let t = time_of_closest_approach(p1, v1, p2, v2);
+    ((p2 + v2 * t) - (p1 + v1 * t)).magnitude()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn height_at_target(origin: Vector2, target: Vector2, speed: f32, gravity: f32, angle: f32) -> f32 {
+        let velocity = Vector2::from_angle(angle) * speed;
+        let t = (target.x - origin.x) / velocity.x;
+        origin.y + velocity.y * t - 0.5f32 * gravity * t * t
+    }
+
+    #[test]
+    fn test_reachable_target_has_two_arcs() {
+        let origin = Vector2::new(1f32, 2f32);
+        let speed = 20f32;
+        let gravity = 9.8f32;
+        for target in [Vector2::new(31f32, 7f32), Vector2::new(-19f32, -3f32)] {
+            let (low, high) = launch_angle(origin, target, speed, gravity).unwrap();
+            assert!(low != high);
+            for angle in [low, high] {
+                let y = height_at_target(origin, target, speed, gravity, angle);
+                assert!((y - target.y).abs() < 1E-02f32);
+            }
+        }
+        let (low, high) = launch_angle(origin, Vector2::new(31f32, 7f32), speed, gravity).unwrap();
+        assert!(low < high);
+    }
+
+    #[test]
+    fn test_unreachable_target() {
+        let speed = 10f32;
+        let gravity = 10f32;
+        assert!(launch_angle(Vector2::zero(), Vector2::new(10.5f32, 0f32), speed, gravity).is_none());
+        assert!(launch_angle(Vector2::zero(), Vector2::new(0f32, 6f32), speed, gravity).is_none());
+        let (low, high) = launch_angle(Vector2::zero(), Vector2::new(10f32, 0f32), speed, gravity).unwrap();
+        assert!((low - 45f32).abs() < 1E-02f32);
+        assert!((high - 45f32).abs() < 1E-02f32);
+    }
+
+    #[test]
+    fn test_intercept_stationary_target() {
+        let target = Vector2::new(3f32, 4f32);
+        assert_eq!(intercept_point(Vector2::zero(), 2f32, target, Vector2::zero()), Some(target));
+    }
+
+    #[test]
+    fn test_intercept_crossing_target() {
+        let shooter = Vector2::new(1f32, 1f32);
+        let target = Vector2::new(11f32, 1f32);
+        let velocity = Vector2::new(0f32, 3f32);
+        let speed = 5f32;
+        let aim = intercept_point(shooter, speed, target, velocity).unwrap();
+        assert!(aim.y > target.y);
+        assert!((aim.x - target.x).abs() < 1E-06f32);
+        let target_time = (aim - target).magnitude() / velocity.magnitude();
+        let projectile_time = (aim - shooter).magnitude() / speed;
+        assert!((target_time - projectile_time).abs() < 1E-04f32);
+        assert!((target_time - 2.5f32).abs() < 1E-04f32);
+    }
+
+    #[test]
+    fn test_intercept_too_slow() {
+        let result = intercept_point(Vector2::zero(), 1f32, Vector2::new(5f32, 0f32), Vector2::new(2f32, 0f32));
+        assert_eq!(result, None);
+        let same_speed = intercept_point(Vector2::zero(), 2f32, Vector2::new(5f32, 0f32), Vector2::new(2f32, 0f32));
+        assert_eq!(same_speed, None);
+        let approaching = intercept_point(Vector2::zero(), 2f32, Vector2::new(5f32, 0f32), Vector2::new(-2f32, 0f32));
+        assert_eq!(approaching, Some(Vector2::new(2.5f32, 0f32)));
+    }
+
+    #[test]
+    fn test_closest_approach_converging() {
+        let p1 = Vector2::new(0f32, 0f32);
+        let v1 = Vector2::new(1f32, 0f32);
+        let p2 = Vector2::new(10f32, 2f32);
+        let v2 = Vector2::new(-1f32, 0f32);
+        assert_eq!(time_of_closest_approach(p1, v1, p2, v2), 5f32);
+        assert_eq!(closest_approach_distance(p1, v1, p2, v2), 2f32);
+    }
+
+    #[test]
+    fn test_closest_approach_in_the_past() {
+        let t = time_of_closest_approach(Vector2::zero(), Vector2::zero(), Vector2::new(3f32, 1f32), Vector2::new(1f32, 0f32));
+        assert_eq!(t, -3f32);
+    }
+
+    #[test]
+    fn test_closest_approach_parallel_motion() {
+        let velocity = Vector2::new(2f32, 1f32);
+        let p1 = Vector2::new(0f32, 0f32);
+        let p2 = Vector2::new(3f32, 4f32);
+        assert_eq!(time_of_closest_approach(p1, velocity, p2, velocity), 0f32);
+        assert_eq!(closest_approach_distance(p1, velocity, p2, velocity), 5f32);
+    }
+
+    #[test]
+    fn test_vertical_and_gravity_free_shots() {
+        assert_eq!(launch_angle(Vector2::zero(), Vector2::new(0f32, 4f32), 10f32, 10f32), Some((90f32, 90f32)));
+        let (low, high) = launch_angle(Vector2::zero(), Vector2::new(-1f32, 1f32), 10f32, 0f32).unwrap();
+        assert_eq!(low, high);
+        assert!((low - 135f32).abs() < 1E-04f32);
+    }
+}
@@ -0,0 +1,320 @@
+use super::vector2::Vector2;
+
+/// Point on the cubic bezier with control points `p0..p3` at `t` in `[0, 1]`.
+pub fn cubic_bezier(p0: Vector2, p1: Vector2, p2: Vector2, p3: Vector2, t: f32) -> Vector2 {
+    let u = 1f32 - t;
+    p0 * (u * u * u) + p1 * (3f32 * u * u * t) + p2 * (3f32 * u * t * t) + p3 * (t * t * t)
+}
+
+/// First derivative of [`cubic_bezier`] with respect to `t`.
+pub fn cubic_bezier_derivative(p0: Vector2, p1: Vector2, p2: Vector2, p3: Vector2, t: f32) -> Vector2 {
+    let u = 1f32 - t;
+    (p1 - p0) * (3f32 * u * u) + (p2 - p1) * (6f32 * u * t) + (p3 - p2) * (3f32 * t * t)
+}
+
+/// `samples` points of the curve at even steps of `t`, each moved `distance` along the left
+/// normal; negative distances offset to the right. Where the tangent vanishes (a control point
+/// on its endpoint) the chord direction stands in for it.
+pub fn offset_bezier(p0: Vector2, p1: Vector2, p2: Vector2, p3: Vector2, distance: f32, samples: usize) -> Vec<Vector2> {
+    let steps = samples.saturating_sub(1).max(1) as f32;
+    (0..samples)
+        .map(|i| {
+            let t = i as f32 / steps;
+            let mut tangent = cubic_bezier_derivative(p0, p1, p2, p3, t).normalized();
+            if tangent == Vector2::zero() {
+                tangent = (p3 - p0).normalized();
+            }
+            cubic_bezier(p0, p1, p2, p3, t) + tangent.perpendicular() * distance
+        })
+        .collect()
+}
+
+/// Full circle as `segments` cubic beziers running counter-clockwise from +x. Each arc of
+/// angle `θ` gets handles of length `4/3 · tan(θ/4) · radius` along the tangents.
+pub fn circle_to_beziers(center: Vector2, radius: f32, segments: usize) -> Vec<[Vector2; 4]> {
+    if segments == 0 {
+        return Vec::new();
+    }
+    let step = 360f32 / segments as f32;
+    let handle = 4f32 / 3f32 * (step.to_radians() / 4f32).tan() * radius;
+    (0..segments)
+        .map(|i| {
+            let (start, end) = (Vector2::from_angle(step * i as f32), Vector2::from_angle(step * (i + 1) as f32));
+            let (p0, p3) = (center + start * radius, center + end * radius);
+            [p0, p0 + start.perpendicular() * handle, p3 - end.perpendicular() * handle, p3]
+        })
+        .collect()
+}
+
+fn cubic_bezier_second_derivative(p0: Vector2, p1: Vector2, p2: Vector2, p3: Vector2, t: f32) -> Vector2 {
+    ((p2 - p1 * 2f32 + p0) * (1f32 - t) + (p3 - p2 * 2f32 + p1) * t) * 6f32
+}
+
+/// Newton refinements of the parameterisation tried before splitting a segment.
+const MAX_REPARAMETERIZATIONS: usize = 20;
+
+/// Schneider's cubic bezier fitting: fits one curve to the points by least squares, and until
+/// every point is within `error_tolerance` of it, reparameterises or splits at the worst point.
+/// Returns the curves as `[p0, p1, p2, p3]`, joined end to end with matching tangents at the
+/// joins.
+pub fn fit_bezier(points: &[Vector2], error_tolerance: f32) -> Vec<[Vector2; 4]> {
+    let mut points = points.to_vec();
+    points.dedup();
+    let n = points.len();
+    if n < 2 {
+        return Vec::new();
+    }
+    let start_tangent = end_tangent(&points[..n.min(3)]);
+    let end_tangent = end_tangent(&points[n.saturating_sub(3)..].iter().rev().copied().collect::<Vec<_>>());
+    let mut curves = Vec::new();
+    fit_cubic(&points, start_tangent, end_tangent, error_tolerance * error_tolerance, &mut curves);
+    curves
+}
+
+/// Unit tangent leaving `points[0]` towards the rest. With three points this uses the
+/// second-order one-sided difference, which for evenly spaced samples of a curve is much
+/// closer than the chord.
+fn end_tangent(points: &[Vector2]) -> Vector2 {
+    if points.len() >= 3 {
+        let tangent = (points[1] * 4f32 - points[0] * 3f32 - points[2]).normalized();
+        if tangent != Vector2::zero() {
+            return tangent;
+        }
+    }
+    (points[1] - points[0]).normalized()
+}
+
+fn fit_cubic(points: &[Vector2], start_tangent: Vector2, end_tangent: Vector2, max_sqr_error: f32, curves: &mut Vec<[Vector2; 4]>) {
+    let n = points.len();
+    if n == 2 {
+        let third = (points[1] - points[0]).magnitude() / 3f32;
+        curves.push([points[0], points[0] + start_tangent * third, points[1] + end_tangent * third, points[1]]);
+        return;
+    }
+
+    let mut u = chord_length_parameters(points);
+    let mut curve = generate_bezier(points, &u, start_tangent, end_tangent);
+    let (mut error, mut split) = max_error(points, &curve, &u);
+    if error <= max_sqr_error {
+        curves.push(curve);
+        return;
+    }
+    // Chord-length parameters are only a first guess; refine them while that keeps helping
+    // before resorting to a split.
+    for _ in 0..MAX_REPARAMETERIZATIONS {
+        let refined_u = reparameterize(points, &curve, &u);
+        let refined = generate_bezier(points, &refined_u, start_tangent, end_tangent);
+        let (refined_error, refined_split) = max_error(points, &refined, &refined_u);
+        if refined_error >= error {
+            break;
+        }
+        (u, curve, error, split) = (refined_u, refined, refined_error, refined_split);
+        if error <= max_sqr_error {
+            curves.push(curve);
+            return;
+        }
+    }
+
+    let mut center_tangent = (points[split - 1] - points[split + 1]).normalized();
+    if center_tangent == Vector2::zero() {
+        center_tangent = (points[split] - points[split + 1]).normalized().perpendicular();
+    }
+    fit_cubic(&points[..=split], start_tangent, center_tangent, max_sqr_error, curves);
+    fit_cubic(&points[split..], center_tangent * -1f32, end_tangent, max_sqr_error, curves);
+}
+
+fn chord_length_parameters(points: &[Vector2]) -> Vec<f32> {
+    let mut u = vec![0f32];
+    for w in points.windows(2) {
+        u.push(u[u.len() - 1] + (w[1] - w[0]).magnitude());
+    }
+    let total = u[u.len() - 1];
+    u.iter().map(|&d| d / total).collect()
+}
+
+/// Least-squares handle lengths along the fixed end tangents. Falls back to a third of the
+/// chord when the system is singular or a handle would point backwards.
+fn generate_bezier(points: &[Vector2], u: &[f32], start_tangent: Vector2, end_tangent: Vector2) -> [Vector2; 4] {
+    let (first, last) = (points[0], points[points.len() - 1]);
+    let (mut c00, mut c01, mut c11, mut x0, mut x1) = (0f32, 0f32, 0f32, 0f32, 0f32);
+    for (&point, &t) in points.iter().zip(u) {
+        let s = 1f32 - t;
+        let (b0, b1, b2, b3) = (s * s * s, 3f32 * s * s * t, 3f32 * s * t * t, t * t * t);
+        let (a0, a1) = (start_tangent * b1, end_tangent * b2);
+        c00 += Vector2::dot(a0, a0);
+        c01 += Vector2::dot(a0, a1);
+        c11 += Vector2::dot(a1, a1);
+        let residual = point - (first * (b0 + b1) + last * (b2 + b3));
+        x0 += Vector2::dot(a0, residual);
+        x1 += Vector2::dot(a1, residual);
+    }
+    let determinant = c00 * c11 - c01 * c01;
+    let chord = (last - first).magnitude();
+    let (mut alpha_start, mut alpha_end) = (0f32, 0f32);
+    if determinant.abs() > 1E-12f32 {
+        alpha_start = (x0 * c11 - x1 * c01) / determinant;
+        alpha_end = (c00 * x1 - c01 * x0) / determinant;
+    }
+    let epsilon = 1E-06f32 * chord;
+    if alpha_start < epsilon || alpha_end < epsilon {
+        alpha_start = chord / 3f32;
+        alpha_end = alpha_start;
+    }
+    [first, first + start_tangent * alpha_start, last + end_tangent * alpha_end, last]
+}
+
+/// Largest squared distance between a point and the curve at its parameter, and the interior
+/// index where it occurs.
+fn max_error(points: &[Vector2], curve: &[Vector2; 4], u: &[f32]) -> (f32, usize) {
+    let [p0, p1, p2, p3] = *curve;
+    let mut worst = (0f32, points.len() / 2);
+    for i in 1..points.len() - 1 {
+        let error = (cubic_bezier(p0, p1, p2, p3, u[i]) - points[i]).sqr_magnitude();
+        if error >= worst.0 {
+            worst = (error, i);
+        }
+    }
+    worst
+}
+
+/// One Newton step per point towards the parameter of its closest point on the curve.
+fn reparameterize(points: &[Vector2], curve: &[Vector2; 4], u: &[f32]) -> Vec<f32> {
+    let [p0, p1, p2, p3] = *curve;
+    points
+        .iter()
+        .zip(u)
+        .map(|(&point, &t)| {
+            let offset = cubic_bezier(p0, p1, p2, p3, t) - point;
+            let first = cubic_bezier_derivative(p0, p1, p2, p3, t);
+            let second = cubic_bezier_second_derivative(p0, p1, p2, p3, t);
+            let denominator = Vector2::dot(first, first) + Vector2::dot(offset, second);
+            if denominator.abs() < 1E-12f32 { t } else { (t - Vector2::dot(offset, first) / denominator).clamp(0f32, 1f32) }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cubic_bezier_endpoints_and_derivative() {
+        let (p0, p1, p2, p3) = (Vector2::zero(), Vector2::new(1f32, 2f32), Vector2::new(3f32, 2f32), Vector2::new(4f32, 0f32));
+        assert_eq!(cubic_bezier(p0, p1, p2, p3, 0f32), p0);
+        assert_eq!(cubic_bezier(p0, p1, p2, p3, 1f32), p3);
+        assert_eq!(cubic_bezier(p0, p1, p2, p3, 0.5f32), Vector2::new(2f32, 1.5f32));
+        assert_eq!(cubic_bezier_derivative(p0, p1, p2, p3, 0f32), (p1 - p0) * 3f32);
+        assert_eq!(cubic_bezier_derivative(p0, p1, p2, p3, 1f32), (p3 - p2) * 3f32);
+    }
+
+    #[test]
+    fn test_offset_straight_bezier() {
+        let (p0, p3) = (Vector2::new(1f32, 1f32), Vector2::new(4f32, 5f32));
+        let (p1, p2) = (Vector2::lerp(p0, p3, 0.2f32), Vector2::lerp(p0, p3, 0.9f32));
+        let offset = offset_bezier(p0, p1, p2, p3, 2f32, 9);
+        assert_eq!(offset.len(), 9);
+        let direction = (p3 - p0).normalized();
+        for &point in &offset {
+            // Positive distance lands on the left of the direction of travel.
+            assert!((Vector2::cross(direction, point - p0) - 2f32).abs() < 1E-05f32);
+        }
+        assert!((offset[0] - (p0 + direction.perpendicular() * 2f32)).magnitude() < 1E-05f32);
+        assert!((offset[8] - (p3 + direction.perpendicular() * 2f32)).magnitude() < 1E-05f32);
+    }
+
+    #[test]
+    fn test_offset_curved_bezier_sides() {
+        let (p0, p1, p2, p3) = (Vector2::zero(), Vector2::new(0f32, 3f32), Vector2::new(4f32, 3f32), Vector2::new(4f32, 0f32));
+        let left = offset_bezier(p0, p1, p2, p3, 0.5f32, 16);
+        let right = offset_bezier(p0, p1, p2, p3, -0.5f32, 16);
+        for (i, (l, r)) in left.iter().zip(&right).enumerate() {
+            let t = i as f32 / 15f32;
+            let center = cubic_bezier(p0, p1, p2, p3, t);
+            let tangent = cubic_bezier_derivative(p0, p1, p2, p3, t);
+            assert!(Vector2::cross(tangent, *l - center) > 0f32);
+            assert!(Vector2::cross(tangent, *r - center) < 0f32);
+            assert!(((*l - center).magnitude() - 0.5f32).abs() < 1E-05f32);
+        }
+    }
+
+    #[test]
+    fn test_offset_degenerate_tangent() {
+        let (p0, p3) = (Vector2::zero(), Vector2::new(2f32, 0f32));
+        let offset = offset_bezier(p0, p0, p3, p3, 1f32, 3);
+        assert!((offset[0] - Vector2::new(0f32, 1f32)).magnitude() < 1E-05f32);
+        assert!((offset[2] - Vector2::new(2f32, 1f32)).magnitude() < 1E-05f32);
+    }
+
+    fn max_distance_to_curves(points: &[Vector2], curves: &[[Vector2; 4]]) -> f32 {
+        let samples: Vec<Vector2> = curves.iter().flat_map(|&[p0, p1, p2, p3]| (0..=400).map(move |i| cubic_bezier(p0, p1, p2, p3, i as f32 / 400f32))).collect();
+        points
+            .iter()
+            .map(|&p| samples.iter().map(|&s| (s - p).magnitude()).fold(f32::INFINITY, f32::min))
+            .fold(0f32, f32::max)
+    }
+
+    #[test]
+    fn test_fit_bezier_single_curve() {
+        let (p0, p1, p2, p3) = (Vector2::zero(), Vector2::new(1f32, 3f32), Vector2::new(4f32, 3f32), Vector2::new(5f32, 0f32));
+        let points: Vec<Vector2> = (0..=40).map(|i| cubic_bezier(p0, p1, p2, p3, i as f32 / 40f32)).collect();
+        let curves = fit_bezier(&points, 0.01f32);
+        assert_eq!(curves.len(), 1);
+        assert_eq!(curves[0][0], p0);
+        assert_eq!(curves[0][3], p3);
+        assert!(max_distance_to_curves(&points, &curves) <= 0.01f32);
+    }
+
+    #[test]
+    fn test_fit_bezier_splits_at_corner() {
+        let mut points: Vec<Vector2> = (0..=20).map(|i| Vector2::new(i as f32 * 0.25f32, 0f32)).collect();
+        points.extend((1..=20).map(|i| Vector2::new(5f32, i as f32 * 0.25f32)));
+        let curves = fit_bezier(&points, 0.05f32);
+        assert!(curves.len() >= 2);
+        for w in curves.windows(2) {
+            assert_eq!(w[0][3], w[1][0]);
+        }
+        assert!(max_distance_to_curves(&points, &curves) <= 0.06f32);
+    }
+
+    #[test]
+    fn test_fit_bezier_degenerate() {
+        assert!(fit_bezier(&[Vector2::one()], 0.1f32).is_empty());
+        let curves = fit_bezier(&[Vector2::zero(), Vector2::zero(), Vector2::new(3f32, 0f32)], 0.1f32);
+        assert_eq!(curves, vec![[Vector2::zero(), Vector2::new(1f32, 0f32), Vector2::new(2f32, 0f32), Vector2::new(3f32, 0f32)]]);
+    }
+
+    #[test]
+    fn test_circle_to_beziers_endpoints_and_tangents() {
+        let (center, radius) = (Vector2::new(2f32, -1f32), 3f32);
+        let curves = circle_to_beziers(center, radius, 6);
+        assert_eq!(curves.len(), 6);
+        for (i, &[p0, p1, p2, p3]) in curves.iter().enumerate() {
+            assert!(((p0 - center).magnitude() - radius).abs() < 1E-05f32);
+            assert!(((p3 - center).magnitude() - radius).abs() < 1E-05f32);
+            assert!((p0 - (center + Vector2::from_angle(i as f32 * 60f32) * radius)).magnitude() < 1E-05f32);
+            // Handles are tangent to the circle and point counter-clockwise.
+            assert!(Vector2::dot(p1 - p0, p0 - center).abs() < 1E-04f32);
+            assert!(Vector2::dot(p3 - p2, p3 - center).abs() < 1E-04f32);
+            assert!(Vector2::cross(p0 - center, p1 - p0) > 0f32);
+            assert!(Vector2::cross(p3 - center, p3 - p2) > 0f32);
+        }
+        for w in curves.windows(2) {
+            assert_eq!(w[0][3], w[1][0]);
+        }
+        assert!((curves[5][3] - curves[0][0]).magnitude() < 1E-05f32);
+    }
+
+    #[test]
+    fn test_circle_to_beziers_error_bound() {
+        let curves = circle_to_beziers(Vector2::zero(), 1f32, 4);
+        let handle = curves[0][1] - curves[0][0];
+        assert!((handle.magnitude() - 0.552_284_8f32).abs() < 1E-05f32);
+        let worst = curves
+            .iter()
+            .flat_map(|&[p0, p1, p2, p3]| (0..=100).map(move |i| cubic_bezier(p0, p1, p2, p3, i as f32 / 100f32)))
+            .map(|p| (p.magnitude() - 1f32).abs())
+            .fold(0f32, f32::max);
+        assert!(worst < 3E-04f32);
+        assert!(circle_to_beziers(Vector2::zero(), 1f32, 0).is_empty());
+    }
+}
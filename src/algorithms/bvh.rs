@@ -0,0 +1,244 @@
+use super::ray2d::Ray2D;
+use super::rect::Rect;
+use super::segment::closest_point_on_segment;
+use super::vector2::Vector2;
+
+const LEAF_SIZE: usize = 4;
+
+#[derive(Debug, Clone)]
+struct Node {
+    bounds: Rect,
+    // Leaves own `items[start..start + count]`; internal nodes have `count == 0` and their
+    // children at `left` and `left + 1`.
+    left: usize,
+    start: usize,
+    count: usize,
+}
+
+/// Bounding volume hierarchy over static rects. Queries return the indices of the input
+/// rects whose bounds pass the test.
+#[derive(Debug, Clone)]
+pub struct Bvh2D {
+    nodes: Vec<Node>,
+    items: Vec<usize>,
+    bounds: Vec<Rect>,
+    segments: Vec<(Vector2, Vector2)>,
+}
+
+impl Bvh2D {
+    pub fn new(rects: &[Rect]) -> Self {
+        let mut bvh = Self { nodes: Vec::new(), items: (0..rects.len()).collect(), bounds: rects.to_vec(), segments: Vec::new() };
+        if !rects.is_empty() {
+            bvh.nodes.push(Node { bounds: rects[0], left: 0, start: 0, count: rects.len() });
+            bvh.subdivide(0);
+        }
+        bvh
+    }
+
+    /// Hierarchy over the bounding boxes of line segments, which also supports
+    /// [`Bvh2D::nearest_segment`].
+    pub fn from_segments(segments: &[(Vector2, Vector2)]) -> Self {
+        let rects: Vec<Rect> = segments
+            .iter()
+            .map(|&(a, b)| Rect::min_max(Vector2::combine(a, b, f32::min), Vector2::combine(a, b, f32::max)))
+            .collect();
+        let mut bvh = Self::new(&rects);
+        bvh.segments = segments.to_vec();
+        bvh
+    }
+
+    fn subdivide(&mut self, index: usize) {
+        let (start, count) = (self.nodes[index].start, self.nodes[index].count);
+        let items = &mut self.items[start..start + count];
+        let bounds = items.iter().skip(1).fold(self.bounds[items[0]], |b, &i| b.union(&self.bounds[i]));
+        self.nodes[index].bounds = bounds;
+        if count <= LEAF_SIZE {
+            return;
+        }
+
+        let centers = Rect::from_points(&items.iter().map(|&i| self.bounds[i].center()).collect::<Vec<_>>()).unwrap();
+        let axis = if centers.width >= centers.height { 0 } else { 1 };
+        let half = count / 2;
+        let all_bounds = &self.bounds;
+        items.select_nth_unstable_by(half, |&a, &b| all_bounds[a].center()[axis].total_cmp(&all_bounds[b].center()[axis]));
+
+        let left = self.nodes.len();
+        self.nodes.push(Node { bounds, left: 0, start, count: half });
+        self.nodes.push(Node { bounds, left: 0, start: start + half, count: count - half });
+        self.nodes[index].left = left;
+        self.nodes[index].count = 0;
+        self.subdivide(left);
+        self.subdivide(left + 1);
+    }
+
+    fn query(&self, mut hits_bounds: impl FnMut(&Rect) -> bool) -> Vec<usize> {
+        let mut result = Vec::new();
+        if self.nodes.is_empty() {
+            return result;
+        }
+        let mut stack = vec![0];
+        while let Some(index) = stack.pop() {
+            let node = &self.nodes[index];
+            if !hits_bounds(&node.bounds) {
+                continue;
+            }
+            if node.count == 0 {
+                stack.push(node.left);
+                stack.push(node.left + 1);
+            } else {
+                result.extend(self.items[node.start..node.start + node.count].iter().filter(|&&i| hits_bounds(&self.bounds[i])));
+            }
+        }
+        result
+    }
+
+    /// Items whose bounds the ray passes through.
+    pub fn query_ray(&self, ray: Ray2D) -> Vec<usize> {
+        self.query(|bounds| ray.intersect_rect(bounds).is_some())
+    }
+
+    /// Items whose bounds overlap `rect`.
+    pub fn query_rect(&self, rect: Rect) -> Vec<usize> {
+        self.query(|bounds| bounds.overlaps(&rect))
+    }
+
+    /// Index of the segment closest to `point`, the closest point on it and the distance.
+    /// Always `None` for a hierarchy built from rects.
+    pub fn nearest_segment(&self, point: Vector2) -> Option<(usize, Vector2, f32)> {
+        if self.segments.is_empty() {
+            return None;
+        }
+        let sqr_distance_to = |bounds: &Rect| (bounds.closest_point(point) - point).sqr_magnitude();
+        let mut best: Option<(usize, Vector2, f32)> = None;
+        let mut stack = vec![0];
+        while let Some(index) = stack.pop() {
+            let node = &self.nodes[index];
+            if best.is_some_and(|(_, _, d)| sqr_distance_to(&node.bounds) > d) {
+                continue;
+            }
+            if node.count == 0 {
+                // Visit the nearer child first so the best distance shrinks sooner.
+                let (near, far) = if sqr_distance_to(&self.nodes[node.left].bounds) <= sqr_distance_to(&self.nodes[node.left + 1].bounds) {
+                    (node.left, node.left + 1)
+                } else {
+                    (node.left + 1, node.left)
+                };
+                stack.push(far);
+                stack.push(near);
+                continue;
+            }
+            for &i in &self.items[node.start..node.start + node.count] {
+                let (a, b) = self.segments[i];
+                let closest = closest_point_on_segment(point, a, b);
+                let d = (closest - point).sqr_magnitude();
+                if best.is_none_or(|(best_i, _, best_d)| d < best_d || (d == best_d && i < best_i)) {
+                    best = Some((i, closest, d));
+                }
+            }
+        }
+        best.map(|(i, closest, d)| (i, closest, d.sqrt()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::test_support::Lcg;
+
+    fn random_segments(count: usize, seed: u64) -> Vec<(Vector2, Vector2)> {
+        let mut rng = Lcg(seed);
+        (0..count)
+            .map(|_| {
+                let a = Vector2::new(rng.next() * 100f32, rng.next() * 100f32);
+                let b = a + Vector2::new(rng.next() * 4f32 - 2f32, rng.next() * 4f32 - 2f32);
+                (a, b)
+            })
+            .collect()
+    }
+
+    fn segment_hits_ray(ray: &Ray2D, (a, b): (Vector2, Vector2)) -> bool {
+        let edge = b - a;
+        let denominator = Vector2::cross(ray.direction, edge);
+        if denominator == 0f32 {
+            return false;
+        }
+        let offset = a - ray.origin;
+        let t = Vector2::cross(offset, edge) / denominator;
+        let u = Vector2::cross(offset, ray.direction) / denominator;
+        t >= 0f32 && (0f32..=1f32).contains(&u)
+    }
+
+    #[test]
+    fn test_query_rect_matches_brute_force() {
+        let segments = random_segments(1000, 7);
+        let bvh = Bvh2D::from_segments(&segments);
+        let query = Rect::new(40f32, 40f32, 10f32, 10f32);
+        let mut result = bvh.query_rect(query);
+        result.sort_unstable();
+        let expected: Vec<usize> = segments
+            .iter()
+            .enumerate()
+            .filter(|(_, &(a, b))| query.overlaps(&Rect::from_points(&[a, b]).unwrap()))
+            .map(|(i, _)| i)
+            .collect();
+        assert_eq!(result, expected);
+        assert!(result.len() < segments.len() / 20);
+    }
+
+    #[test]
+    fn test_query_ray_is_superset_of_hits() {
+        let segments = random_segments(1000, 11);
+        let bvh = Bvh2D::from_segments(&segments);
+        let ray = Ray2D::new(Vector2::new(-5f32, 50f32), Vector2::new(1f32, 0.1f32));
+        let candidates = bvh.query_ray(ray);
+        let hits: Vec<usize> = (0..segments.len()).filter(|&i| segment_hits_ray(&ray, segments[i])).collect();
+        assert!(!hits.is_empty());
+        assert!(hits.iter().all(|i| candidates.contains(i)));
+        assert!(candidates.len() < segments.len() / 5);
+    }
+
+    #[test]
+    fn test_rect_bvh() {
+        let rects = [Rect::new(0f32, 0f32, 1f32, 1f32), Rect::new(5f32, 5f32, 1f32, 1f32), Rect::new(0.5f32, 0.5f32, 1f32, 1f32)];
+        let bvh = Bvh2D::new(&rects);
+        let mut result = bvh.query_rect(Rect::new(0.8f32, 0.8f32, 0.1f32, 0.1f32));
+        result.sort_unstable();
+        assert_eq!(result, vec![0, 2]);
+        assert_eq!(bvh.query_ray(Ray2D::new(Vector2::new(5.5f32, 0f32), Vector2::up())), vec![1]);
+    }
+
+    #[test]
+    fn test_nearest_segment_matches_brute_force() {
+        let segments = random_segments(500, 3);
+        let bvh = Bvh2D::from_segments(&segments);
+        let mut rng = Lcg(99);
+        for _ in 0..200 {
+            let query = Vector2::new(rng.next() * 120f32 - 10f32, rng.next() * 120f32 - 10f32);
+            let (index, closest, distance) = bvh.nearest_segment(query).unwrap();
+            let expected = segments
+                .iter()
+                .map(|&(a, b)| (query - closest_point_on_segment(query, a, b)).magnitude())
+                .fold(f32::INFINITY, f32::min);
+            assert!((distance - expected).abs() < 1E-05f32);
+            assert!(((query - closest).magnitude() - distance).abs() < 1E-05f32);
+            let (a, b) = segments[index];
+            assert_eq!(closest, closest_point_on_segment(query, a, b));
+        }
+    }
+
+    #[test]
+    fn test_nearest_segment_without_segments() {
+        let bvh = Bvh2D::new(&[Rect::new(0f32, 0f32, 1f32, 1f32)]);
+        assert_eq!(bvh.nearest_segment(Vector2::zero()), None);
+        assert_eq!(Bvh2D::from_segments(&[]).nearest_segment(Vector2::zero()), None);
+        let single = Bvh2D::from_segments(&[(Vector2::zero(), Vector2::new(2f32, 0f32))]);
+        assert_eq!(single.nearest_segment(Vector2::new(1f32, 3f32)), Some((0, Vector2::new(1f32, 0f32), 3f32)));
+    }
+
+    #[test]
+    fn test_empty_bvh() {
+        let bvh = Bvh2D::new(&[]);
+        assert!(bvh.query_rect(Rect::new(0f32, 0f32, 1f32, 1f32)).is_empty());
+        assert!(bvh.query_ray(Ray2D::new(Vector2::zero(), Vector2::right())).is_empty());
+    }
+}
@@ -0,0 +1,171 @@
+use super::polygon::signed_area;
+use super::rect::Rect;
+use super::vector2::Vector2;
+
+/// Part of `subject` inside the convex polygon `clip` (Sutherland-Hodgman), counter-clockwise
+/// when `subject` is. `clip` may wind either way.
+pub fn clip_convex(subject: &[Vector2], clip: &[Vector2]) -> Vec<Vector2> {
+    let orientation = signed_area(clip).signum();
+    let mut output = subject.to_vec();
+    let n = clip.len();
+    for i in 0..n {
+        if output.is_empty() {
+            break;
+        }
+        let (a, b) = (clip[i], clip[(i + 1) % n]);
+        let side = |p: Vector2| Vector2::cross(b - a, p - a) * orientation;
+        let input = std::mem::take(&mut output);
+        for (k, &current) in input.iter().enumerate() {
+            let previous = input[(k + input.len() - 1) % input.len()];
+            let (side_current, side_previous) = (side(current), side(previous));
+            if (side_current >= 0f32) != (side_previous >= 0f32) {
+                output.push(Vector2::lerp_unclamped(previous, current, side_previous / (side_previous - side_current)));
+            }
+            if side_current >= 0f32 {
+                output.push(current);
+            }
+        }
+    }
+    output
+}
+
+/// Part of `polygon` inside `rect`, keeping the winding of the input. Equivalent to
+/// [`clip_convex`] against the rect but tests each vertex against one coordinate per side.
+pub fn clip_to_rect(polygon: &[Vector2], rect: Rect) -> Vec<Vector2> {
+    let mut output = polygon.to_vec();
+    for (axis, bound, keep_above) in [(0, rect.x_min(), true), (0, rect.x_max(), false), (1, rect.y_min(), true), (1, rect.y_max(), false)] {
+        if output.is_empty() {
+            break;
+        }
+        let inside = |p: Vector2| if keep_above { p[axis] >= bound } else { p[axis] <= bound };
+        let input = std::mem::take(&mut output);
+        for (k, &current) in input.iter().enumerate() {
+            let previous = input[(k + input.len() - 1) % input.len()];
+            if inside(current) != inside(previous) {
+                let t = (bound - previous[axis]) / (current[axis] - previous[axis]);
+                let mut crossing = Vector2::lerp_unclamped(previous, current, t);
+                crossing[axis] = bound;
+                output.push(crossing);
+            }
+            if inside(current) {
+                output.push(current);
+            }
+        }
+    }
+    output
+}
+
+/// Area shared by two convex polygons of either winding.
+pub fn convex_intersection_area(a: &[Vector2], b: &[Vector2]) -> f32 {
+    if a.len() < 3 || b.len() < 3 {
+        return 0f32;
+    }
+    signed_area(&clip_convex(a, b)).abs()
+}
+
+/// Intersection over union of two convex polygons, in `[0, 1]`; zero when the union is empty.
+pub fn iou(a: &[Vector2], b: &[Vector2]) -> f32 {
+    let intersection = convex_intersection_area(a, b);
+    let union = signed_area(a).abs() + signed_area(b).abs() - intersection;
+    if union <= 0f32 {
+        return 0f32;
+    }
+    (intersection / union).clamp(0f32, 1f32)
+}
+
+/// Area covered by exactly one of two convex polygons: their union minus their intersection.
+pub fn symmetric_difference_area(a: &[Vector2], b: &[Vector2]) -> f32 {
+    let intersection = convex_intersection_area(a, b);
+    (signed_area(a).abs() + signed_area(b).abs() - 2f32 * intersection).max(0f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::raster::rasterize_polygon;
+
+    fn square(min: Vector2, size: f32) -> [Vector2; 4] {
+        [min, min + Vector2::new(size, 0f32), min + Vector2::new(size, size), min + Vector2::new(0f32, size)]
+    }
+
+    fn hexagon(center: Vector2, radius: f32) -> Vec<Vector2> { (0..6).map(|i| center + Vector2::from_angle(i as f32 * 60f32) * radius).collect() }
+
+    #[test]
+    fn test_containment_and_disjoint() {
+        let big = square(Vector2::zero(), 10f32);
+        let small = hexagon(Vector2::new(5f32, 5f32), 2f32);
+        let hexagon_area = signed_area(&small);
+        assert!((convex_intersection_area(&big, &small) - hexagon_area).abs() < 1E-04f32);
+        assert!((convex_intersection_area(&small, &big) - hexagon_area).abs() < 1E-04f32);
+        assert_eq!(convex_intersection_area(&big, &square(Vector2::new(20f32, 0f32), 3f32)), 0f32);
+        assert_eq!(convex_intersection_area(&big, &[]), 0f32);
+    }
+
+    #[test]
+    fn test_partial_overlap_matches_grid_reference() {
+        let a = hexagon(Vector2::new(2f32, 2f32), 2f32);
+        let mut b: Vec<Vector2> = square(Vector2::new(2.5f32, 0.5f32), 3f32).to_vec();
+        b.reverse();
+        let area = convex_intersection_area(&a, &b);
+
+        let bounds = Rect::new(0f32, 0f32, 6f32, 6f32);
+        let cell = 0.01f32;
+        let (in_a, in_b) = (rasterize_polygon(&a, bounds, cell), rasterize_polygon(&b, bounds, cell));
+        let shared = in_a.iter().zip(&in_b).map(|(ra, rb)| ra.iter().zip(rb).filter(|(&x, &y)| x && y).count()).sum::<usize>();
+        let reference = shared as f32 * cell * cell;
+        assert!((area - reference).abs() < 0.02f32, "{} vs {}", area, reference);
+        assert!(area > 1f32);
+    }
+
+    #[test]
+    fn test_iou() {
+        let a = square(Vector2::zero(), 2f32);
+        assert!((iou(&a, &a) - 1f32).abs() < 1E-06f32);
+        assert_eq!(iou(&a, &square(Vector2::new(5f32, 5f32), 1f32)), 0f32);
+        // Shifted by half a side: overlap 2, union 6.
+        let half = square(Vector2::new(1f32, 0f32), 2f32);
+        assert!((iou(&a, &half) - 1f32 / 3f32).abs() < 1E-06f32);
+        assert_eq!(iou(&[], &[]), 0f32);
+    }
+
+    #[test]
+    fn test_symmetric_difference_area() {
+        let a = hexagon(Vector2::new(2f32, 2f32), 2f32);
+        assert!(symmetric_difference_area(&a, &a).abs() < 1E-05f32);
+
+        let b = square(Vector2::new(2.5f32, 0.5f32), 3f32);
+        let bounds = Rect::new(0f32, 0f32, 6f32, 6f32);
+        let cell = 0.01f32;
+        let (in_a, in_b) = (rasterize_polygon(&a, bounds, cell), rasterize_polygon(&b, bounds, cell));
+        let count = |keep: fn(bool, bool) -> bool| in_a.iter().zip(&in_b).map(|(ra, rb)| ra.iter().zip(rb).filter(|(&x, &y)| keep(x, y)).count()).sum::<usize>() as f32 * cell * cell;
+        let (union, intersection) = (count(|x, y| x || y), count(|x, y| x && y));
+        assert!((symmetric_difference_area(&a, &b) - (union - intersection)).abs() < 0.05f32);
+        let disjoint = square(Vector2::new(10f32, 10f32), 1f32);
+        assert!((symmetric_difference_area(&a, &disjoint) - (signed_area(&a) + 1f32)).abs() < 1E-05f32);
+    }
+
+    #[test]
+    fn test_clip_to_rect() {
+        let rect = Rect::new(0f32, 0f32, 4f32, 4f32);
+        let straddling = square(Vector2::new(3f32, 1f32), 2f32);
+        let clipped = clip_to_rect(&straddling, rect);
+        assert!((signed_area(&clipped) - 2f32).abs() < 1E-06f32);
+        assert!(clipped.iter().all(|&p| rect.contains(p)));
+        assert!(clipped.contains(&Vector2::new(4f32, 1f32)) && clipped.contains(&Vector2::new(4f32, 3f32)));
+
+        let inside = hexagon(Vector2::new(2f32, 2f32), 1f32);
+        assert_eq!(clip_to_rect(&inside, rect), inside);
+        assert!(clip_to_rect(&square(Vector2::new(10f32, -3f32), 1f32), rect).is_empty());
+    }
+
+    #[test]
+    fn test_clip_to_rect_matches_clip_convex() {
+        let rect = Rect::new(1f32, 0.5f32, 2f32, 3f32);
+        let corners = [rect.min(), Vector2::new(rect.x_max(), rect.y_min()), rect.max(), Vector2::new(rect.x_min(), rect.y_max())];
+        let mut subject = hexagon(Vector2::new(2f32, 2f32), 1.8f32);
+        subject.reverse();
+        let fast = clip_to_rect(&subject, rect);
+        assert!(signed_area(&fast) < 0f32);
+        assert!((signed_area(&fast) - signed_area(&clip_convex(&subject, &corners))).abs() < 1E-05f32);
+    }
+}
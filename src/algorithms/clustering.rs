@@ -0,0 +1,207 @@
+#[cfg(feature = "rand")]
+use rand::{Rng, RngCore};
+
+use super::points::PointGrid;
+use super::vector2::Vector2;
+
+/// Density-based clustering: a point with at least `min_points` points (itself included)
+/// within `epsilon` is a core point, and clusters grow through chains of core points. Returns a
+/// label per point, numbered from zero in order of each cluster's first point, with `-1` for
+/// noise. A border point near several clusters joins the first to reach it. Neighbours come
+/// from the same hash grid as [`dedup_points`](super::points::dedup_points), with
+/// `epsilon`-sized cells.
+pub fn dbscan(points: &[Vector2], epsilon: f32, min_points: usize) -> Vec<i32> {
+    const UNVISITED: i32 = -2;
+    let mut grid = PointGrid::new(epsilon);
+    for (i, &p) in points.iter().enumerate() {
+        grid.insert(i, p);
+    }
+    let max_sqr_distance = epsilon * epsilon;
+    // Sorted so that which cluster claims a shared border point does not depend on the grid.
+    let neighbours = |i: usize| -> Vec<usize> {
+        let mut found: Vec<usize> = grid.candidates(points[i]).filter(|&j| (points[j] - points[i]).sqr_magnitude() <= max_sqr_distance).collect();
+        found.sort_unstable();
+        found
+    };
+
+    let mut labels = vec![UNVISITED; points.len()];
+    let mut cluster = 0;
+    for i in 0..points.len() {
+        if labels[i] != UNVISITED {
+            continue;
+        }
+        let seeds = neighbours(i);
+        if seeds.len() < min_points {
+            labels[i] = -1;
+            continue;
+        }
+        labels[i] = cluster;
+        let mut queue = seeds;
+        while let Some(j) = queue.pop() {
+            if labels[j] == -1 {
+                labels[j] = cluster;
+            }
+            if labels[j] != UNVISITED {
+                continue;
+            }
+            labels[j] = cluster;
+            let reach = neighbours(j);
+            if reach.len() >= min_points {
+                queue.extend(reach);
+            }
+        }
+        cluster += 1;
+    }
+    labels
+}
+
+/// Lloyd's k-means from a k-means++ start, stopping once no point changes cluster or after
+/// `max_iterations` rounds. Returns the centroids and each point's centroid index. `k` is capped
+/// at the number of points, and a centroid that loses all its points stays where it was. With
+/// `k == 0` or no points there are no clusters, so both vectors come back empty.
+#[cfg(feature = "rand")]
+pub fn kmeans(points: &[Vector2], k: usize, max_iterations: u32, rng: &mut impl RngCore) -> (Vec<Vector2>, Vec<usize>) {
+    let k = k.min(points.len());
+    if k == 0 {
+        return (Vec::new(), Vec::new());
+    }
+    let nearest = |centroids: &[Vector2], p: Vector2| {
+        (0..centroids.len()).map(|c| (c, (centroids[c] - p).sqr_magnitude())).min_by(|a, b| a.1.total_cmp(&b.1)).unwrap()
+    };
+    // k-means++: each further centroid is drawn with probability proportional to the squared
+    // distance from the centroids chosen so far.
+    let mut centroids = vec![points[rng.gen_range(0..points.len())]];
+    while centroids.len() < k {
+        let weights: Vec<f32> = points.iter().map(|&p| nearest(&centroids, p).1).collect();
+        let total: f32 = weights.iter().sum();
+        if total <= 0f32 {
+            centroids.push(points[rng.gen_range(0..points.len())]);
+            continue;
+        }
+        let mut target = rng.gen::<f32>() * total;
+        let chosen = weights.iter().position(|&w| {
+            target -= w;
+            target < 0f32
+        });
+        centroids.push(points[chosen.unwrap_or(points.len() - 1)]);
+    }
+
+    let mut assignments: Vec<usize> = points.iter().map(|&p| nearest(&centroids, p).0).collect();
+    for _ in 0..max_iterations {
+        let mut sums = vec![(Vector2::zero(), 0usize); k];
+        for (&p, &c) in points.iter().zip(&assignments) {
+            sums[c] = (sums[c].0 + p, sums[c].1 + 1);
+        }
+        for (centroid, &(sum, count)) in centroids.iter_mut().zip(&sums) {
+            if count > 0 {
+                *centroid = sum / count as f32;
+            }
+        }
+        let next: Vec<usize> = points.iter().map(|&p| nearest(&centroids, p).0).collect();
+        if next == assignments {
+            break;
+        }
+        assignments = next;
+    }
+    (centroids, assignments)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::test_support::Lcg;
+
+    fn blob(rng: &mut Lcg, center: Vector2, count: usize) -> Vec<Vector2> {
+        (0..count).map(|_| center + Vector2::new(rng.next() - 0.5f32, rng.next() - 0.5f32)).collect()
+    }
+
+    /// Textbook DBSCAN with brute-force neighbour search.
+    fn reference(points: &[Vector2], epsilon: f32, min_points: usize) -> Vec<i32> {
+        let neighbours = |i: usize| (0..points.len()).filter(|&j| (points[j] - points[i]).magnitude() <= epsilon).collect::<Vec<usize>>();
+        let mut labels = vec![-2; points.len()];
+        let mut cluster = 0;
+        for i in 0..points.len() {
+            if labels[i] != -2 {
+                continue;
+            }
+            let mut seeds = neighbours(i);
+            if seeds.len() < min_points {
+                labels[i] = -1;
+                continue;
+            }
+            labels[i] = cluster;
+            while let Some(j) = seeds.pop() {
+                if labels[j] == -1 {
+                    labels[j] = cluster;
+                }
+                if labels[j] != -2 {
+                    continue;
+                }
+                labels[j] = cluster;
+                let more = neighbours(j);
+                if more.len() >= min_points {
+                    seeds.extend(more);
+                }
+            }
+            cluster += 1;
+        }
+        labels
+    }
+
+    #[test]
+    fn test_dbscan_two_blobs_and_noise() {
+        let mut rng = Lcg(4);
+        let mut points = blob(&mut rng, Vector2::new(0f32, 0f32), 30);
+        points.extend(blob(&mut rng, Vector2::new(10f32, 3f32), 30));
+        points.push(Vector2::new(5f32, 10f32));
+        points.push(Vector2::new(-6f32, 4f32));
+        let labels = dbscan(&points, 0.5f32, 4);
+        assert!(labels[..30].iter().all(|&l| l == labels[0]));
+        assert!(labels[30..60].iter().all(|&l| l == labels[30]));
+        assert!(labels[0] >= 0 && labels[30] >= 0 && labels[0] != labels[30]);
+        assert_eq!(&labels[60..], &[-1, -1]);
+    }
+
+    #[test]
+    fn test_dbscan_matches_reference() {
+        let mut rng = Lcg(21);
+        let points: Vec<Vector2> = (0..150).map(|_| Vector2::new(rng.next() * 6f32, rng.next() * 6f32)).collect();
+        for (epsilon, min_points) in [(0.4f32, 3), (0.6f32, 5), (0.25f32, 2)] {
+            assert_eq!(dbscan(&points, epsilon, min_points), reference(&points, epsilon, min_points));
+        }
+        assert!(dbscan(&[], 1f32, 2).is_empty());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_kmeans_two_clusters() {
+        use rand::SeedableRng;
+
+        let mut lcg = Lcg(8);
+        let mut points = blob(&mut lcg, Vector2::new(1f32, 1f32), 40);
+        points.extend(blob(&mut lcg, Vector2::new(6f32, -2f32), 25));
+        let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+        let (centroids, assignments) = kmeans(&points, 2, 50, &mut rng);
+        assert_eq!(centroids.len(), 2);
+        assert!(assignments[..40].iter().all(|&a| a == assignments[0]));
+        assert!(assignments[40..].iter().all(|&a| a == assignments[40]));
+        assert_ne!(assignments[0], assignments[40]);
+        let mean = |range: std::ops::Range<usize>| Vector2::centroid(&points[range]).unwrap();
+        assert!((centroids[assignments[0]] - mean(0..40)).magnitude() < 1E-04f32);
+        assert!((centroids[assignments[40]] - mean(40..65)).magnitude() < 1E-04f32);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_kmeans_degenerate() {
+        use rand::SeedableRng;
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(1);
+        let points = [Vector2::one(), Vector2::one(), Vector2::zero()];
+        let (centroids, assignments) = kmeans(&points, 5, 10, &mut rng);
+        assert_eq!(centroids.len(), 3);
+        assert_eq!(assignments[0], assignments[1]);
+        assert_eq!(kmeans(&[], 3, 10, &mut rng), (Vec::new(), Vec::new()));
+        assert_eq!(kmeans(&points, 0, 10, &mut rng), (Vec::new(), Vec::new()));
+    }
+}
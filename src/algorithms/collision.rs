@@ -0,0 +1,154 @@
+use super::polygon::{point_in_polygon, signed_area};
+use super::ray2d::Ray2D;
+use super::segment::{closest_point_on_segment, distance_to_segment};
+use super::vector2::Vector2;
+
+/// Smallest translation separating a circle from the convex `polygon` (either winding), as the
+/// unit direction to move the circle and the depth to move it by. Touching or separate shapes
+/// give `None`.
+pub fn circle_polygon_penetration(center: Vector2, radius: f32, polygon: &[Vector2]) -> Option<(Vector2, f32)> {
+    let n = polygon.len();
+    if n < 3 {
+        return None;
+    }
+    let closest = (0..n)
+        .map(|i| closest_point_on_segment(center, polygon[i], polygon[(i + 1) % n]))
+        .min_by(|a, b| (*a - center).sqr_magnitude().total_cmp(&(*b - center).sqr_magnitude()))?;
+    let offset = center - closest;
+    let distance = offset.magnitude();
+    if distance > 0f32 && !point_in_polygon(center, polygon) {
+        return (distance < radius).then(|| (offset * (1f32 / distance), radius - distance));
+    }
+    // The centre leaves through the nearest edge line; on a convex polygon that is the
+    // separating axis with the least overlap.
+    let orientation = signed_area(polygon).signum();
+    (0..n)
+        .map(|i| {
+            let (a, b) = (polygon[i], polygon[(i + 1) % n]);
+            let direction = (b - a).normalized();
+            let normal = Vector2::new(direction.y, -direction.x) * orientation;
+            (normal, Vector2::dot(a - center, normal) + radius)
+        })
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+}
+
+/// `center` moved out of the convex `polygon` just far enough for the circle to touch its
+/// surface, or unchanged when they do not overlap.
+pub fn push_out_circle(center: Vector2, radius: f32, polygon: &[Vector2]) -> Vector2 {
+    match circle_polygon_penetration(center, radius, polygon) {
+        Some((normal, depth)) => center + normal * depth,
+        None => center,
+    }
+}
+
+/// First fraction of `velocity` in `[0, 1]` at which a circle moving from `center` touches the
+/// convex `polygon` (either winding), or `None` if it stays clear. Starting in contact gives
+/// zero. The circle is swept as a point against the polygon's edges pushed out by `radius` and
+/// circles of `radius` around its vertices.
+pub fn circle_polygon_toi(center: Vector2, velocity: Vector2, radius: f32, polygon: &[Vector2]) -> Option<f32> {
+    let n = polygon.len();
+    if n < 3 {
+        return None;
+    }
+    let touching = (0..n).any(|i| distance_to_segment(center, polygon[i], polygon[(i + 1) % n]) <= radius);
+    if touching || point_in_polygon(center, polygon) {
+        return Some(0f32);
+    }
+    let ray = Ray2D::new(center, velocity);
+    let orientation = signed_area(polygon).signum();
+    let mut first = f32::INFINITY;
+    for i in 0..n {
+        let (a, b) = (polygon[i], polygon[(i + 1) % n]);
+        let direction = (b - a).normalized();
+        let offset = Vector2::new(direction.y, -direction.x) * (orientation * radius);
+        if let Some(t) = ray.intersect_segment(a + offset, b + offset) {
+            first = first.min(t);
+        }
+        if let Some(t) = ray_circle(center, velocity, a, radius) {
+            first = first.min(t);
+        }
+    }
+    (first <= 1f32).then_some(first)
+}
+
+/// Smallest `t >= 0` at which `origin + direction * t` enters the circle, for an origin outside it.
+fn ray_circle(origin: Vector2, direction: Vector2, center: Vector2, radius: f32) -> Option<f32> {
+    let a = direction.sqr_magnitude();
+    if a == 0f32 {
+        return None;
+    }
+    let offset = origin - center;
+    let b = Vector2::dot(offset, direction);
+    let discriminant = b * b - a * (offset.sqr_magnitude() - radius * radius);
+    if discriminant < 0f32 {
+        return None;
+    }
+    let t = (-b - discriminant.sqrt()) / a;
+    (t >= 0f32).then_some(t)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::polygon::distance_to_boundary;
+
+    fn square() -> [Vector2; 4] { [Vector2::zero(), Vector2::new(4f32, 0f32), Vector2::new(4f32, 4f32), Vector2::new(0f32, 4f32)] }
+
+    #[test]
+    fn test_penetration_straddling_edge() {
+        let (normal, depth) = circle_polygon_penetration(Vector2::new(4.5f32, 2f32), 1f32, &square()).unwrap();
+        assert!((normal - Vector2::right()).magnitude() < 1E-06f32);
+        assert!((depth - 0.5f32).abs() < 1E-06f32);
+        // Centre just inside the top edge: push up past the edge plus the radius.
+        let mut clockwise = square();
+        clockwise.reverse();
+        let (normal, depth) = circle_polygon_penetration(Vector2::new(1.5f32, 3.75f32), 0.5f32, &clockwise).unwrap();
+        assert!((normal - Vector2::up()).magnitude() < 1E-06f32);
+        assert!((depth - 0.75f32).abs() < 1E-06f32);
+    }
+
+    #[test]
+    fn test_penetration_corner_and_separate() {
+        let (normal, depth) = circle_polygon_penetration(Vector2::new(4.3f32, 4.4f32), 1f32, &square()).unwrap();
+        assert!((normal - Vector2::new(0.6f32, 0.8f32)).magnitude() < 1E-05f32);
+        assert!((depth - 0.5f32).abs() < 1E-05f32);
+        assert_eq!(circle_polygon_penetration(Vector2::new(6f32, 2f32), 1f32, &square()), None);
+        assert_eq!(circle_polygon_penetration(Vector2::new(5f32, 2f32), 1f32, &square()), None);
+        let (normal, depth) = circle_polygon_penetration(Vector2::new(2f32, 0f32), 1f32, &square()).unwrap();
+        assert_eq!((normal, depth), (Vector2::down(), 1f32));
+    }
+
+    #[test]
+    fn test_push_out_circle() {
+        for center in [Vector2::new(4.5f32, 2f32), Vector2::new(3.2f32, 1f32), Vector2::new(4.3f32, 4.4f32), Vector2::new(2f32, 2.2f32)] {
+            let pushed = push_out_circle(center, 1f32, &square());
+            assert!(!point_in_polygon(pushed, &square()));
+            assert!((distance_to_boundary(pushed, &square()) - 1f32).abs() < 1E-05f32, "{} -> {}", center, pushed);
+        }
+        assert_eq!(push_out_circle(Vector2::new(3.2f32, 1f32), 1f32, &square()), Vector2::new(5f32, 1f32));
+        let clear = Vector2::new(-2f32, 1f32);
+        assert_eq!(push_out_circle(clear, 1f32, &square()), clear);
+    }
+
+    #[test]
+    fn test_toi_head_on() {
+        let toi = circle_polygon_toi(Vector2::new(-3f32, 2f32), Vector2::new(4f32, 0f32), 1f32, &square()).unwrap();
+        assert!((toi - 0.5f32).abs() < 1E-06f32);
+        // Approaching a corner diagonally touches the vertex circle first.
+        let diagonal = Vector2::new(1f32, 1f32);
+        let toi = circle_polygon_toi(Vector2::new(-2f32, -2f32), diagonal * 4f32, 1f32, &square()).unwrap();
+        let expected = (8f32.sqrt() - 1f32) / 32f32.sqrt();
+        assert!((toi - expected).abs() < 1E-05f32);
+        let contact = Vector2::new(-2f32, -2f32) + diagonal * 4f32 * toi;
+        assert!((distance_to_boundary(contact, &square()) - 1f32).abs() < 1E-05f32);
+    }
+
+    #[test]
+    fn test_toi_miss_and_contact() {
+        assert_eq!(circle_polygon_toi(Vector2::new(-3f32, 5.1f32), Vector2::new(10f32, 0f32), 1f32, &square()), None);
+        assert_eq!(circle_polygon_toi(Vector2::new(-3f32, 2f32), Vector2::new(1f32, 0f32), 1f32, &square()), None);
+        assert_eq!(circle_polygon_toi(Vector2::new(-3f32, 2f32), Vector2::new(-4f32, 0f32), 1f32, &square()), None);
+        assert_eq!(circle_polygon_toi(Vector2::new(-1f32, 2f32), Vector2::new(-4f32, 0f32), 1f32, &square()), Some(0f32));
+        assert_eq!(circle_polygon_toi(Vector2::new(2f32, 2f32), Vector2::zero(), 1f32, &square()), Some(0f32));
+    }
+}
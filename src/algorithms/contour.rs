@@ -0,0 +1,259 @@
+use std::collections::{BTreeMap, BinaryHeap};
+
+use super::heap::MinCost;
+use super::polygon::{point_in_polygon, signed_area};
+use super::rect::Rect;
+use super::vector2::Vector2;
+
+/// A crossing point on a grid edge: the lower node's `(x, y)` and whether the edge runs
+/// vertically from it.
+type EdgeKey = (usize, usize, bool);
+
+/// Iso-lines of `field`, indexed `[y][x]`, at level `iso`, with node `(x, y)` at grid
+/// coordinates `(x, y)` and crossings placed by linear interpolation. Values below `iso` count
+/// as inside. Closed loops repeat their first point at the end; lines cut by the border of the
+/// grid are left open. Saddle cells are resolved by the average of their corners.
+pub fn marching_squares(field: &[Vec<f32>], iso: f32) -> Vec<Vec<Vector2>> {
+    let height = field.len();
+    let width = field.first().map_or(0, |row| row.len());
+    let mut links: BTreeMap<EdgeKey, Vec<EdgeKey>> = BTreeMap::new();
+    for y in 0..height.saturating_sub(1) {
+        for x in 0..width.saturating_sub(1) {
+            let corners = [field[y][x], field[y][x + 1], field[y + 1][x + 1], field[y + 1][x]];
+            let inside = corners.map(|v| v < iso);
+            // Edge k runs from corner k to corner k + 1: bottom, right, top, left.
+            let edges: [EdgeKey; 4] = [(x, y, false), (x + 1, y, true), (x, y + 1, false), (x, y, true)];
+            let crossing: Vec<usize> = (0..4).filter(|&k| inside[k] != inside[(k + 1) % 4]).collect();
+            let mut connect = |a: usize, b: usize| {
+                links.entry(edges[a]).or_default().push(edges[b]);
+                links.entry(edges[b]).or_default().push(edges[a]);
+            };
+            match crossing.len() {
+                2 => connect(crossing[0], crossing[1]),
+                4 => {
+                    // Cut off the two corners that disagree with the cell centre; corner k sits
+                    // between edges k - 1 and k.
+                    let center_inside = corners.iter().sum::<f32>() * 0.25f32 < iso;
+                    for k in (0..4).filter(|&k| inside[k] != center_inside) {
+                        connect((k + 3) % 4, k);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let position = |&(x, y, vertical): &EdgeKey| {
+        let (a, b) = if vertical { (field[y][x], field[y + 1][x]) } else { (field[y][x], field[y][x + 1]) };
+        // An infinite end (unreachable, blocked) puts the crossing on the finite one.
+        let t = match (a.is_finite(), b.is_finite()) {
+            (false, true) => 1f32,
+            (true, false) => 0f32,
+            _ if a == b => 0.5f32,
+            _ => ((iso - a) / (b - a)).clamp(0f32, 1f32),
+        };
+        if vertical { Vector2::new(x as f32, y as f32 + t) } else { Vector2::new(x as f32 + t, y as f32) }
+    };
+    let mut lines = Vec::new();
+    // Open lines start at a crossing used by a single cell; whatever is left forms loops.
+    let starts: Vec<EdgeKey> = links.iter().filter(|(_, next)| next.len() == 1).map(|(&key, _)| key).chain(links.keys().copied()).collect();
+    for start in starts {
+        if !links.contains_key(&start) {
+            continue;
+        }
+        let mut line = vec![position(&start)];
+        let mut current = start;
+        while let Some(next) = links.get_mut(&current).and_then(|next| next.pop()) {
+            if let Some(back) = links.get_mut(&next) {
+                if let Some(i) = back.iter().position(|&k| k == current) {
+                    back.swap_remove(i);
+                }
+            }
+            if links[&current].is_empty() {
+                links.remove(&current);
+            }
+            line.push(position(&next));
+            current = next;
+        }
+        links.remove(&current);
+        lines.push(line);
+    }
+    lines
+}
+
+/// Curves at `target_distance` from the nearest of `sites`, traced by [`marching_squares`]
+/// over nearest-site distances sampled every `resolution` across `bounds`. Discs of
+/// overlapping sites merge into one outline. Closed loops repeat their first point; curves
+/// leaving `bounds` are cut open there. A non-positive `resolution` gives no curves.
+pub fn multi_site_distance_contour(sites: &[Vector2], target_distance: f32, bounds: Rect, resolution: f32) -> Vec<Vec<Vector2>> {
+    if resolution <= 0f32 {
+        return Vec::new();
+    }
+    let (width, height) = ((bounds.width / resolution).ceil() as usize + 1, (bounds.height / resolution).ceil() as usize + 1);
+    let node = |x: usize, y: usize| bounds.min() + Vector2::new(x as f32, y as f32) * resolution;
+    let field: Vec<Vec<f32>> = (0..height)
+        .map(|y| {
+            (0..width)
+                .map(|x| sites.iter().map(|&s| (s - node(x, y)).sqr_magnitude()).fold(f32::INFINITY, f32::min).sqrt())
+                .collect()
+        })
+        .collect();
+    marching_squares(&field, target_distance)
+        .into_iter()
+        .map(|line| line.iter().map(|&p| bounds.min() + p * resolution).collect())
+        .collect()
+}
+
+/// Boundary, counter-clockwise, of the area reachable from `start` within `max_distance`
+/// without entering any of the `obstacles` polygons. Travel distances come from Dijkstra over
+/// an 8-connected grid of `grid_resolution` spacing, which cannot cut between two blocked
+/// neighbours diagonally, and the boundary is their iso-line at `max_distance`. Holes around
+/// obstacles inside the area are not returned. A blocked start or a non-positive resolution
+/// gives an empty contour.
+pub fn reachability_contour(start: Vector2, max_distance: f32, obstacles: &[Vec<Vector2>], grid_resolution: f32) -> Vec<Vector2> {
+    if grid_resolution <= 0f32 {
+        return Vec::new();
+    }
+    let margin = max_distance + 2f32 * grid_resolution;
+    let size = (2f32 * margin / grid_resolution).ceil() as usize + 1;
+    let origin = start - Vector2::one() * margin;
+    let node = |x: usize, y: usize| origin + Vector2::new(x as f32, y as f32) * grid_resolution;
+    let open: Vec<bool> = (0..size * size).map(|i| !obstacles.iter().any(|o| point_in_polygon(node(i % size, i / size), o))).collect();
+
+    let mut distances = vec![f32::INFINITY; size * size];
+    let start_cell = (start - origin) / grid_resolution;
+    let (sx, sy) = (start_cell.x.round() as usize, start_cell.y.round() as usize);
+    if !open[sy * size + sx] {
+        return Vec::new();
+    }
+    distances[sy * size + sx] = 0f32;
+    let mut queue = BinaryHeap::from([MinCost { cost: 0f32, item: sy * size + sx }]);
+    while let Some(MinCost { cost, item: index }) = queue.pop() {
+        if cost > distances[index] || cost > margin {
+            continue;
+        }
+        let (x, y) = ((index % size) as i64, (index / size) as i64);
+        for (dx, dy) in [(1, 0), (-1, 0), (0, 1), (0, -1), (1, 1), (1, -1), (-1, 1), (-1, -1)] {
+            let (nx, ny) = (x + dx, y + dy);
+            if nx < 0 || ny < 0 || nx >= size as i64 || ny >= size as i64 {
+                continue;
+            }
+            let is_open = |x: i64, y: i64| open[y as usize * size + x as usize];
+            if !is_open(nx, ny) || (dx != 0 && dy != 0 && !(is_open(nx, y) && is_open(x, ny))) {
+                continue;
+            }
+            let step = if dx != 0 && dy != 0 { std::f32::consts::SQRT_2 } else { 1f32 };
+            let next = ny as usize * size + nx as usize;
+            let cost = cost + step * grid_resolution;
+            if cost < distances[next] {
+                distances[next] = cost;
+                queue.push(MinCost { cost, item: next });
+            }
+        }
+    }
+
+    let field: Vec<Vec<f32>> = distances.chunks(size).map(|row| row.to_vec()).collect();
+    let outer = marching_squares(&field, max_distance)
+        .into_iter()
+        .map(|line| line.iter().map(|&p| origin + p * grid_resolution).collect::<Vec<Vector2>>())
+        .max_by(|a, b| signed_area(a).abs().total_cmp(&signed_area(b).abs()));
+    let Some(mut contour) = outer else { return Vec::new() };
+    if contour.len() > 1 && contour.first() == contour.last() {
+        contour.pop();
+    }
+    if signed_area(&contour) < 0f32 {
+        contour.reverse();
+    }
+    contour
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_marching_squares_single_peak() {
+        let mut field = vec![vec![1f32; 3]; 3];
+        field[1][1] = 0f32;
+        let lines = marching_squares(&field, 0.5f32);
+        assert_eq!(lines.len(), 1);
+        let ring = &lines[0];
+        assert_eq!(ring.len(), 5);
+        assert_eq!(ring.first(), ring.last());
+        for p in [Vector2::new(0.5f32, 1f32), Vector2::new(1.5f32, 1f32), Vector2::new(1f32, 0.5f32), Vector2::new(1f32, 1.5f32)] {
+            assert!(ring.contains(&p));
+        }
+        assert!((signed_area(&ring[..4]).abs() - 0.5f32).abs() < 1E-06f32);
+    }
+
+    #[test]
+    fn test_marching_squares_open_and_saddle() {
+        let ramp: Vec<Vec<f32>> = (0..3).map(|_| (0..4).map(|x| x as f32).collect()).collect();
+        let lines = marching_squares(&ramp, 1.5f32);
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].len(), 3);
+        assert!(lines[0].iter().all(|p| p.x == 1.5f32));
+
+        let saddle = vec![vec![0f32, 1f32], vec![1f32, 0f32]];
+        assert_eq!(marching_squares(&saddle, 0.4f32).len(), 2);
+        let lines = marching_squares(&saddle, 0.6f32);
+        assert_eq!(lines.len(), 2);
+        assert!(lines.iter().all(|line| line.len() == 2));
+        assert!(marching_squares(&[], 0f32).is_empty());
+        let blocked = vec![vec![0f32, f32::INFINITY], vec![0f32, 2f32]];
+        assert!(marching_squares(&blocked, 1f32).iter().flatten().all(|p| p.is_finite()));
+    }
+
+    #[test]
+    fn test_multi_site_distance_contour() {
+        let bounds = Rect::new(-5f32, -5f32, 12f32, 10f32);
+        let site = Vector2::new(1f32, 0.5f32);
+        let lines = multi_site_distance_contour(&[site], 2f32, bounds, 0.1f32);
+        assert_eq!(lines.len(), 1);
+        let circle = &lines[0];
+        assert_eq!(circle.first(), circle.last());
+        assert!(circle.iter().all(|&p| ((p - site).magnitude() - 2f32).abs() < 0.01f32));
+        assert!((signed_area(&circle[1..]).abs() - std::f32::consts::PI * 4f32).abs() < 0.05f32);
+
+        // Sites 3 apart with radius 2 overlap into one outline; 5 apart they stay separate.
+        let merged = multi_site_distance_contour(&[Vector2::zero(), Vector2::new(3f32, 0f32)], 2f32, bounds, 0.1f32);
+        assert_eq!(merged.len(), 1);
+        assert!(merged[0].iter().all(|p| (p.x - 1.5f32).abs() > 0.5f32 || p.y.abs() > 1f32));
+        let apart = multi_site_distance_contour(&[Vector2::zero(), Vector2::new(5f32, 0f32)], 2f32, bounds, 0.1f32);
+        assert_eq!(apart.len(), 2);
+        assert!(multi_site_distance_contour(&[], 2f32, bounds, 0.5f32).is_empty());
+        assert!(multi_site_distance_contour(&[site], 2f32, bounds, 0f32).is_empty());
+        assert!(multi_site_distance_contour(&[site], 2f32, bounds, -1f32).is_empty());
+    }
+
+    #[test]
+    fn test_reachability_open_area_is_round() {
+        let start = Vector2::new(1f32, 2f32);
+        let contour = reachability_contour(start, 5f32, &[], 0.25f32);
+        assert!(signed_area(&contour) > 0f32);
+        for &p in &contour {
+            let r = (p - start).magnitude();
+            // Octile distance sits between the true radius and about 8% inside it.
+            assert!((4.6f32..=5.01f32).contains(&r), "{}", r);
+        }
+        let area = signed_area(&contour);
+        assert!(area > 0.85f32 * std::f32::consts::PI * 25f32 && area < std::f32::consts::PI * 25f32);
+    }
+
+    #[test]
+    fn test_reachability_obstacle_dents_contour() {
+        let start = Vector2::zero();
+        let walls = [vec![Vector2::new(1f32, -1.5f32), Vector2::new(1.5f32, -1.5f32), Vector2::new(1.5f32, 1.5f32), Vector2::new(1f32, 1.5f32)]];
+        let contour = reachability_contour(start, 4f32, &walls, 0.1f32);
+        let open = reachability_contour(start, 4f32, &[], 0.1f32);
+        let reach_along = |contour: &[Vector2], direction: Vector2| {
+            contour.iter().filter(|p| Vector2::angle(**p, direction) < 5f32).map(|p| p.magnitude()).fold(0f32, f32::max)
+        };
+        assert!(reach_along(&contour, Vector2::right()) < reach_along(&open, Vector2::right()) - 0.5f32);
+        assert!((reach_along(&contour, Vector2::left()) - reach_along(&open, Vector2::left())).abs() < 0.05f32);
+        assert!(signed_area(&contour) < signed_area(&open));
+        assert!(reachability_contour(Vector2::new(1.2f32, 0f32), 4f32, &walls, 0.1f32).is_empty());
+        assert!(reachability_contour(start, 4f32, &[], 0f32).is_empty());
+        assert!(reachability_contour(start, 4f32, &[], -0.1f32).is_empty());
+    }
+}
@@ -0,0 +1,76 @@
+use super::vector2::Vector2;
+
+/// Radial deadzone: zero below `inner` magnitude, then the `[inner, outer]` band is rescaled to
+/// `[0, 1]` while keeping the input's direction. Magnitudes past `outer` clamp to 1.
+pub fn apply_deadzone(input: Vector2, inner: f32, outer: f32) -> Vector2 {
+    let magnitude = input.magnitude();
+    if magnitude <= inner {
+        return Vector2::zero();
+    }
+    input * (rescale(magnitude, inner, outer) / magnitude)
+}
+
+/// Per-axis deadzone: each component is zeroed below `inner` and rescaled from `[inner, outer]`
+/// to `[0, 1]` independently, which snaps near-axis input onto the axis.
+pub fn apply_deadzone_axial(input: Vector2, inner: f32, outer: f32) -> Vector2 {
+    input.map(|value| if value.abs() <= inner { 0f32 } else { rescale(value.abs(), inner, outer).copysign(value) })
+}
+
+fn rescale(value: f32, inner: f32, outer: f32) -> f32 {
+    if value >= outer {
+        return 1f32;
+    }
+    (value - inner) / (outer - inner)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deadzone_inner_zeroes() {
+        assert_eq!(apply_deadzone(Vector2::new(0.1f32, 0.05f32), 0.2f32, 0.9f32), Vector2::zero());
+        assert_eq!(apply_deadzone(Vector2::zero(), 0f32, 1f32), Vector2::zero());
+    }
+
+    #[test]
+    fn test_deadzone_outer_is_unit() {
+        let input = Vector2::from_angle(30f32) * 0.9f32;
+        assert!((apply_deadzone(input, 0.2f32, 0.9f32).magnitude() - 1f32).abs() < 1E-06f32);
+        assert!((apply_deadzone(input * 2f32, 0.2f32, 0.9f32).magnitude() - 1f32).abs() < 1E-06f32);
+    }
+
+    #[test]
+    fn test_deadzone_preserves_direction() {
+        let input = Vector2::new(0.3f32, -0.4f32);
+        let output = apply_deadzone(input, 0.2f32, 0.8f32);
+        assert!((output.magnitude() - 0.5f32).abs() < 1E-06f32);
+        assert!(Vector2::cross(input, output).abs() < 1E-06f32);
+        assert!(Vector2::dot(input, output) > 0f32);
+    }
+
+    #[test]
+    fn test_deadzone_axial() {
+        let diagonal = Vector2::new(0.3f32, -0.3f32);
+        let output = apply_deadzone_axial(diagonal, 0.2f32, 0.6f32);
+        assert!((output - Vector2::new(0.25f32, -0.25f32)).magnitude() < 1E-06f32);
+        assert_eq!(apply_deadzone_axial(Vector2::new(2f32, -0.1f32), 0.2f32, 0.6f32), Vector2::new(1f32, 0f32));
+    }
+
+    #[test]
+    fn test_deadzone_axial_vs_radial() {
+        // Past the radial threshold, but the y component alone is inside it.
+        let input = Vector2::new(0.25f32, 0.15f32);
+        let radial = apply_deadzone(input, 0.2f32, 1f32);
+        let axial = apply_deadzone_axial(input, 0.2f32, 1f32);
+        assert!(radial.y > 0f32);
+        assert!(Vector2::cross(input, radial).abs() < 1E-06f32);
+        assert_eq!(axial.y, 0f32);
+        assert!(axial.x > 0f32);
+
+        // Each axis is just past the threshold, so both variants pass it with different scaling.
+        let diagonal = Vector2::new(0.21f32, 0.21f32);
+        assert!(apply_deadzone_axial(diagonal, 0.2f32, 1f32).magnitude() < 0.02f32);
+        assert!(apply_deadzone(diagonal, 0.2f32, 1f32).magnitude() > 0.1f32);
+    }
+}
@@ -0,0 +1,290 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use super::vector2::Vector2;
+
+type EdgeMap = HashMap<(usize, usize), usize>;
+
+/// Bowyer-Watson Delaunay triangulation. Triangles index into `points` and wind
+/// counter-clockwise; repeated points are left out and collinear input gives no triangles.
+pub fn delaunay(points: &[Vector2]) -> Vec<[usize; 3]> {
+    let n = points.len();
+    if n < 3 {
+        return Vec::new();
+    }
+    let mut coords: Vec<(f64, f64)> = points.iter().map(|p| (p.x as f64, p.y as f64)).collect();
+    let (min, max) = bounds(&coords);
+    let span = (max.0 - min.0).max(max.1 - min.1).max(1f64);
+    let centre = ((min.0 + max.0) * 0.5, (min.1 + max.1) * 0.5);
+    // A super triangle far enough out that its vertices never end up on the hull.
+    let reach = span * 1E+04;
+    coords.push((centre.0 - reach, centre.1 - reach));
+    coords.push((centre.0 + reach, centre.1 - reach));
+    coords.push((centre.0, centre.1 + reach));
+    let mut triangles = vec![[n, n + 1, n + 2]];
+    let mut seen = HashSet::new();
+    for (i, p) in points.iter().enumerate() {
+        if !seen.insert((p.x.to_bits(), p.y.to_bits())) {
+            continue;
+        }
+        let (bad, kept): (Vec<[usize; 3]>, Vec<[usize; 3]>) = triangles.into_iter().partition(|&t| in_circle(&coords, t, i) > 0f64);
+        let edges: Vec<(usize, usize)> = bad.iter().flat_map(|&[a, b, c]| [(a, b), (b, c), (c, a)]).collect();
+        let lookup: HashSet<(usize, usize)> = edges.iter().copied().collect();
+        triangles = kept;
+        for &(a, b) in &edges {
+            if !lookup.contains(&(b, a)) {
+                triangles.push([a, b, i]);
+            }
+        }
+    }
+    triangles.retain(|t| t.iter().all(|&v| v < n));
+    triangles
+}
+
+/// Delaunay triangulation that contains every edge in `edges` (pairs of indices into
+/// `points`), for walls and outlines. Constraints are recovered by flipping the edges that
+/// cross them, then the remaining edges are flipped back toward Delaunay. Points lying on a
+/// constraint split it; constraints crossing each other are not supported.
+pub fn constrained_delaunay(points: &[Vector2], edges: &[(usize, usize)]) -> Vec<[usize; 3]> {
+    let mut triangles = delaunay(points);
+    if triangles.is_empty() {
+        return triangles;
+    }
+    let coords: Vec<(f64, f64)> = points.iter().map(|p| (p.x as f64, p.y as f64)).collect();
+    let mut map = edge_map(&triangles);
+    let mut in_mesh = vec![false; points.len()];
+    for &(a, _) in map.keys() {
+        in_mesh[a] = true;
+    }
+    let mut constrained = HashSet::new();
+    for &(u, v) in edges {
+        insert_constraint(&coords, &in_mesh, &mut triangles, &mut map, &mut constrained, u, v);
+    }
+    let (min, max) = bounds(&coords);
+    let span = (max.0 - min.0).max(max.1 - min.1);
+    let tolerance = 1E-12f64 * span.powi(4);
+    loop {
+        let mut flipped = false;
+        let mut interior: Vec<(usize, usize)> = map.keys().copied().filter(|&(a, b)| a < b && map.contains_key(&(b, a))).collect();
+        interior.sort_unstable();
+        for (a, b) in interior {
+            if constrained.contains(&(a, b)) || !map.contains_key(&(a, b)) {
+                continue;
+            }
+            let (c, d) = (opposite(&triangles, &map, a, b), opposite(&triangles, &map, b, a));
+            if in_circle(&coords, [a, b, c], d) > tolerance && is_convex_quad(&coords, a, b, c, d) {
+                flip(&mut triangles, &mut map, a, b);
+                flipped = true;
+            }
+        }
+        if !flipped {
+            return triangles;
+        }
+    }
+}
+
+fn insert_constraint(
+    coords: &[(f64, f64)],
+    in_mesh: &[bool],
+    triangles: &mut [[usize; 3]],
+    map: &mut EdgeMap,
+    constrained: &mut HashSet<(usize, usize)>,
+    u: usize,
+    v: usize,
+) {
+    if u == v {
+        return;
+    }
+    // Split at the nearest point lying on the constraint so each piece is a clean segment.
+    let (pu, pv) = (coords[u], coords[v]);
+    let (dx, dy) = (pv.0 - pu.0, pv.1 - pu.1);
+    let length2 = dx * dx + dy * dy;
+    let on_segment = (0..coords.len())
+        .filter(|&i| i != u && i != v && in_mesh[i])
+        .filter_map(|i| {
+            let (px, py) = (coords[i].0 - pu.0, coords[i].1 - pu.1);
+            let t = (px * dx + py * dy) / length2;
+            let off = (px * dy - py * dx).abs() / length2.sqrt();
+            (t > 0f64 && t < 1f64 && off < 1E-09f64 * length2.sqrt().max(1f64)).then_some((i, t))
+        })
+        .min_by(|a, b| a.1.total_cmp(&b.1));
+    if let Some((i, _)) = on_segment {
+        insert_constraint(coords, in_mesh, triangles, map, constrained, u, i);
+        insert_constraint(coords, in_mesh, triangles, map, constrained, i, v);
+        return;
+    }
+    constrained.insert((u.min(v), u.max(v)));
+    if map.contains_key(&(u, v)) || map.contains_key(&(v, u)) {
+        return;
+    }
+    let mut crossing: Vec<(usize, usize)> =
+        map.keys().copied().filter(|&(a, b)| a < b && map.contains_key(&(b, a)) && crosses(coords, a, b, u, v)).collect();
+    crossing.sort_unstable();
+    let mut crossing = VecDeque::from(crossing);
+    while let Some((a, b)) = crossing.pop_front() {
+        let (c, d) = (opposite(triangles, map, a, b), opposite(triangles, map, b, a));
+        if !is_convex_quad(coords, a, b, c, d) {
+            crossing.push_back((a, b));
+            continue;
+        }
+        flip(triangles, map, a, b);
+        if crosses(coords, c, d, u, v) {
+            crossing.push_back((c, d));
+        }
+    }
+}
+
+fn bounds(coords: &[(f64, f64)]) -> ((f64, f64), (f64, f64)) {
+    coords.iter().fold(((f64::MAX, f64::MAX), (f64::MIN, f64::MIN)), |(lo, hi), &(x, y)| ((lo.0.min(x), lo.1.min(y)), (hi.0.max(x), hi.1.max(y))))
+}
+
+fn edge_map(triangles: &[[usize; 3]]) -> EdgeMap {
+    let mut map = HashMap::new();
+    for (t, &[a, b, c]) in triangles.iter().enumerate() {
+        for edge in [(a, b), (b, c), (c, a)] {
+            map.insert(edge, t);
+        }
+    }
+    map
+}
+
+/// The vertex of the triangle holding directed edge `a -> b` that is not on the edge.
+fn opposite(triangles: &[[usize; 3]], map: &EdgeMap, a: usize, b: usize) -> usize {
+    *triangles[map[&(a, b)]].iter().find(|&&v| v != a && v != b).unwrap()
+}
+
+/// Replaces the diagonal `a - b` of the quad `a, d, b, c` with `c - d`.
+fn flip(triangles: &mut [[usize; 3]], map: &mut EdgeMap, a: usize, b: usize) {
+    let (t1, t2) = (map[&(a, b)], map[&(b, a)]);
+    let (c, d) = (opposite(triangles, map, a, b), opposite(triangles, map, b, a));
+    for t in [t1, t2] {
+        let [p, q, r] = triangles[t];
+        for edge in [(p, q), (q, r), (r, p)] {
+            map.remove(&edge);
+        }
+    }
+    triangles[t1] = [a, d, c];
+    triangles[t2] = [d, b, c];
+    for t in [t1, t2] {
+        let [p, q, r] = triangles[t];
+        for edge in [(p, q), (q, r), (r, p)] {
+            map.insert(edge, t);
+        }
+    }
+}
+
+fn orient(coords: &[(f64, f64)], a: usize, b: usize, c: usize) -> f64 {
+    let (pa, pb, pc) = (coords[a], coords[b], coords[c]);
+    (pb.0 - pa.0) * (pc.1 - pa.1) - (pb.1 - pa.1) * (pc.0 - pa.0)
+}
+
+/// Whether segments `a - b` and `u - v` cross at a point interior to both.
+fn crosses(coords: &[(f64, f64)], a: usize, b: usize, u: usize, v: usize) -> bool {
+    if a == u || a == v || b == u || b == v {
+        return false;
+    }
+    orient(coords, a, b, u) * orient(coords, a, b, v) < 0f64 && orient(coords, u, v, a) * orient(coords, u, v, b) < 0f64
+}
+
+/// Whether the quad around diagonal `a - b`, with `c` and `d` on either side, is strictly
+/// convex so the diagonal can be flipped.
+fn is_convex_quad(coords: &[(f64, f64)], a: usize, b: usize, c: usize, d: usize) -> bool {
+    orient(coords, c, d, a) * orient(coords, c, d, b) < 0f64
+}
+
+/// Positive when `d` lies strictly inside the circumcircle of the counter-clockwise triangle.
+fn in_circle(coords: &[(f64, f64)], [a, b, c]: [usize; 3], d: usize) -> f64 {
+    let pd = coords[d];
+    let rel = |i: usize| {
+        let (x, y) = (coords[i].0 - pd.0, coords[i].1 - pd.1);
+        (x, y, x * x + y * y)
+    };
+    let (ax, ay, aw) = rel(a);
+    let (bx, by, bw) = rel(b);
+    let (cx, cy, cw) = rel(c);
+    ax * (by * cw - bw * cy) - ay * (bx * cw - bw * cx) + aw * (bx * cy - by * cx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::hull::convex_hull;
+    use crate::algorithms::polygon::signed_area;
+    use crate::algorithms::test_support::Lcg;
+
+    fn random_points(count: usize, seed: u64) -> Vec<Vector2> {
+        let mut rng = Lcg(seed);
+        let mut next = || rng.next() * 10f32;
+        (0..count).map(|_| Vector2::new(next(), next())).collect()
+    }
+
+    fn grid(n: usize) -> Vec<Vector2> {
+        (0..n).flat_map(|y| (0..n).map(move |x| Vector2::new(x as f32, y as f32))).collect()
+    }
+
+    fn area(points: &[Vector2], triangles: &[[usize; 3]]) -> f32 {
+        triangles.iter().map(|&[a, b, c]| signed_area(&[points[a], points[b], points[c]])).sum()
+    }
+
+    fn has_edge(triangles: &[[usize; 3]], u: usize, v: usize) -> bool {
+        triangles.iter().any(|t| t.contains(&u) && t.contains(&v))
+    }
+
+    #[test]
+    fn test_delaunay_empty_circumcircles() {
+        let points = random_points(60, 7);
+        let triangles = delaunay(&points);
+        assert_eq!(triangles.len(), 2 * points.len() - 2 - convex_hull(&points).len());
+        assert!((area(&points, &triangles) - signed_area(&convex_hull(&points))).abs() < 1E-03f32);
+        let coords: Vec<(f64, f64)> = points.iter().map(|p| (p.x as f64, p.y as f64)).collect();
+        for &t in &triangles {
+            assert!(signed_area(&[points[t[0]], points[t[1]], points[t[2]]]) > 0f32);
+            assert!((0..points.len()).all(|d| in_circle(&coords, t, d) <= 1E-09f64));
+        }
+    }
+
+    #[test]
+    fn test_delaunay_degenerate() {
+        assert!(delaunay(&[Vector2::zero(), Vector2::one()]).is_empty());
+        assert!(delaunay(&[Vector2::zero(), Vector2::one(), Vector2::new(2f32, 2f32)]).is_empty());
+        let points = grid(4);
+        let mut doubled = points.clone();
+        doubled.push(points[5]);
+        let triangles = delaunay(&doubled);
+        // A grid keeps its collinear boundary points: 2n - 2 - h with all 12 edge points.
+        assert_eq!(triangles.len(), 18);
+        assert!(triangles.iter().all(|t| !t.contains(&16)));
+        assert!((area(&doubled, &triangles) - 9f32).abs() < 1E-05f32);
+    }
+
+    #[test]
+    fn test_constrained_edges_present() {
+        let mut points = random_points(50, 11);
+        points.extend([Vector2::new(-1f32, 5f32), Vector2::new(11f32, 5.5f32), Vector2::new(5f32, -1f32), Vector2::new(5.2f32, 11f32)]);
+        let below = points.iter().position(|p| p.y < 4f32).unwrap();
+        let above = points.iter().position(|p| p.y > 6.5f32).unwrap();
+        let constraints = [(50, 51), (52, below), (above, 53)];
+        assert!(!has_edge(&delaunay(&points), 50, 51));
+        let triangles = constrained_delaunay(&points, &constraints);
+        for &(u, v) in &constraints {
+            assert!(has_edge(&triangles, u, v), "missing {} - {}", u, v);
+        }
+        assert_eq!(triangles.len(), delaunay(&points).len());
+        assert!(triangles.iter().all(|&[a, b, c]| signed_area(&[points[a], points[b], points[c]]) > 0f32));
+        assert!((area(&points, &triangles) - signed_area(&convex_hull(&points))).abs() < 1E-03f32);
+    }
+
+    #[test]
+    fn test_constrained_through_points() {
+        let points = grid(4);
+        let triangles = constrained_delaunay(&points, &[(0, 15), (4, 13)]);
+        assert!(has_edge(&triangles, 0, 5) && has_edge(&triangles, 5, 10) && has_edge(&triangles, 10, 15));
+        assert!(has_edge(&triangles, 4, 13) && !has_edge(&triangles, 0, 15));
+        assert_eq!(triangles.len(), 18);
+    }
+
+    #[test]
+    fn test_unconstrained_matches_delaunay() {
+        let points = random_points(40, 3);
+        assert_eq!(constrained_delaunay(&points, &[]), delaunay(&points));
+    }
+}
@@ -0,0 +1,75 @@
+use std::f32::consts::PI;
+
+use super::rect::{grid_size, Rect};
+use super::vector2::Vector2;
+
+/// Gaussian kernel density estimate of `points` at `query`, with `bandwidth` as the kernel's
+/// standard deviation. Normalised so the density integrates to one over the plane; an empty set
+/// has zero density everywhere.
+pub fn kernel_density(points: &[Vector2], query: Vector2, bandwidth: f32) -> f32 {
+    if points.is_empty() {
+        return 0f32;
+    }
+    let inverse_variance = 1f32 / (bandwidth * bandwidth);
+    let sum: f32 = points.iter().map(|&p| (-0.5f32 * (p - query).sqr_magnitude() * inverse_variance).exp()).sum();
+    sum * inverse_variance / (2f32 * PI * points.len() as f32)
+}
+
+/// [`kernel_density`] at the centre of every `cell_size` cell of `bounds`, indexed `[y][x]`. A
+/// non-positive `cell_size` gives an empty grid.
+pub fn kernel_density_grid(points: &[Vector2], bounds: Rect, cell_size: f32, bandwidth: f32) -> Vec<Vec<f32>> {
+    let (width, height) = grid_size(bounds, cell_size);
+    (0..height)
+        .map(|y| {
+            (0..width)
+                .map(|x| {
+                    let center = bounds.min() + Vector2::new(x as f32 + 0.5f32, y as f32 + 0.5f32) * cell_size;
+                    kernel_density(points, center, bandwidth)
+                })
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kernel_density_single_point() {
+        let points = [Vector2::new(1f32, 1f32)];
+        let peak = kernel_density(&points, points[0], 0.5f32);
+        assert!((peak - 1f32 / (2f32 * PI * 0.25f32)).abs() < 1E-06f32);
+        // One bandwidth away the Gaussian has fallen to exp(-1/2) of its peak, at any bandwidth.
+        for bandwidth in [0.5f32, 2f32] {
+            let near = kernel_density(&points, points[0] + Vector2::right() * bandwidth, bandwidth);
+            assert!((near / kernel_density(&points, points[0], bandwidth) - (-0.5f32).exp()).abs() < 1E-05f32);
+        }
+        assert_eq!(kernel_density(&[], Vector2::zero(), 1f32), 0f32);
+    }
+
+    #[test]
+    fn test_kernel_density_grid_clusters() {
+        let mut points: Vec<Vector2> = (0..12).map(|i| Vector2::new(2f32, 2f32) + Vector2::from_angle(i as f32 * 30f32) * 0.2f32).collect();
+        points.extend((0..4).map(|i| Vector2::new(7f32, 6f32) + Vector2::from_angle(i as f32 * 90f32) * 0.2f32));
+        let bounds = Rect::new(-3f32, -3f32, 16f32, 16f32);
+        let cell = 0.1f32;
+        let grid = kernel_density_grid(&points, bounds, cell, 0.5f32);
+        assert_eq!((grid.len(), grid[0].len()), (160, 160));
+        let total: f32 = grid.iter().flatten().sum::<f32>() * cell * cell;
+        assert!((total - 1f32).abs() < 1E-03f32, "{}", total);
+        let (peak_y, peak_x) = (0..160).flat_map(|y| (0..160).map(move |x| (y, x))).max_by(|a, b| grid[a.0][a.1].total_cmp(&grid[b.0][b.1])).unwrap();
+        let peak = bounds.min() + Vector2::new(peak_x as f32 + 0.5f32, peak_y as f32 + 0.5f32) * cell;
+        assert!((peak - Vector2::new(2f32, 2f32)).magnitude() < 0.15f32);
+        assert!(kernel_density(&points, Vector2::new(2f32, 2f32), 0.5f32) > 2f32 * kernel_density(&points, Vector2::new(7f32, 6f32), 0.5f32));
+        assert!(kernel_density(&points, Vector2::new(4.5f32, 4f32), 0.5f32) < 1E-03f32);
+    }
+
+    #[test]
+    fn test_kernel_density_grid_non_positive_cell_size() {
+        let points = [Vector2::new(1f32, 1f32)];
+        let bounds = Rect::new(0f32, 0f32, 2f32, 2f32);
+        assert!(kernel_density_grid(&points, bounds, 0f32, 0.5f32).is_empty());
+        assert!(kernel_density_grid(&points, bounds, -0.1f32, 0.5f32).is_empty());
+    }
+}
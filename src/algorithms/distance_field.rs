@@ -0,0 +1,247 @@
+use std::collections::BinaryHeap;
+
+use super::heap::MinCost;
+use super::rect::Rect;
+use super::vector2_int::Vector2Int;
+
+const NEIGHBOURS: [Vector2Int; 4] = [
+    Vector2Int { x: 1, y: 0 },
+    Vector2Int { x: -1, y: 0 },
+    Vector2Int { x: 0, y: 1 },
+    Vector2Int { x: 0, y: -1 },
+];
+
+/// Step distance from every cell of a `width` x `height` grid to the nearest of `sources`,
+/// indexed `[y][x]`. Unreachable and blocked cells are `f32::INFINITY`.
+pub fn distance_field(sources: &[Vector2Int], width: usize, height: usize,
+                      is_walkable: impl Fn(Vector2Int) -> bool) -> Vec<Vec<f32>> {
+    flood(sources, width, height, is_walkable).0
+}
+
+/// Index into `sources` of the nearest source for every cell, indexed `[y][x]`.
+/// Cells equidistant from several sources belong to the lowest source index.
+pub fn nearest_source_field(sources: &[Vector2Int], width: usize, height: usize,
+                            is_walkable: impl Fn(Vector2Int) -> bool) -> Vec<Vec<Option<usize>>> {
+    flood(sources, width, height, is_walkable).1
+}
+
+fn flood(sources: &[Vector2Int], width: usize, height: usize,
+         is_walkable: impl Fn(Vector2Int) -> bool) -> (Vec<Vec<f32>>, Vec<Vec<Option<usize>>>) {
+    let mut distances = vec![vec![f32::INFINITY; width]; height];
+    let mut owners = vec![vec![None; width]; height];
+    let open = |cell: Vector2Int| {
+        cell.x >= 0 && cell.y >= 0 && (cell.x as usize) < width && (cell.y as usize) < height && is_walkable(cell)
+    };
+
+    let mut frontier = Vec::new();
+    for (index, &source) in sources.iter().enumerate() {
+        if !open(source) {
+            continue;
+        }
+        let (x, y) = (source.x as usize, source.y as usize);
+        if owners[y][x].is_none() {
+            distances[y][x] = 0f32;
+            owners[y][x] = Some(index);
+            frontier.push(source);
+        }
+    }
+
+    // Expand one ring at a time so that every claim on a cell at the same distance is seen
+    // before that cell is expanded, which keeps tie-breaking independent of queue order.
+    let mut distance = 0f32;
+    while !frontier.is_empty() {
+        distance += 1f32;
+        let mut next = Vec::new();
+        for cell in frontier {
+            let owner = owners[cell.y as usize][cell.x as usize];
+            for direction in NEIGHBOURS {
+                let neighbour = cell + direction;
+                if !open(neighbour) {
+                    continue;
+                }
+                let (x, y) = (neighbour.x as usize, neighbour.y as usize);
+                if distances[y][x] == f32::INFINITY {
+                    distances[y][x] = distance;
+                    owners[y][x] = owner;
+                    next.push(neighbour);
+                } else if distances[y][x] == distance && owner < owners[y][x] {
+                    owners[y][x] = owner;
+                }
+            }
+        }
+        frontier = next;
+    }
+
+    (distances, owners)
+}
+
+/// Least total cost from `start` to every cell of `bounds`, indexed `[y][x]` from its
+/// rounded-down minimum corner, where a four-connected step costs `cost` of the cell entered.
+/// Costs must not be negative; infinite ones are impassable. Cells that cannot be reached,
+/// including everything when `start` lies outside `bounds`, are `f32::INFINITY`.
+pub fn weighted_distance_field(start: Vector2Int, cost: impl Fn(Vector2Int) -> f32, bounds: Rect) -> Vec<Vec<f32>> {
+    let origin = Vector2Int::new(bounds.x.floor() as i32, bounds.y.floor() as i32);
+    let (width, height) = (bounds.width.ceil() as usize, bounds.height.ceil() as usize);
+    let mut distances = vec![vec![f32::INFINITY; width]; height];
+    let index = |cell: Vector2Int| {
+        let local = cell - origin;
+        (local.x >= 0 && local.y >= 0 && (local.x as usize) < width && (local.y as usize) < height).then_some((local.x as usize, local.y as usize))
+    };
+    let Some((x, y)) = index(start) else { return distances };
+    distances[y][x] = 0f32;
+    let mut queue = BinaryHeap::from([MinCost { cost: 0f32, item: start }]);
+    while let Some(MinCost { cost: reached, item: cell }) = queue.pop() {
+        let (x, y) = index(cell).unwrap();
+        if reached > distances[y][x] {
+            continue;
+        }
+        for direction in NEIGHBOURS {
+            let neighbour = cell + direction;
+            let Some((nx, ny)) = index(neighbour) else { continue };
+            let next = reached + cost(neighbour);
+            if next < distances[ny][nx] {
+                distances[ny][nx] = next;
+                queue.push(MinCost { cost: next, item: neighbour });
+            }
+        }
+    }
+    distances
+}
+
+/// Path from `start` down `field`, indexed `[y][x]`, stepping each time to the four-connected
+/// neighbour with the lowest value until none is lower, which for a field from
+/// [`weighted_distance_field`] with positive costs is its source. Ties go to the first of
+/// +x, -x, +y, -y. Both ends are included; a start outside the grid or at infinity gives an
+/// empty path.
+pub fn descend_field(field: &[Vec<f32>], start: Vector2Int) -> Vec<Vector2Int> {
+    let value = |cell: Vector2Int| {
+        (cell.x >= 0 && cell.y >= 0)
+            .then(|| field.get(cell.y as usize).and_then(|row| row.get(cell.x as usize)))
+            .flatten()
+            .copied()
+    };
+    if !value(start).is_some_and(f32::is_finite) {
+        return Vec::new();
+    }
+    let mut path = vec![start];
+    let mut current = start;
+    loop {
+        let here = value(current).unwrap();
+        let lowest = NEIGHBOURS
+            .iter()
+            .map(|&direction| current + direction)
+            .filter_map(|cell| value(cell).map(|v| (cell, v)))
+            .fold(None, |best: Option<(Vector2Int, f32)>, (cell, v)| if best.is_none_or(|(_, b)| v < b) { Some((cell, v)) } else { best });
+        match lowest {
+            Some((cell, v)) if v < here => {
+                path.push(cell);
+                current = cell;
+            }
+            _ => return path,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_single_source() {
+        let field = distance_field(&[Vector2Int::new(0, 0)], 3, 2, |_| true);
+        assert_eq!(field, vec![vec![0f32, 1f32, 2f32], vec![1f32, 2f32, 3f32]]);
+    }
+
+    #[test]
+    fn test_multiple_sources() {
+        let sources = [Vector2Int::new(0, 0), Vector2Int::new(6, 0)];
+        let distances = distance_field(&sources, 7, 1, |_| true);
+        assert_eq!(distances[0], vec![0f32, 1f32, 2f32, 3f32, 2f32, 1f32, 0f32]);
+        let owners = nearest_source_field(&sources, 7, 1, |_| true);
+        assert_eq!(owners[0], vec![Some(0), Some(0), Some(0), Some(0), Some(1), Some(1), Some(1)]);
+    }
+
+    #[test]
+    fn test_equidistant_cells_go_to_lowest_index() {
+        let sources = [Vector2Int::new(4, 4), Vector2Int::new(0, 0)];
+        let owners = nearest_source_field(&sources, 5, 5, |_| true);
+        for (y, row) in owners.iter().enumerate() {
+            for (x, &owner) in row.iter().enumerate() {
+                let expected = if x + y < 4 { 1 } else { 0 };
+                assert_eq!(owner, Some(expected));
+            }
+        }
+        let swapped = nearest_source_field(&[sources[1], sources[0]], 5, 5, |_| true);
+        assert_eq!(swapped[0][4], Some(0));
+        assert_eq!(swapped[4][0], Some(0));
+    }
+
+    #[test]
+    fn test_walls_and_unreachable_cells() {
+        let wall = |cell: Vector2Int| cell.x != 1;
+        let field = distance_field(&[Vector2Int::new(0, 0)], 3, 2, wall);
+        assert_eq!(field[1][0], 1f32);
+        assert_eq!(field[0][1], f32::INFINITY);
+        assert_eq!(field[0][2], f32::INFINITY);
+        let owners = nearest_source_field(&[Vector2Int::new(0, 0)], 3, 2, wall);
+        assert_eq!(owners[0][2], None);
+    }
+
+    #[test]
+    fn test_invalid_sources_are_ignored() {
+        let sources = [Vector2Int::new(-1, 0), Vector2Int::new(1, 0)];
+        let owners = nearest_source_field(&sources, 2, 1, |_| true);
+        assert_eq!(owners[0], vec![Some(1), Some(1)]);
+        let field = distance_field(&[], 2, 1, |_| true);
+        assert_eq!(field[0], vec![f32::INFINITY, f32::INFINITY]);
+    }
+
+    #[test]
+    fn test_weighted_uniform_matches_distance_field() {
+        let start = Vector2Int::new(2, 1);
+        let weighted = weighted_distance_field(start, |_| 1f32, Rect::new(0f32, 0f32, 6f32, 4f32));
+        assert_eq!(weighted, distance_field(&[start], 6, 4, |_| true));
+        // Walls as infinite cost behave like unwalkable cells.
+        let wall = |cell: Vector2Int| cell.x != 3 || cell.y == 3;
+        let walled = weighted_distance_field(start, |c| if wall(c) { 1f32 } else { f32::INFINITY }, Rect::new(0f32, 0f32, 6f32, 4f32));
+        assert_eq!(walled, distance_field(&[start], 6, 4, wall));
+    }
+
+    #[test]
+    fn test_weighted_routes_around_expensive_terrain() {
+        // A swamp costing 10 per cell in column 2, rows 0 to 3, with a cheap gap at row 4.
+        let cost = |c: Vector2Int| if c.x == 2 && c.y < 4 { 10f32 } else { 1f32 };
+        let field = weighted_distance_field(Vector2Int::new(0, 0), cost, Rect::new(0f32, 0f32, 5f32, 5f32));
+        // Straight through costs 1 + 10 + 1 + 1 = 13; down through the gap and back up is 12.
+        assert_eq!(field[0][4], 12f32);
+        assert_eq!(field[0][2], 11f32);
+        // A mild swamp is cheaper to wade through than to walk around.
+        let mild = weighted_distance_field(Vector2Int::new(0, 0), |c| if c.x == 2 && c.y < 4 { 2f32 } else { 1f32 }, Rect::new(0f32, 0f32, 5f32, 5f32));
+        assert_eq!(mild[0][4], 5f32);
+        // Impassable cells and cells sealed off by them stay infinite.
+        let sealed = |c: Vector2Int| if c.x == 2 { f32::INFINITY } else { 1f32 };
+        let field = weighted_distance_field(Vector2Int::new(0, 0), sealed, Rect::new(0f32, 0f32, 5f32, 5f32));
+        assert!(field.iter().all(|row| row[2..].iter().all(|d| d.is_infinite())));
+        assert_eq!(field[4][1], 5f32);
+        let outside = weighted_distance_field(Vector2Int::new(9, 0), |_| 1f32, Rect::new(0f32, 0f32, 2f32, 2f32));
+        assert!(outside.iter().flatten().all(|d| d.is_infinite()));
+    }
+
+    #[test]
+    fn test_descend_field_follows_least_cost() {
+        let cost = |c: Vector2Int| if c.x == 2 && c.y < 4 { 10f32 } else if c.x == 1 && c.y == 2 { f32::INFINITY } else { 1f32 };
+        let source = Vector2Int::new(0, 0);
+        let field = weighted_distance_field(source, cost, Rect::new(0f32, 0f32, 5f32, 5f32));
+        let start = Vector2Int::new(4, 0);
+        let path = descend_field(&field, start);
+        assert_eq!((path[0], *path.last().unwrap()), (start, source));
+        let at = |c: Vector2Int| field[c.y as usize][c.x as usize];
+        assert!(path.windows(2).all(|w| at(w[1]) < at(w[0]) && Vector2Int::manhattan_distance(w[0], w[1]) == 1));
+        assert!(path.iter().all(|&c| at(c).is_finite() && !(c.x == 2 && c.y < 4)));
+        // Each step into a cell costs that cell, so the path is exactly as long as the start's value.
+        assert_eq!(path[1..].iter().map(|&c| cost(c)).sum::<f32>(), at(start));
+        assert_eq!(descend_field(&field, source), vec![source]);
+        assert!(descend_field(&field, Vector2Int::new(1, 2)).is_empty());
+        assert!(descend_field(&field, Vector2Int::new(-1, 0)).is_empty());
+    }
+}
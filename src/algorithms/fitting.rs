@@ -0,0 +1,241 @@
+#[cfg(feature = "rand")]
+use rand::{Rng, RngCore};
+
+use super::vector2::Vector2;
+
+/// Least-squares algebraic (Kasa) circle fit returning centre and radius. Returns `None` for
+/// fewer than three points or points that are (nearly) collinear.
+pub fn fit_circle(points: &[Vector2]) -> Option<(Vector2, f32)> {
+    if points.len() < 3 {
+        return None;
+    }
+    let mean = Vector2::centroid(points)?;
+    let (mut suu, mut suv, mut svv, mut suuu, mut svvv, mut suvv, mut svuu) = (0f32, 0f32, 0f32, 0f32, 0f32, 0f32, 0f32);
+    for &p in points {
+        let (u, v) = (p.x - mean.x, p.y - mean.y);
+        suu += u * u;
+        suv += u * v;
+        svv += v * v;
+        suuu += u * u * u;
+        svvv += v * v * v;
+        suvv += u * v * v;
+        svuu += v * u * u;
+    }
+    let det = suu * svv - suv * suv;
+    if det <= 1E-06f32 * (suu + svv) * (suu + svv) {
+        return None;
+    }
+    let rhs_u = 0.5f32 * (suuu + suvv);
+    let rhs_v = 0.5f32 * (svvv + svuu);
+    let uc = (rhs_u * svv - rhs_v * suv) / det;
+    let vc = (rhs_v * suu - rhs_u * suv) / det;
+    let radius = (uc * uc + vc * vc + (suu + svv) / points.len() as f32).sqrt();
+    Some((mean + Vector2::new(uc, vc), radius))
+}
+
+/// Total least squares line fit, minimizing perpendicular distances, so vertical lines fit
+/// as well as horizontal ones. Returns the centroid and a unit direction, or `None` when
+/// there are fewer than two distinct points.
+pub fn fit_line(points: &[Vector2]) -> Option<(Vector2, Vector2)> {
+    let mean = Vector2::centroid(points)?;
+    let (mut sxx, mut sxy, mut syy) = (0f32, 0f32, 0f32);
+    for &p in points {
+        let d = p - mean;
+        sxx += d.x * d.x;
+        sxy += d.x * d.y;
+        syy += d.y * d.y;
+    }
+    if sxx + syy <= 0f32 {
+        return None;
+    }
+    let angle = 0.5f32 * (2f32 * sxy).atan2(sxx - syy);
+    Some((mean, Vector2::new(angle.cos(), angle.sin())))
+}
+
+/// Line supported by the most points within `threshold`, refined with [`fit_line`] over those
+/// inliers. Candidate lines come from `iterations` random point pairs.
+#[cfg(feature = "rand")]
+pub fn ransac_line(points: &[Vector2], threshold: f32, iterations: u32, rng: &mut impl RngCore) -> Option<(Vector2, Vector2)> {
+    if points.len() < 2 {
+        return fit_line(points);
+    }
+    let mut best: Vec<Vector2> = Vec::new();
+    for _ in 0..iterations {
+        let (a, b) = (points[rng.gen_range(0..points.len())], points[rng.gen_range(0..points.len())]);
+        let direction = (b - a).normalized();
+        if direction == Vector2::zero() {
+            continue;
+        }
+        let inliers: Vec<Vector2> = points.iter().copied().filter(|&p| Vector2::cross(direction, p - a).abs() <= threshold).collect();
+        if inliers.len() > best.len() {
+            best = inliers;
+        }
+    }
+    fit_line(&best)
+}
+
+/// Circle supported by the most points within `threshold` of its rim, refined with
+/// [`fit_circle`] over those inliers. Candidate circles come from `iterations` random triples.
+#[cfg(feature = "rand")]
+pub fn ransac_circle(points: &[Vector2], threshold: f32, iterations: u32, rng: &mut impl RngCore) -> Option<(Vector2, f32)> {
+    if points.len() < 3 {
+        return None;
+    }
+    let mut best: Vec<Vector2> = Vec::new();
+    for _ in 0..iterations {
+        let sample = [0; 3].map(|_| points[rng.gen_range(0..points.len())]);
+        let Some((center, radius)) = circumcircle(sample[0], sample[1], sample[2]) else {
+            continue;
+        };
+        let inliers: Vec<Vector2> = points.iter().copied().filter(|&p| ((p - center).magnitude() - radius).abs() <= threshold).collect();
+        if inliers.len() > best.len() {
+            best = inliers;
+        }
+    }
+    fit_circle(&best)
+}
+
+/// Circle through three points, or `None` when they are collinear.
+pub fn circumcircle(a: Vector2, b: Vector2, c: Vector2) -> Option<(Vector2, f32)> {
+    let (ab, ac) = (b - a, c - a);
+    let d = 2f32 * Vector2::cross(ab, ac);
+    if d.abs() < 1E-12f32 {
+        return None;
+    }
+    let (ab2, ac2) = (ab.sqr_magnitude(), ac.sqr_magnitude());
+    let offset = Vector2::new(ac.y * ab2 - ab.y * ac2, ab.x * ac2 - ac.x * ab2) / d;
+    Some((a + offset, offset.magnitude()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fit_circle_exact() {
+        let center = Vector2::new(3f32, -2f32);
+        let points: Vec<Vector2> = (0..12).map(|i| center + Vector2::from_angle(i as f32 * 30f32) * 5f32).collect();
+        let (c, r) = fit_circle(&points).unwrap();
+        assert!((c - center).magnitude() < 1E-04f32);
+        assert!((r - 5f32).abs() < 1E-04f32);
+    }
+
+    #[test]
+    fn test_fit_circle_arc_with_noise() {
+        let center = Vector2::new(10f32, 20f32);
+        let points: Vec<Vector2> = (0..30)
+            .map(|i| {
+                let noise = if i % 2 == 0 { 0.01f32 } else { -0.01f32 };
+                center + Vector2::from_angle(i as f32 * 3f32) * (2f32 + noise)
+            })
+            .collect();
+        let (c, r) = fit_circle(&points).unwrap();
+        assert!((c - center).magnitude() < 0.05f32);
+        assert!((r - 2f32).abs() < 0.05f32);
+    }
+
+    #[test]
+    fn test_fit_circle_degenerate() {
+        let line = [Vector2::new(0f32, 0f32), Vector2::new(1f32, 1f32), Vector2::new(2f32, 2f32), Vector2::new(4f32, 4f32)];
+        assert_eq!(fit_circle(&line), None);
+        assert_eq!(fit_circle(&line[..2]), None);
+        assert_eq!(fit_circle(&[Vector2::one(); 5]), None);
+    }
+
+    fn assert_on_line(points: &[Vector2], (origin, direction): (Vector2, Vector2)) {
+        assert!((direction.magnitude() - 1f32).abs() < 1E-05f32);
+        for &p in points {
+            assert!(Vector2::cross(direction, p - origin).abs() < 1E-04f32);
+        }
+    }
+
+    #[test]
+    fn test_fit_line() {
+        let points: Vec<Vector2> = (0..10).map(|i| Vector2::new(1f32, 2f32) + Vector2::new(2f32, 1f32) * i as f32).collect();
+        let fit = fit_line(&points).unwrap();
+        assert_on_line(&points, fit);
+        assert!(Vector2::cross(fit.1, Vector2::new(2f32, 1f32)).abs() < 1E-05f32);
+    }
+
+    #[test]
+    fn test_fit_line_vertical() {
+        let points: Vec<Vector2> = (0..5).map(|i| Vector2::new(3f32, i as f32 * 1.5f32)).collect();
+        let fit = fit_line(&points).unwrap();
+        assert_on_line(&points, fit);
+        assert!(fit.1.x.abs() < 1E-06f32);
+        assert!((fit.0 - Vector2::new(3f32, 3f32)).magnitude() < 1E-05f32);
+    }
+
+    #[test]
+    fn test_fit_line_degenerate() {
+        assert_eq!(fit_line(&[]), None);
+        assert_eq!(fit_line(&[Vector2::one()]), None);
+        assert_eq!(fit_line(&[Vector2::one(), Vector2::one()]), None);
+        assert!(fit_line(&[Vector2::zero(), Vector2::one()]).is_some());
+    }
+
+    #[test]
+    fn test_circumcircle() {
+        let (center, radius) = circumcircle(Vector2::new(1f32, 0f32), Vector2::new(-1f32, 0f32), Vector2::new(0f32, 1f32)).unwrap();
+        assert!(center.magnitude() < 1E-06f32);
+        assert!((radius - 1f32).abs() < 1E-06f32);
+        assert_eq!(circumcircle(Vector2::zero(), Vector2::one(), Vector2::new(2f32, 2f32)), None);
+    }
+
+    #[cfg(feature = "rand")]
+    fn noisy_line_with_outliers() -> Vec<Vector2> {
+        let mut points: Vec<Vector2> = (0..40)
+            .map(|i| {
+                let noise = ((i * 7) % 5) as f32 * 0.01f32 - 0.02f32;
+                Vector2::new(i as f32 * 0.5f32, 1f32 + i as f32 * 0.25f32 + noise)
+            })
+            .collect();
+        for i in 0..12 {
+            points.push(Vector2::new(i as f32 * 1.7f32, 30f32 - i as f32 * 0.3f32));
+        }
+        points
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_ransac_line_ignores_outliers() {
+        use rand::SeedableRng;
+
+        let points = noisy_line_with_outliers();
+        let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+        let (origin, direction) = ransac_line(&points, 0.1f32, 200, &mut rng).unwrap();
+        let true_direction = Vector2::new(2f32, 1f32).normalized();
+        assert!(Vector2::cross(direction, true_direction).abs() < 0.01f32);
+        assert!(Vector2::cross(true_direction, origin - Vector2::new(0f32, 1f32)).abs() < 0.05f32);
+
+        let (_, plain) = fit_line(&points).unwrap();
+        assert!(Vector2::cross(plain, true_direction).abs() > 0.05f32);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_ransac_circle_ignores_outliers() {
+        use rand::SeedableRng;
+
+        let center = Vector2::new(5f32, 5f32);
+        let mut points: Vec<Vector2> = (0..36).map(|i| center + Vector2::from_angle(i as f32 * 10f32) * 3f32).collect();
+        points.extend((0..10).map(|i| Vector2::new(i as f32, 20f32 + i as f32)));
+        let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+        let (c, r) = ransac_circle(&points, 0.05f32, 300, &mut rng).unwrap();
+        assert!((c - center).magnitude() < 1E-03f32);
+        assert!((r - 3f32).abs() < 1E-03f32);
+
+        let (plain_center, _) = fit_circle(&points).unwrap();
+        assert!((plain_center - center).magnitude() > 1f32);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_ransac_degenerate() {
+        use rand::SeedableRng;
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(1);
+        assert_eq!(ransac_line(&[Vector2::one()], 0.1f32, 10, &mut rng), None);
+        assert_eq!(ransac_circle(&[Vector2::one(), Vector2::zero()], 0.1f32, 10, &mut rng), None);
+    }
+}
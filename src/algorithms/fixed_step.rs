@@ -0,0 +1,82 @@
+/// Splits variable frame times into a whole number of fixed simulation steps, carrying the
+/// remainder over to the next frame.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FixedStep {
+    accumulator: f32,
+    dt: f32,
+}
+
+impl FixedStep {
+    /// Stepper with an empty accumulator that runs one step per `dt` of frame time.
+    ///
+    /// # Panics
+    ///
+    /// If `dt` is not positive, which would make [`FixedStep::advance`] loop forever.
+    pub fn new(dt: f32) -> Self {
+        assert!(dt > 0f32, "dt must be positive");
+        Self { accumulator: 0f32, dt }
+    }
+    pub fn dt(&self) -> f32 { self.dt }
+    pub fn accumulator(&self) -> f32 { self.accumulator }
+    /// Fraction of the next step already accumulated, for blending the previous and current
+    /// simulation states.
+    pub fn interpolation_alpha(&self) -> f32 { self.accumulator / self.dt }
+
+    pub fn advance(&mut self, frame_time: f32, mut step_fn: impl FnMut()) {
+        self.accumulator += frame_time;
+        while self.accumulator >= self.dt {
+            step_fn();
+            self.accumulator -= self.dt;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_advance_carries_remainder() {
+        let mut fixed = FixedStep::new(1f32);
+        let mut steps = 0;
+        fixed.advance(2.5f32, || steps += 1);
+        assert_eq!(steps, 2);
+        assert_eq!(fixed.accumulator(), 0.5f32);
+        fixed.advance(0.25f32, || steps += 1);
+        assert_eq!(steps, 2);
+        fixed.advance(0.25f32, || steps += 1);
+        assert_eq!(steps, 3);
+        assert_eq!(fixed.accumulator(), 0f32);
+    }
+
+    #[test]
+    fn test_interpolation_alpha() {
+        let mut fixed = FixedStep::new(0.5f32);
+        assert_eq!(fixed.interpolation_alpha(), 0f32);
+        fixed.advance(1.125f32, || {});
+        assert_eq!(fixed.interpolation_alpha(), 0.25f32);
+        assert_eq!(fixed.interpolation_alpha(), fixed.accumulator() / fixed.dt());
+        for _ in 0..100 {
+            fixed.advance(0.07f32, || {});
+            let alpha = fixed.interpolation_alpha();
+            assert!((0f32..1f32).contains(&alpha));
+        }
+    }
+
+    #[test]
+    fn test_advance_small_dt() {
+        let mut fixed = FixedStep::new(1f32 / 60f32);
+        let mut steps = 0;
+        for _ in 0..30 {
+            fixed.advance(1f32 / 30f32, || steps += 1);
+        }
+        assert!((59..=60).contains(&steps));
+        assert!(fixed.accumulator() < fixed.dt());
+    }
+
+    #[test]
+    #[should_panic(expected = "dt must be positive")]
+    fn test_new_zero_dt() {
+        FixedStep::new(0f32);
+    }
+}
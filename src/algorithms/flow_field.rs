@@ -0,0 +1,187 @@
+use super::vector2::Vector2;
+
+/// Vectors stored on grid nodes, indexed `[y][x]`, where node `(x, y)` sits at
+/// world position `(x * cell_size, y * cell_size)`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlowField {
+    pub vectors: Vec<Vec<Vector2>>,
+    pub cell_size: f32,
+}
+
+impl FlowField {
+    pub fn new(vectors: Vec<Vec<Vector2>>, cell_size: f32) -> Self { Self { vectors, cell_size } }
+    pub fn width(&self) -> usize { self.vectors.first().map_or(0, |row| row.len()) }
+    pub fn height(&self) -> usize { self.vectors.len() }
+
+    /// Bilinearly interpolated vector at `position`, clamped to the edge of the grid.
+    pub fn sample(&self, position: Vector2) -> Vector2 {
+        let (width, height) = (self.width(), self.height());
+        if width == 0 || height == 0 {
+            return Vector2::zero();
+        }
+        let gx = (position.x / self.cell_size).clamp(0f32, (width - 1) as f32);
+        let gy = (position.y / self.cell_size).clamp(0f32, (height - 1) as f32);
+        let (x0, y0) = (gx.floor() as usize, gy.floor() as usize);
+        let (x1, y1) = ((x0 + 1).min(width - 1), (y0 + 1).min(height - 1));
+        let (tx, ty) = (gx - x0 as f32, gy - y0 as f32);
+        let bottom = Vector2::lerp(self.vectors[y0][x0], self.vectors[y0][x1], tx);
+        let top = Vector2::lerp(self.vectors[y1][x0], self.vectors[y1][x1], tx);
+        Vector2::lerp(bottom, top, ty)
+    }
+}
+
+/// Traces a streamline from `start`, advancing `step_size` along the flow direction per
+/// step with a midpoint (RK2) integrator. Stops early where the flow vanishes.
+pub fn streamline(field: &FlowField, start: Vector2, steps: u32, step_size: f32) -> Vec<Vector2> {
+    let mut points = vec![start];
+    let mut position = start;
+    for _ in 0..steps {
+        let k1 = field.sample(position).normalized();
+        if k1 == Vector2::zero() {
+            break;
+        }
+        let k2 = field.sample(position + k1 * (step_size * 0.5f32)).normalized();
+        if k2 == Vector2::zero() {
+            break;
+        }
+        position = position + k2 * step_size;
+        points.push(position);
+    }
+    points
+}
+
+/// Central-difference divergence of a node grid with unit spacing, indexed `[y][x]`.
+/// Border nodes have no centred stencil and read zero.
+pub fn divergence(field: &[Vec<Vector2>]) -> Vec<Vec<f32>> {
+    let height = field.len();
+    let width = field.first().map_or(0, |row| row.len());
+    let mut result = vec![vec![0f32; width]; height];
+    for y in 1..height.saturating_sub(1) {
+        for x in 1..width.saturating_sub(1) {
+            result[y][x] = 0.5f32 * (field[y][x + 1].x - field[y][x - 1].x + field[y + 1][x].y - field[y - 1][x].y);
+        }
+    }
+    result
+}
+
+/// Removes the divergent part of a node grid in place by solving for a pressure field with
+/// `iterations` Jacobi sweeps and subtracting its gradient from the interior nodes.
+/// The pressure solve uses the same central differences as [`divergence`], so it converges
+/// towards zero measured divergence. Border nodes are left untouched.
+pub fn make_divergence_free(field: &mut [Vec<Vector2>], iterations: u32) {
+    let height = field.len();
+    let width = field.first().map_or(0, |row| row.len());
+    if width < 3 || height < 3 {
+        return;
+    }
+    let div = divergence(field);
+    let mut pressure = vec![vec![0f32; width]; height];
+    let mut gradient = vec![vec![Vector2::zero(); width]; height];
+    for _ in 0..iterations {
+        pressure_gradient(&pressure, &mut gradient);
+        let residual = divergence(&gradient);
+        for y in 1..height - 1 {
+            for x in 1..width - 1 {
+                // Each in-grid neighbour's gradient stencil reaches back to this node with
+                // weight 1/4, which gives the diagonal of the operator.
+                let reach = [x > 1, x < width - 2, y > 1, y < height - 2].iter().filter(|&&r| r).count();
+                if reach > 0 {
+                    pressure[y][x] -= (div[y][x] - residual[y][x]) / (0.25f32 * reach as f32);
+                }
+            }
+        }
+    }
+    pressure_gradient(&pressure, &mut gradient);
+    for y in 1..height - 1 {
+        for x in 1..width - 1 {
+            field[y][x] = field[y][x] - gradient[y][x];
+        }
+    }
+}
+
+fn pressure_gradient(pressure: &[Vec<f32>], gradient: &mut [Vec<Vector2>]) {
+    let height = pressure.len();
+    let width = pressure[0].len();
+    for y in 1..height - 1 {
+        for x in 1..width - 1 {
+            gradient[y][x] = Vector2::new(0.5f32 * (pressure[y][x + 1] - pressure[y][x - 1]),
+                                          0.5f32 * (pressure[y + 1][x] - pressure[y - 1][x]));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn uniform(value: Vector2) -> FlowField {
+        FlowField::new(vec![vec![value; 4]; 4], 1f32)
+    }
+
+    #[test]
+    fn test_sample() {
+        let field = FlowField::new(vec![
+            vec![Vector2::new(0f32, 0f32), Vector2::new(2f32, 0f32)],
+            vec![Vector2::new(0f32, 2f32), Vector2::new(2f32, 2f32)],
+        ], 2f32);
+        assert_eq!(field.sample(Vector2::new(0f32, 0f32)), Vector2::new(0f32, 0f32));
+        assert_eq!(field.sample(Vector2::new(1f32, 1f32)), Vector2::new(1f32, 1f32));
+        assert_eq!(field.sample(Vector2::new(10f32, -3f32)), Vector2::new(2f32, 0f32));
+    }
+
+    #[test]
+    fn test_streamline_in_uniform_field() {
+        let field = uniform(Vector2::new(3f32, 4f32));
+        let points = streamline(&field, Vector2::new(0.5f32, 0.5f32), 5, 0.25f32);
+        assert_eq!(points.len(), 6);
+        let direction = Vector2::new(0.6f32, 0.8f32);
+        for (i, p) in points.iter().enumerate() {
+            let expected = Vector2::new(0.5f32, 0.5f32) + direction * (0.25f32 * i as f32);
+            assert!((*p - expected).magnitude() < 1E-05f32);
+        }
+        let length = (points[5] - points[0]).magnitude();
+        assert!((length - 1.25f32).abs() < 1E-05f32);
+    }
+
+    fn total_divergence(field: &[Vec<Vector2>]) -> f32 {
+        divergence(field).iter().flatten().map(|d| d.abs()).sum()
+    }
+
+    #[test]
+    fn test_divergence() {
+        let field: Vec<Vec<Vector2>> = (0..5)
+            .map(|y| (0..5).map(|x| Vector2::new(x as f32, y as f32)).collect())
+            .collect();
+        let div = divergence(&field);
+        assert_eq!(div[2][2], 2f32);
+        assert_eq!(div[0][2], 0f32);
+    }
+
+    #[test]
+    fn test_make_divergence_free() {
+        let mut field: Vec<Vec<Vector2>> = (0..16)
+            .map(|y| (0..16).map(|x| Vector2::new(x as f32 - 7.5f32, y as f32 - 7.5f32) * 0.1f32).collect())
+            .collect();
+        let before = total_divergence(&field);
+        make_divergence_free(&mut field, 200);
+        let after = total_divergence(&field);
+        assert!(after < before * 0.05f32, "divergence {} -> {}", before, after);
+    }
+
+    #[test]
+    fn test_make_divergence_free_keeps_solenoidal_field() {
+        let mut field: Vec<Vec<Vector2>> = (0..8)
+            .map(|y| (0..8).map(|x| Vector2::new(-(y as f32), x as f32)).collect())
+            .collect();
+        let original = field.clone();
+        make_divergence_free(&mut field, 50);
+        assert_eq!(field, original);
+    }
+
+    #[test]
+    fn test_streamline_stops_on_zero_flow() {
+        let field = uniform(Vector2::zero());
+        let points = streamline(&field, Vector2::one(), 10, 0.5f32);
+        assert_eq!(points, vec![Vector2::one()]);
+    }
+}
@@ -0,0 +1,95 @@
+use super::vector3::Vector3;
+
+/// `(tangent, normal, binormal)` at every point of a space curve, carrying the first normal
+/// along by parallel transport so the frame twists as little as possible. The first normal
+/// points towards the curve's initial bend, so a planar curve keeps its plane normal as
+/// binormal; a straight start falls back to the world axis least aligned with it.
+pub fn parallel_transport_frames(points: &[Vector3]) -> Vec<(Vector3, Vector3, Vector3)> {
+    let n = points.len();
+    if n < 2 {
+        return Vec::new();
+    }
+    let tangents: Vec<Vector3> = (0..n).map(|i| (points[(i + 1).min(n - 1)] - points[i.saturating_sub(1)]).normalized()).collect();
+
+    let bend = tangents.windows(2).map(|w| Vector3::cross(w[0], w[1])).find(|b| b.magnitude() > 1E-04f32);
+    let seed = match bend {
+        Some(binormal) => Vector3::cross(binormal.normalized(), tangents[0]),
+        None => {
+            let axes = [Vector3::right(), Vector3::up(), Vector3::forward()];
+            axes.iter().copied().min_by(|a, b| Vector3::dot(*a, tangents[0]).abs().total_cmp(&Vector3::dot(*b, tangents[0]).abs())).unwrap()
+        }
+    };
+    let mut normal = (seed - tangents[0] * Vector3::dot(seed, tangents[0])).normalized();
+
+    let mut frames = Vec::with_capacity(n);
+    for i in 0..n {
+        let tangent = tangents[i];
+        if i > 0 {
+            normal = transport(normal, tangents[i - 1], tangent);
+        }
+        normal = (normal - tangent * Vector3::dot(normal, tangent)).normalized();
+        frames.push((tangent, normal, Vector3::cross(tangent, normal)));
+    }
+    frames
+}
+
+/// Rotates `v` by the rotation taking `from` onto `to` (Rodrigues' formula).
+fn transport(v: Vector3, from: Vector3, to: Vector3) -> Vector3 {
+    let axis = Vector3::cross(from, to);
+    let sin = axis.magnitude();
+    if sin < 1E-06f32 {
+        return v;
+    }
+    let axis = axis * (1f32 / sin);
+    let cos = Vector3::dot(from, to);
+    v * cos + Vector3::cross(axis, v) * sin + axis * (Vector3::dot(axis, v) * (1f32 - cos))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::approx::ApproxEq;
+
+    fn assert_orthonormal(&(t, n, b): &(Vector3, Vector3, Vector3)) {
+        for v in [t, n, b] {
+            assert!(v.magnitude().approx_eq(&1f32, 1E-05f32));
+        }
+        for (a, b) in [(t, n), (n, b), (t, b)] {
+            assert!(Vector3::dot(a, b).abs() < 1E-05f32);
+        }
+    }
+
+    #[test]
+    fn test_helix_frames_are_orthonormal() {
+        let helix: Vec<Vector3> = (0..100).map(|i| {
+            let angle = i as f32 * 0.2f32;
+            Vector3::new(angle.cos() * 3f32, angle.sin() * 3f32, i as f32 * 0.1f32)
+        }).collect();
+        let frames = parallel_transport_frames(&helix);
+        assert_eq!(frames.len(), helix.len());
+        frames.iter().for_each(assert_orthonormal);
+    }
+
+    #[test]
+    fn test_planar_curve_keeps_binormal() {
+        let arc: Vec<Vector3> = (0..50).map(|i| {
+            let angle = (i as f32 * 4f32).to_radians();
+            Vector3::new(angle.cos() * 5f32, angle.sin() * 5f32, 0f32)
+        }).collect();
+        let frames = parallel_transport_frames(&arc);
+        let first = frames[0].2;
+        assert!(Vector3::dot(first, Vector3::forward()).abs().approx_eq(&1f32, 1E-05f32));
+        for frame in &frames {
+            assert_orthonormal(frame);
+            assert!(frame.2.approx_eq(&first, 1E-04f32));
+        }
+    }
+
+    #[test]
+    fn test_straight_line_has_constant_frame() {
+        let line = [Vector3::zero(), Vector3::new(1f32, 1f32, 0f32), Vector3::new(2f32, 2f32, 0f32)];
+        let frames = parallel_transport_frames(&line);
+        assert!(frames.iter().all(|frame| frame.1.approx_eq(&frames[0].1, 1E-06f32)));
+        assert!(parallel_transport_frames(&line[..1]).is_empty());
+    }
+}
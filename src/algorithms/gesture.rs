@@ -0,0 +1,143 @@
+use super::matrix2x2::Matrix2x2;
+use super::polyline::resample_to_count;
+use super::rect::Rect;
+use super::vector2::Vector2;
+
+/// Points every stroke is resampled to.
+const SAMPLE_COUNT: usize = 64;
+/// Side of the square strokes are scaled into.
+const SQUARE_SIZE: f32 = 250f32;
+/// Search range and precision, in degrees, of the best-rotation search.
+const ANGLE_RANGE: f32 = 45f32;
+const ANGLE_PRECISION: f32 = 2f32;
+
+/// $1 unistroke recognizer. Strokes are resampled, rotated so the centroid-to-start direction
+/// points along +x, scaled into a square and centred, then compared with each template by mean
+/// point distance at the best rotation within ±45°.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GestureRecognizer {
+    templates: Vec<(String, Vec<Vector2>)>,
+}
+
+impl GestureRecognizer {
+    pub fn new() -> Self { Self::default() }
+
+    /// Strokes with fewer than two distinct points are ignored.
+    pub fn add_template(&mut self, name: impl Into<String>, points: &[Vector2]) {
+        if let Some(normalized) = normalize(points) {
+            self.templates.push((name.into(), normalized));
+        }
+    }
+
+    /// Best matching template and its score in `[0, 1]`, 1 being a perfect match. `None` with no
+    /// templates or a degenerate stroke.
+    pub fn recognize(&self, points: &[Vector2]) -> Option<(String, f32)> {
+        let candidate = normalize(points)?;
+        let half_diagonal = 0.5f32 * (2f32 * SQUARE_SIZE * SQUARE_SIZE).sqrt();
+        self.templates
+            .iter()
+            .map(|(name, template)| (name, distance_at_best_angle(&candidate, template)))
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(name, distance)| (name.clone(), 1f32 - distance / half_diagonal))
+    }
+}
+
+fn normalize(points: &[Vector2]) -> Option<Vec<Vector2>> {
+    let bounds = Rect::from_points(points)?;
+    if bounds.width == 0f32 && bounds.height == 0f32 {
+        return None;
+    }
+    let resampled = resample_to_count(points, SAMPLE_COUNT);
+    let centroid = Vector2::centroid(&resampled)?;
+    let rotated = rotate(&resampled, centroid, -(resampled[0] - centroid).to_angle());
+    // Scaling each axis to the square stretches near-1D strokes; guard the flat axis.
+    let size = Rect::from_points(&rotated)?.size().map(|s| if s > 1E-06f32 { s } else { 1f32 });
+    let scaled: Vec<Vector2> = rotated.iter().map(|&p| Vector2::new(p.x / size.x, p.y / size.y) * SQUARE_SIZE).collect();
+    let centroid = Vector2::centroid(&scaled)?;
+    Some(scaled.iter().map(|&p| p - centroid).collect())
+}
+
+fn rotate(points: &[Vector2], center: Vector2, degrees: f32) -> Vec<Vector2> {
+    let rotation = Matrix2x2::rotation(degrees);
+    points.iter().map(|&p| center + rotation * (p - center)).collect()
+}
+
+fn path_distance(a: &[Vector2], b: &[Vector2]) -> f32 {
+    a.iter().zip(b).map(|(&p, &q)| (p - q).magnitude()).sum::<f32>() / a.len() as f32
+}
+
+/// Golden-section search for the rotation of `candidate` closest to `template`.
+fn distance_at_best_angle(candidate: &[Vector2], template: &[Vector2]) -> f32 {
+    let phi = 0.5f32 * (5f32.sqrt() - 1f32);
+    let distance_at = |degrees: f32| path_distance(&rotate(candidate, Vector2::zero(), degrees), template);
+    let (mut low, mut high) = (-ANGLE_RANGE, ANGLE_RANGE);
+    let mut x1 = phi * low + (1f32 - phi) * high;
+    let mut x2 = (1f32 - phi) * low + phi * high;
+    let (mut f1, mut f2) = (distance_at(x1), distance_at(x2));
+    while (high - low).abs() > ANGLE_PRECISION {
+        if f1 < f2 {
+            high = x2;
+            (x2, f2) = (x1, f1);
+            x1 = phi * low + (1f32 - phi) * high;
+            f1 = distance_at(x1);
+        } else {
+            low = x1;
+            (x1, f1) = (x2, f2);
+            x2 = (1f32 - phi) * low + phi * high;
+            f2 = distance_at(x2);
+        }
+    }
+    f1.min(f2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn circle() -> Vec<Vector2> { (0..=40).map(|i| Vector2::from_angle(i as f32 * 9f32) * 50f32).collect() }
+    fn triangle() -> Vec<Vector2> {
+        let corners = [Vector2::new(0f32, 0f32), Vector2::new(100f32, 0f32), Vector2::new(50f32, 80f32), Vector2::new(0f32, 0f32)];
+        corners.windows(2).flat_map(|w| (0..10).map(move |i| Vector2::lerp(w[0], w[1], i as f32 / 10f32))).collect()
+    }
+    fn zigzag() -> Vec<Vector2> { (0..8).map(|i| Vector2::new(i as f32 * 20f32, if i % 2 == 0 { 0f32 } else { 40f32 })).collect() }
+
+    fn recognizer() -> GestureRecognizer {
+        let mut recognizer = GestureRecognizer::new();
+        recognizer.add_template("circle", &circle());
+        recognizer.add_template("triangle", &triangle());
+        recognizer.add_template("zigzag", &zigzag());
+        recognizer
+    }
+
+    #[test]
+    fn test_recognizes_exact_template() {
+        let (name, score) = recognizer().recognize(&triangle()).unwrap();
+        assert_eq!(name, "triangle");
+        assert!(score > 0.99f32);
+    }
+
+    #[test]
+    fn test_recognizes_noisy_variants() {
+        let recognizer = recognizer();
+        let rotation = Matrix2x2::rotation(20f32);
+        for (expected, stroke) in [("circle", circle()), ("triangle", triangle()), ("zigzag", zigzag())] {
+            let noisy: Vec<Vector2> = stroke
+                .iter()
+                .enumerate()
+                .map(|(i, &p)| rotation * (p * 0.6f32) + Vector2::new(300f32, -40f32) + Vector2::new((i as f32 * 1.7f32).sin(), (i as f32 * 2.3f32).cos()) * 2f32)
+                .collect();
+            let (name, score) = recognizer.recognize(&noisy).unwrap();
+            assert_eq!(name, expected);
+            assert!(score > 0.8f32 && score <= 1f32, "{} scored {}", name, score);
+        }
+    }
+
+    #[test]
+    fn test_recognize_degenerate() {
+        assert_eq!(GestureRecognizer::new().recognize(&circle()), None);
+        assert_eq!(recognizer().recognize(&[Vector2::one(), Vector2::one()]), None);
+        let mut empty = GestureRecognizer::new();
+        empty.add_template("dot", &[Vector2::one()]);
+        assert_eq!(empty, GestureRecognizer::new());
+    }
+}
@@ -0,0 +1,19 @@
+use std::cmp::Ordering;
+
+/// Entry for a [`BinaryHeap`](std::collections::BinaryHeap) that pops the lowest `cost` first,
+/// as Dijkstra-style searches need.
+#[derive(PartialEq)]
+pub(crate) struct MinCost<T> {
+    pub cost: f32,
+    pub item: T,
+}
+
+impl<T: PartialEq> Eq for MinCost<T> {}
+
+impl<T: PartialEq> PartialOrd for MinCost<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) }
+}
+
+impl<T: PartialEq> Ord for MinCost<T> {
+    fn cmp(&self, other: &Self) -> Ordering { other.cost.total_cmp(&self.cost) }
+}
@@ -0,0 +1,235 @@
+use super::vector2::Vector2;
+
+/// Convex hull in counter-clockwise order, starting at the lowest-x (then lowest-y) point.
+/// Collinear and duplicate points are dropped; fewer than three distinct points, or all of
+/// them collinear, give the distinct extremes.
+pub fn convex_hull(points: &[Vector2]) -> Vec<Vector2> {
+    let mut sorted = points.to_vec();
+    sorted.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+    sorted.dedup();
+    if sorted.len() < 3 {
+        return sorted;
+    }
+    let mut lower: Vec<Vector2> = Vec::new();
+    for &p in &sorted {
+        while lower.len() >= 2 && turn(lower[lower.len() - 2], lower[lower.len() - 1], p) <= 0f32 {
+            lower.pop();
+        }
+        lower.push(p);
+    }
+    let mut upper: Vec<Vector2> = Vec::new();
+    for &p in sorted.iter().rev() {
+        while upper.len() >= 2 && turn(upper[upper.len() - 2], upper[upper.len() - 1], p) <= 0f32 {
+            upper.pop();
+        }
+        upper.push(p);
+    }
+    lower.pop();
+    upper.pop();
+    lower.extend(upper);
+    lower
+}
+
+fn turn(a: Vector2, b: Vector2, c: Vector2) -> f32 { Vector2::cross(b - a, c - a) }
+
+/// Convex hull maintained under insertion. Points inside the current hull are ignored; points
+/// outside replace the chain of edges they can see. The hull is kept in the same order as
+/// [`convex_hull`] returns.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct IncrementalHull {
+    hull: Vec<Vector2>,
+}
+
+impl IncrementalHull {
+    pub fn new() -> Self { Self::default() }
+    pub fn hull(&self) -> &[Vector2] { &self.hull }
+
+    pub fn insert(&mut self, point: Vector2) {
+        let n = self.hull.len();
+        if n < 3 {
+            let mut points = std::mem::take(&mut self.hull);
+            points.push(point);
+            self.hull = convex_hull(&points);
+            return;
+        }
+        // An edge is visible when the point lies on its outer side or on its line.
+        let visible: Vec<bool> = (0..n).map(|i| turn(self.hull[i], self.hull[(i + 1) % n], point) <= 0f32).collect();
+        let inside = (0..n).all(|i| turn(self.hull[i], self.hull[(i + 1) % n], point) >= 0f32);
+        if inside {
+            return;
+        }
+        let start = (0..n).find(|&i| visible[i] && !visible[(i + n - 1) % n]).unwrap();
+        let run = (0..n).take_while(|&k| visible[(start + k) % n]).count();
+        // Keep the vertices from the end of the visible run round to its start, then the point.
+        let mut hull: Vec<Vector2> = (0..=n - run).map(|k| self.hull[(start + run + k) % n]).collect();
+        hull.push(point);
+        let first = (0..hull.len()).min_by(|&a, &b| hull[a].x.total_cmp(&hull[b].x).then(hull[a].y.total_cmp(&hull[b].y))).unwrap();
+        hull.rotate_left(first);
+        self.hull = hull;
+    }
+}
+
+/// Convex hull of `points` pushed outward by `margin` with mitred corners, so it contains
+/// every point within `margin` of the hull. Fewer than three hull points give the
+/// margin-padded box around the point or segment.
+pub fn inflated_hull(points: &[Vector2], margin: f32) -> Vec<Vector2> {
+    let hull = convex_hull(points);
+    match hull.len() {
+        0 => Vec::new(),
+        1 | 2 => {
+            let (a, b) = (hull[0], *hull.last().unwrap());
+            let direction = if a == b { Vector2::right() } else { (b - a).normalized() };
+            let (along, across) = (direction * margin, direction.perpendicular() * margin);
+            vec![a - along - across, b + along - across, b + along + across, a - along + across]
+        }
+        n => (0..n)
+            .map(|i| {
+                let (before, after) = (outward_normal(hull[(i + n - 1) % n], hull[i]), outward_normal(hull[i], hull[(i + 1) % n]));
+                hull[i] + (before + after) * (margin / (1f32 + Vector2::dot(before, after)))
+            })
+            .collect(),
+    }
+}
+
+/// Like [`inflated_hull`] but each corner is an arc of `arc_segments` segments around the hull
+/// vertex, so every vertex lies exactly `margin` from the hull.
+pub fn inflated_hull_rounded(points: &[Vector2], margin: f32, arc_segments: u32) -> Vec<Vector2> {
+    let hull = convex_hull(points);
+    let segments = arc_segments.max(1);
+    if hull.len() == 1 {
+        return (0..segments * 4).map(|k| hull[0] + Vector2::from_angle(k as f32 * 90f32 / segments as f32) * margin).collect();
+    }
+    let n = hull.len();
+    let mut result = Vec::with_capacity(n * (segments as usize + 1));
+    for i in 0..n {
+        let (before, after) = (outward_normal(hull[(i + n - 1) % n], hull[i]), outward_normal(hull[i], hull[(i + 1) % n]));
+        let (start, sweep) = (before.to_angle(), Vector2::signed_angle(before, after).rem_euclid(360f32));
+        for k in 0..=segments {
+            result.push(hull[i] + Vector2::from_angle(start + sweep * k as f32 / segments as f32) * margin);
+        }
+    }
+    result
+}
+
+/// Unit normal on the outer side of a counter-clockwise edge.
+fn outward_normal(a: Vector2, b: Vector2) -> Vector2 {
+    let direction = (b - a).normalized();
+    Vector2::new(direction.y, -direction.x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::test_support::Lcg;
+
+    #[test]
+    fn test_convex_hull_square() {
+        let points = [
+            Vector2::new(1f32, 1f32),
+            Vector2::new(0f32, 0f32),
+            Vector2::new(2f32, 0f32),
+            Vector2::new(1f32, 0f32),
+            Vector2::new(2f32, 2f32),
+            Vector2::new(0f32, 2f32),
+            Vector2::new(0f32, 0f32),
+        ];
+        let expected = [Vector2::new(0f32, 0f32), Vector2::new(2f32, 0f32), Vector2::new(2f32, 2f32), Vector2::new(0f32, 2f32)];
+        assert_eq!(convex_hull(&points), expected);
+    }
+
+    #[test]
+    fn test_convex_hull_degenerate() {
+        assert!(convex_hull(&[]).is_empty());
+        assert_eq!(convex_hull(&[Vector2::one(), Vector2::one()]), vec![Vector2::one()]);
+        let line = [Vector2::zero(), Vector2::new(2f32, 2f32), Vector2::one()];
+        assert_eq!(convex_hull(&line), vec![Vector2::zero(), Vector2::new(2f32, 2f32)]);
+    }
+
+    #[test]
+    fn test_incremental_hull_matches_batch() {
+        let mut rng = Lcg(5);
+        let mut hull = IncrementalHull::new();
+        let mut points = Vec::new();
+        for _ in 0..300 {
+            let point = Vector2::new(rng.next() * 10f32, rng.next() * 10f32);
+            points.push(point);
+            hull.insert(point);
+            assert_eq!(hull.hull(), convex_hull(&points).as_slice());
+        }
+    }
+
+    #[test]
+    fn test_incremental_hull_inside_and_extending() {
+        let mut hull = IncrementalHull::new();
+        for point in [Vector2::zero(), Vector2::new(4f32, 0f32), Vector2::new(0f32, 4f32)] {
+            hull.insert(point);
+        }
+        let before = hull.hull().to_vec();
+        hull.insert(Vector2::new(1f32, 1f32));
+        hull.insert(Vector2::new(2f32, 0f32));
+        assert_eq!(hull.hull(), before.as_slice());
+        hull.insert(Vector2::new(4f32, 4f32));
+        assert_eq!(hull.hull(), [Vector2::zero(), Vector2::new(4f32, 0f32), Vector2::new(4f32, 4f32), Vector2::new(0f32, 4f32)]);
+        // Extending an edge's line removes the vertex that becomes collinear.
+        hull.insert(Vector2::new(6f32, 0f32));
+        assert_eq!(hull.hull(), [Vector2::zero(), Vector2::new(6f32, 0f32), Vector2::new(4f32, 4f32), Vector2::new(0f32, 4f32)]);
+    }
+
+    #[test]
+    fn test_incremental_hull_collinear_start() {
+        let mut hull = IncrementalHull::new();
+        for point in [Vector2::zero(), Vector2::one(), Vector2::new(2f32, 2f32), Vector2::new(2f32, 0f32)] {
+            hull.insert(point);
+        }
+        assert_eq!(hull.hull(), [Vector2::zero(), Vector2::new(2f32, 0f32), Vector2::new(2f32, 2f32)]);
+    }
+
+    #[test]
+    fn test_inflated_hull_contains_margin() {
+        let mut rng = Lcg(17);
+        let points: Vec<Vector2> = (0..40).map(|_| Vector2::new(rng.next() * 6f32, rng.next() * 3f32)).collect();
+        let hull = convex_hull(&points);
+        let inflated = inflated_hull(&points, 0.5f32);
+        assert_eq!(inflated.len(), hull.len());
+        let contains = |polygon: &[Vector2], p: Vector2| (0..polygon.len()).all(|i| turn(polygon[i], polygon[(i + 1) % polygon.len()], p) >= -1E-04f32);
+        for &vertex in &hull {
+            for k in 0..16 {
+                assert!(contains(&inflated, vertex + Vector2::from_angle(k as f32 * 22.5f32) * 0.5f32));
+            }
+        }
+        // Each inflated edge sits exactly `margin` outside the matching hull edge.
+        for i in 0..hull.len() {
+            let (a, b) = (hull[i], hull[(i + 1) % hull.len()]);
+            let distance = turn(a, b, inflated[i]) / (b - a).magnitude();
+            assert!((distance + 0.5f32).abs() < 1E-04f32);
+        }
+    }
+
+    #[test]
+    fn test_inflated_hull_degenerate() {
+        assert!(inflated_hull(&[], 1f32).is_empty());
+        let box_around = [Vector2::new(-1f32, -1f32), Vector2::new(1f32, -1f32), Vector2::new(1f32, 1f32), Vector2::new(-1f32, 1f32)];
+        assert_eq!(inflated_hull(&[Vector2::zero()], 1f32), box_around);
+        let segment = inflated_hull(&[Vector2::zero(), Vector2::new(2f32, 0f32)], 0.5f32);
+        assert_eq!(segment[0], Vector2::new(-0.5f32, -0.5f32));
+        assert_eq!(segment[2], Vector2::new(2.5f32, 0.5f32));
+    }
+
+    #[test]
+    fn test_inflated_hull_rounded() {
+        let square = [Vector2::zero(), Vector2::new(2f32, 0f32), Vector2::new(2f32, 2f32), Vector2::new(0f32, 2f32)];
+        let rounded = inflated_hull_rounded(&square, 0.5f32, 4);
+        assert_eq!(rounded.len(), 4 * 5);
+        for &p in &rounded {
+            let distance = (p - Vector2::new(p.x.clamp(0f32, 2f32), p.y.clamp(0f32, 2f32))).magnitude();
+            assert!((distance - 0.5f32).abs() < 1E-05f32);
+        }
+        // The first corner sweeps from the left edge's normal round to the bottom edge's.
+        assert!((rounded[0] - Vector2::new(-0.5f32, 0f32)).magnitude() < 1E-05f32);
+        assert!((rounded[2] - Vector2::from_angle(225f32) * 0.5f32).magnitude() < 1E-05f32);
+        assert!((rounded[4] - Vector2::new(0f32, -0.5f32)).magnitude() < 1E-05f32);
+        let circle = inflated_hull_rounded(&[Vector2::one()], 2f32, 3);
+        assert_eq!(circle.len(), 12);
+        assert!(circle.iter().all(|&p| ((p - Vector2::one()).magnitude() - 2f32).abs() < 1E-05f32));
+    }
+}
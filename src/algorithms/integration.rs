@@ -0,0 +1,43 @@
+use super::vector2::Vector2;
+
+/// Advances `state` by `dt` with the classic fourth-order Runge-Kutta scheme for an
+/// autonomous system `state' = derivative(state)`.
+pub fn rk4_step(state: Vector2, derivative: impl Fn(Vector2) -> Vector2, dt: f32) -> Vector2 {
+    let k1 = derivative(state);
+    let k2 = derivative(state + k1 * (dt * 0.5f32));
+    let k3 = derivative(state + k2 * (dt * 0.5f32));
+    let k4 = derivative(state + k3 * dt);
+    state + (k1 + k2 * 2f32 + k3 * 2f32 + k4) * (dt / 6f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rotation(v: Vector2) -> Vector2 { Vector2::new(-v.y, v.x) }
+
+    #[test]
+    fn test_rk4_constant_derivative() {
+        let result = rk4_step(Vector2::zero(), |_| Vector2::new(1f32, 2f32), 0.5f32);
+        assert_eq!(result, Vector2::new(0.5f32, 1f32));
+    }
+
+    #[test]
+    fn test_rk4_circular_motion() {
+        let dt = 0.1f32;
+        let steps = 100;
+        let mut rk4 = Vector2::right();
+        let mut euler = Vector2::right();
+        for _ in 0..steps {
+            rk4 = rk4_step(rk4, rotation, dt);
+            euler = euler + rotation(euler) * dt;
+        }
+        let t = dt * steps as f32;
+        let exact = Vector2::new(t.cos(), t.sin());
+        let rk4_error = (rk4 - exact).magnitude();
+        let euler_error = (euler - exact).magnitude();
+        assert!(rk4_error < 1E-04f32);
+        assert!(rk4_error * 1000f32 < euler_error);
+        assert!((rk4.magnitude() - 1f32).abs() < 1E-04f32);
+    }
+}
@@ -0,0 +1,177 @@
+use std::ops::Mul;
+
+use super::validate::debug_validate;
+use super::vector2::Vector2;
+
+/// Linear 2D transform acting on column vectors: `x' = m00 * x + m01 * y`,
+/// `y' = m10 * x + m11 * y`. `a * b` applies `b` first, then `a`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Matrix2x2 {
+    pub m00: f32,
+    pub m01: f32,
+    pub m10: f32,
+    pub m11: f32,
+}
+
+impl Matrix2x2 {
+    pub fn new(m00: f32, m01: f32, m10: f32, m11: f32) -> Self { Self { m00, m01, m10, m11 } }
+    pub fn identity() -> Self { Self::new(1f32, 0f32, 0f32, 1f32) }
+    pub fn scale(scale: Vector2) -> Self { Self::new(scale.x, 0f32, 0f32, scale.y) }
+    /// Counter-clockwise rotation by `degrees`.
+    pub fn rotation(degrees: f32) -> Self {
+        let (sin, cos) = degrees.to_radians().sin_cos();
+        Self::new(cos, -sin, sin, cos)
+    }
+    pub fn determinant(&self) -> f32 { self.m00 * self.m11 - self.m01 * self.m10 }
+    pub fn transpose(&self) -> Self { Self::new(self.m00, self.m10, self.m01, self.m11) }
+
+    pub fn inverse(&self) -> Option<Self> {
+        debug_validate!([self.m00, self.m01, self.m10, self.m11].iter().all(|m| m.is_finite()),
+                        "Matrix2x2::inverse called on non-finite matrix {:?}", self);
+        let det = self.determinant();
+        if det.abs() < 1E-12f32 {
+            return None;
+        }
+        let inv = 1f32 / det;
+        Some(Self::new(self.m11 * inv, -self.m01 * inv, -self.m10 * inv, self.m00 * inv))
+    }
+
+    /// Splits into `(orthogonal, stretch)` with `self == orthogonal * stretch`, where `stretch`
+    /// is symmetric. The orthogonal factor is a rotation unless the determinant is negative,
+    /// in which case it is a reflection.
+    pub fn polar_decompose(&self) -> (Matrix2x2, Matrix2x2) {
+        let (x, y, mirrored) = if self.determinant() >= 0f32 {
+            (self.m00 + self.m11, self.m10 - self.m01, false)
+        } else {
+            (self.m00 - self.m11, self.m10 + self.m01, true)
+        };
+        let length = (x * x + y * y).sqrt();
+        let orthogonal = if length < 1E-12f32 {
+            Self::identity()
+        } else if mirrored {
+            Self::new(x / length, y / length, y / length, -x / length)
+        } else {
+            Self::new(x / length, -y / length, y / length, x / length)
+        };
+        let mut stretch = orthogonal.transpose() * *self;
+        // Remove rounding asymmetry so callers can rely on a symmetric stretch.
+        let off_diagonal = 0.5f32 * (stretch.m01 + stretch.m10);
+        stretch.m01 = off_diagonal;
+        stretch.m10 = off_diagonal;
+        (orthogonal, stretch)
+    }
+}
+
+impl Mul for Matrix2x2 {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self::new(
+            self.m00 * other.m00 + self.m01 * other.m10,
+            self.m00 * other.m01 + self.m01 * other.m11,
+            self.m10 * other.m00 + self.m11 * other.m10,
+            self.m10 * other.m01 + self.m11 * other.m11,
+        )
+    }
+}
+
+impl Mul<Vector2> for Matrix2x2 {
+    type Output = Vector2;
+
+    fn mul(self, v: Vector2) -> Vector2 {
+        Vector2::new(self.m00 * v.x + self.m01 * v.y, self.m10 * v.x + self.m11 * v.y)
+    }
+}
+
+/// True when `m` is a rotation combined with a non-zero uniform scale, i.e. it preserves
+/// angles and orientation. Shears, non-uniform scales and mirrors are rejected.
+pub fn is_conformal(m: &Matrix2x2, eps: f32) -> bool {
+    (m.m00 - m.m11).abs() <= eps && (m.m01 + m.m10).abs() <= eps && m.determinant() > eps * eps
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::approx::ApproxEq;
+
+    #[test]
+    fn test_constructors() {
+        let v = Vector2::new(1f32, 2f32);
+        assert_eq!(Matrix2x2::identity() * v, v);
+        assert_eq!(Matrix2x2::scale(Vector2::new(3f32, -1f32)) * v, Vector2::new(3f32, -2f32));
+        assert!((Matrix2x2::rotation(90f32) * v).approx_eq(&Vector2::new(-2f32, 1f32), 1E-06f32));
+    }
+
+    #[test]
+    fn test_multiplication_and_transpose() {
+        let a = Matrix2x2::new(1f32, 2f32, 3f32, 4f32);
+        let b = Matrix2x2::new(0f32, 1f32, 1f32, 0f32);
+        assert_eq!(a * b, Matrix2x2::new(2f32, 1f32, 4f32, 3f32));
+        assert_eq!(a.transpose(), Matrix2x2::new(1f32, 3f32, 2f32, 4f32));
+        let v = Vector2::new(1f32, -1f32);
+        assert_eq!((a * b) * v, a * (b * v));
+    }
+
+    #[test]
+    fn test_inverse() {
+        let m = Matrix2x2::new(2f32, 1f32, 1f32, 3f32);
+        assert!((m * m.inverse().unwrap()).approx_eq(&Matrix2x2::identity(), 1E-06f32));
+        assert_eq!(m.determinant(), 5f32);
+        assert_eq!(Matrix2x2::new(1f32, 2f32, 2f32, 4f32).inverse(), None);
+    }
+
+    #[test]
+    fn test_is_conformal() {
+        let rotation_scale = Matrix2x2::rotation(33f32) * Matrix2x2::scale(Vector2::new(2.5f32, 2.5f32));
+        assert!(is_conformal(&rotation_scale, 1E-05f32));
+        assert!(is_conformal(&Matrix2x2::identity(), 1E-05f32));
+        let shear = Matrix2x2::new(1f32, 0.5f32, 0f32, 1f32);
+        assert!(!is_conformal(&shear, 1E-05f32));
+        assert!(!is_conformal(&Matrix2x2::scale(Vector2::new(1f32, 2f32)), 1E-05f32));
+        assert!(!is_conformal(&Matrix2x2::scale(Vector2::new(1f32, -1f32)), 1E-05f32));
+        assert!(!is_conformal(&Matrix2x2::scale(Vector2::zero()), 1E-05f32));
+    }
+
+    fn assert_polar(m: Matrix2x2) {
+        let (orthogonal, stretch) = m.polar_decompose();
+        assert!((orthogonal.transpose() * orthogonal).approx_eq(&Matrix2x2::identity(), 1E-05f32));
+        assert_eq!(stretch.m01, stretch.m10);
+        assert!((orthogonal * stretch).approx_eq(&m, 1E-04f32));
+        assert!(stretch.m00 + stretch.m11 >= 0f32);
+    }
+
+    #[test]
+    fn test_polar_decompose() {
+        let rotation = Matrix2x2::rotation(40f32);
+        let stretch = Matrix2x2::new(2f32, 0.3f32, 0.3f32, 1f32);
+        let (r, s) = (rotation * stretch).polar_decompose();
+        assert!(r.approx_eq(&rotation, 1E-05f32));
+        assert!(s.approx_eq(&stretch, 1E-05f32));
+        assert!((r.determinant() - 1f32).abs() < 1E-05f32);
+        assert_polar(Matrix2x2::new(1f32, 0.5f32, 0f32, 1f32));
+        assert_polar(Matrix2x2::new(3f32, -1f32, 2f32, 0.5f32));
+        assert_polar(Matrix2x2::identity());
+    }
+
+    #[test]
+    fn test_polar_decompose_mirror() {
+        let m = Matrix2x2::rotation(70f32) * Matrix2x2::scale(Vector2::new(-2f32, 1f32));
+        assert_polar(m);
+        let (orthogonal, _) = m.polar_decompose();
+        assert!((orthogonal.determinant() + 1f32).abs() < 1E-05f32);
+    }
+
+    #[test]
+    fn test_polar_decompose_zero() {
+        let (orthogonal, stretch) = Matrix2x2::new(0f32, 0f32, 0f32, 0f32).polar_decompose();
+        assert_eq!(orthogonal, Matrix2x2::identity());
+        assert_eq!(stretch, Matrix2x2::new(0f32, 0f32, 0f32, 0f32));
+    }
+
+    #[cfg(all(feature = "validate", debug_assertions))]
+    #[test]
+    #[should_panic(expected = "Matrix2x2::inverse called on non-finite matrix")]
+    fn test_validate_inverse() {
+        let _ = Matrix2x2::new(f32::INFINITY, 0f32, 0f32, 1f32).inverse();
+    }
+}
@@ -0,0 +1,152 @@
+use std::ops::Mul;
+
+use super::validate::debug_validate;
+use super::vector2::Vector2;
+
+/// 2D affine transform acting on column vectors:
+/// `x' = m00 * x + m01 * y + m02`, `y' = m10 * x + m11 * y + m12`.
+/// `a * b` applies `b` first, then `a`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Matrix3x2 {
+    pub m00: f32,
+    pub m01: f32,
+    pub m02: f32,
+    pub m10: f32,
+    pub m11: f32,
+    pub m12: f32,
+}
+
+impl Matrix3x2 {
+    pub fn new(m00: f32, m01: f32, m02: f32, m10: f32, m11: f32, m12: f32) -> Self { Self { m00, m01, m02, m10, m11, m12 } }
+    pub fn identity() -> Self { Self::new(1f32, 0f32, 0f32, 0f32, 1f32, 0f32) }
+    pub fn translation(offset: Vector2) -> Self { Self::new(1f32, 0f32, offset.x, 0f32, 1f32, offset.y) }
+    pub fn scale(scale: Vector2) -> Self { Self::new(scale.x, 0f32, 0f32, 0f32, scale.y, 0f32) }
+    /// Counter-clockwise rotation by `degrees`.
+    pub fn rotation(degrees: f32) -> Self {
+        let (sin, cos) = degrees.to_radians().sin_cos();
+        Self::new(cos, -sin, 0f32, sin, cos, 0f32)
+    }
+    pub fn get_translation(&self) -> Vector2 { Vector2::new(self.m02, self.m12) }
+    pub fn determinant(&self) -> f32 { self.m00 * self.m11 - self.m01 * self.m10 }
+
+    pub fn transform_point(&self, p: Vector2) -> Vector2 {
+        Vector2::new(self.m00 * p.x + self.m01 * p.y + self.m02, self.m10 * p.x + self.m11 * p.y + self.m12)
+    }
+
+    /// Applies the linear part only, ignoring translation.
+    pub fn transform_vector(&self, v: Vector2) -> Vector2 {
+        Vector2::new(self.m00 * v.x + self.m01 * v.y, self.m10 * v.x + self.m11 * v.y)
+    }
+
+    pub fn inverse(&self) -> Option<Self> {
+        debug_validate!([self.m00, self.m01, self.m02, self.m10, self.m11, self.m12].iter().all(|m| m.is_finite()),
+                        "Matrix3x2::inverse called on non-finite matrix {:?}", self);
+        let det = self.determinant();
+        if det.abs() < 1E-12f32 {
+            return None;
+        }
+        let inv = 1f32 / det;
+        let (a, b, c, d) = (self.m11 * inv, -self.m01 * inv, -self.m10 * inv, self.m00 * inv);
+        Some(Self::new(a, b, -(a * self.m02 + b * self.m12), c, d, -(c * self.m02 + d * self.m12)))
+    }
+}
+
+impl Mul for Matrix3x2 {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self::new(
+            self.m00 * other.m00 + self.m01 * other.m10,
+            self.m00 * other.m01 + self.m01 * other.m11,
+            self.m00 * other.m02 + self.m01 * other.m12 + self.m02,
+            self.m10 * other.m00 + self.m11 * other.m10,
+            self.m10 * other.m01 + self.m11 * other.m11,
+            self.m10 * other.m02 + self.m11 * other.m12 + self.m12,
+        )
+    }
+}
+
+pub fn transform_points(matrix: &Matrix3x2, points: &mut [Vector2]) {
+    for p in points.iter_mut() {
+        *p = matrix.transform_point(*p);
+    }
+}
+
+/// Parallel [`transform_points`], split across the rayon thread pool.
+#[cfg(feature = "rayon")]
+pub fn par_transform_points(matrix: &Matrix3x2, points: &mut [Vector2]) {
+    use rayon::prelude::*;
+
+    points.par_chunks_mut(4096).for_each(|chunk| transform_points(matrix, chunk));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::approx::ApproxEq;
+
+    #[test]
+    fn test_constructors() {
+        let p = Vector2::new(1f32, 2f32);
+        assert_eq!(Matrix3x2::identity().transform_point(p), p);
+        assert_eq!(Matrix3x2::translation(Vector2::new(3f32, -1f32)).transform_point(p), Vector2::new(4f32, 1f32));
+        assert_eq!(Matrix3x2::scale(Vector2::new(2f32, 3f32)).transform_point(p), Vector2::new(2f32, 6f32));
+        let rotated = Matrix3x2::rotation(90f32).transform_point(p);
+        assert!(rotated.approx_eq(&Vector2::new(-2f32, 1f32), 1E-06f32));
+    }
+
+    #[test]
+    fn test_multiplication_order() {
+        let translate = Matrix3x2::translation(Vector2::new(1f32, 0f32));
+        let scale = Matrix3x2::scale(Vector2::new(2f32, 2f32));
+        let p = Vector2::new(1f32, 1f32);
+        assert_eq!((translate * scale).transform_point(p), Vector2::new(3f32, 2f32));
+        assert_eq!((scale * translate).transform_point(p), Vector2::new(4f32, 2f32));
+        assert_eq!((translate * scale).transform_vector(p), Vector2::new(2f32, 2f32));
+    }
+
+    #[test]
+    fn test_inverse() {
+        let m = Matrix3x2::translation(Vector2::new(3f32, -2f32)) * Matrix3x2::rotation(30f32) * Matrix3x2::scale(Vector2::new(2f32, 0.5f32));
+        let inverse = m.inverse().unwrap();
+        assert!((m * inverse).approx_eq(&Matrix3x2::identity(), 1E-05f32));
+        assert!((inverse * m).approx_eq(&Matrix3x2::identity(), 1E-05f32));
+        assert_eq!(Matrix3x2::scale(Vector2::new(1f32, 0f32)).inverse(), None);
+        assert!((m.determinant() - 1f32).abs() < 1E-05f32);
+    }
+
+    #[test]
+    fn test_transform_points() {
+        let m = Matrix3x2::translation(Vector2::one());
+        let mut points = [Vector2::zero(), Vector2::new(2f32, 3f32)];
+        transform_points(&m, &mut points);
+        assert_eq!(points, [Vector2::one(), Vector2::new(3f32, 4f32)]);
+    }
+
+    #[cfg(all(feature = "validate", debug_assertions))]
+    #[test]
+    #[should_panic(expected = "Matrix3x2::inverse called on non-finite matrix")]
+    fn test_validate_inverse() {
+        let mut m = Matrix3x2::identity();
+        m.m01 = f32::NAN;
+        let _ = m.inverse();
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_par_transform_points_matches_serial() {
+        let m = Matrix3x2::translation(Vector2::new(0.5f32, -2f32)) * Matrix3x2::rotation(37f32);
+        let points: Vec<Vector2> = (0..20000).map(|i| Vector2::new(i as f32 * 0.01f32, (i % 13) as f32)).collect();
+        let mut serial = points.clone();
+        let mut parallel = points;
+        transform_points(&m, &mut serial);
+        par_transform_points(&m, &mut parallel);
+        assert_eq!(serial, parallel);
+
+        let mut empty: [Vector2; 0] = [];
+        par_transform_points(&m, &mut empty);
+        let mut single = [Vector2::one()];
+        par_transform_points(&m, &mut single);
+        assert_eq!(single, [m.transform_point(Vector2::one())]);
+    }
+}
@@ -0,0 +1,273 @@
+use std::collections::{BTreeSet, HashMap};
+
+use super::vector2::Vector2;
+use super::vector3::Vector3;
+
+/// Splits every triangle into four through its edge midpoints. Midpoints are shared between
+/// triangles using the same edge, and the original vertices keep their indices.
+pub fn subdivide_triangles(vertices: &[Vector2], triangles: &[[usize; 3]]) -> (Vec<Vector2>, Vec<[usize; 3]>) {
+    let mut out_vertices = vertices.to_vec();
+    let mut midpoints: HashMap<(usize, usize), usize> = HashMap::new();
+    let mut midpoint = |a: usize, b: usize, out: &mut Vec<Vector2>| {
+        *midpoints.entry((a.min(b), a.max(b))).or_insert_with(|| {
+            out.push((vertices[a] + vertices[b]) * 0.5f32);
+            out.len() - 1
+        })
+    };
+    let mut out_triangles = Vec::with_capacity(triangles.len() * 4);
+    for &[a, b, c] in triangles {
+        let ab = midpoint(a, b, &mut out_vertices);
+        let bc = midpoint(b, c, &mut out_vertices);
+        let ca = midpoint(c, a, &mut out_vertices);
+        out_triangles.extend([[a, ab, ca], [ab, b, bc], [ca, bc, c], [ab, bc, ca]]);
+    }
+    (out_vertices, out_triangles)
+}
+
+/// Moves each vertex toward the average of its edge neighbours, updating all vertices from
+/// the previous iteration's positions. With `boundary_fixed`, vertices on an edge used by only
+/// one triangle stay in place.
+pub fn laplacian_smooth(vertices: &mut [Vector2], triangles: &[[usize; 3]], iterations: u32, boundary_fixed: bool) {
+    let mut neighbours = vec![BTreeSet::new(); vertices.len()];
+    for &[a, b, c] in triangles {
+        for (p, q) in [(a, b), (b, c), (c, a)] {
+            neighbours[p].insert(q);
+            neighbours[q].insert(p);
+        }
+    }
+    let mut fixed = vec![false; vertices.len()];
+    if boundary_fixed {
+        for ((a, b), count) in edge_counts(triangles) {
+            if count == 1 {
+                fixed[a] = true;
+                fixed[b] = true;
+            }
+        }
+    }
+    for _ in 0..iterations {
+        let previous = vertices.to_vec();
+        for (i, vertex) in vertices.iter_mut().enumerate() {
+            if fixed[i] || neighbours[i].is_empty() {
+                continue;
+            }
+            let sum = neighbours[i].iter().fold(Vector2::zero(), |acc, &j| acc + previous[j]);
+            *vertex = sum * (1f32 / neighbours[i].len() as f32);
+        }
+    }
+}
+
+/// Number of triangles using each undirected edge, keyed by `(min, max)` index.
+fn edge_counts(triangles: &[[usize; 3]]) -> HashMap<(usize, usize), usize> {
+    let mut counts = HashMap::new();
+    for &[a, b, c] in triangles {
+        for (p, q) in [(a, b), (b, c), (c, a)] {
+            *counts.entry((p.min(q), p.max(q))).or_insert(0) += 1;
+        }
+    }
+    counts
+}
+
+/// Indices of triangles whose unsigned area is below `min_area`, including those with
+/// repeated vertex indices.
+pub fn find_degenerate_triangles(vertices: &[Vector2], triangles: &[[usize; 3]], min_area: f32) -> Vec<usize> {
+    (0..triangles.len()).filter(|&i| triangle_area(vertices, triangles[i]).abs() < min_area).collect()
+}
+
+/// Returns the triangles that `find_degenerate_triangles` would not report, in order.
+pub fn remove_degenerate(vertices: &[Vector2], triangles: &[[usize; 3]], min_area: f32) -> Vec<[usize; 3]> {
+    let degenerate = find_degenerate_triangles(vertices, triangles, min_area);
+    // Both lists are in index order, so one pass skips the reported triangles.
+    let mut skipped = degenerate.iter().peekable();
+    triangles
+        .iter()
+        .enumerate()
+        .filter(|&(i, _)| skipped.next_if_eq(&&i).is_none())
+        .map(|(_, &t)| t)
+        .collect()
+}
+
+/// Per-vertex normals from the area-weighted sum of the adjacent face normals, with faces
+/// facing along their counter-clockwise winding. Vertices used by no triangle get zero.
+pub fn compute_vertex_normals(vertices: &[Vector3], triangles: &[[usize; 3]]) -> Vec<Vector3> {
+    let mut normals = vec![Vector3::zero(); vertices.len()];
+    for &[a, b, c] in triangles {
+        // The cross product's length is twice the face area, which supplies the weighting.
+        let face = Vector3::cross(vertices[b] - vertices[a], vertices[c] - vertices[a]);
+        for i in [a, b, c] {
+            normals[i] = normals[i] + face;
+        }
+    }
+    normals.iter().map(|n| n.normalized()).collect()
+}
+
+/// Per-vertex tangents pointing along increasing `u`, accumulated per face from positions and
+/// UVs and then Gram-Schmidt orthogonalized against `compute_vertex_normals`. Faces with a
+/// degenerate UV mapping contribute nothing.
+pub fn compute_tangents(vertices: &[Vector3], uvs: &[Vector2], triangles: &[[usize; 3]]) -> Vec<Vector3> {
+    let mut tangents = vec![Vector3::zero(); vertices.len()];
+    for &[a, b, c] in triangles {
+        let (e1, e2) = (vertices[b] - vertices[a], vertices[c] - vertices[a]);
+        let (d1, d2) = (uvs[b] - uvs[a], uvs[c] - uvs[a]);
+        let det = d1.x * d2.y - d2.x * d1.y;
+        if det.abs() < 1E-12f32 {
+            continue;
+        }
+        let face = (e1 * d2.y - e2 * d1.y) * (1f32 / det);
+        for i in [a, b, c] {
+            tangents[i] = tangents[i] + face;
+        }
+    }
+    let normals = compute_vertex_normals(vertices, triangles);
+    tangents.iter().zip(&normals).map(|(&t, &n)| (t - n * Vector3::dot(n, t)).normalized()).collect()
+}
+
+/// Signed area, positive for counter-clockwise triangles.
+fn triangle_area(vertices: &[Vector2], [a, b, c]: [usize; 3]) -> f32 {
+    Vector2::cross(vertices[b] - vertices[a], vertices[c] - vertices[a]) * 0.5f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square() -> (Vec<Vector2>, Vec<[usize; 3]>) {
+        let vertices = vec![Vector2::zero(), Vector2::new(2f32, 0f32), Vector2::new(2f32, 2f32), Vector2::new(0f32, 2f32)];
+        (vertices, vec![[0, 1, 2], [0, 2, 3]])
+    }
+
+    #[test]
+    fn test_subdivide_counts() {
+        let (vertices, triangles) = square();
+        let (out_vertices, out_triangles) = subdivide_triangles(&vertices, &triangles);
+        assert_eq!(out_triangles.len(), 8);
+        // 4 corners plus one midpoint per unique edge: 4 sides and the shared diagonal.
+        assert_eq!(out_vertices.len(), 9);
+        assert_eq!(&out_vertices[..4], &vertices[..]);
+        let diagonal = out_vertices.iter().filter(|&&v| v == Vector2::one()).count();
+        assert_eq!(diagonal, 1);
+    }
+
+    #[test]
+    fn test_subdivide_preserves_area() {
+        let (vertices, triangles) = square();
+        let (mut vertices, mut triangles) = (vertices, triangles);
+        for _ in 0..3 {
+            (vertices, triangles) = subdivide_triangles(&vertices, &triangles);
+        }
+        assert_eq!(triangles.len(), 2 * 64);
+        assert!(triangles.iter().all(|&t| triangle_area(&vertices, t) > 0f32));
+        let area: f32 = triangles.iter().map(|&t| triangle_area(&vertices, t)).sum();
+        assert!((area - 4f32).abs() < 1E-05f32);
+        for (i, a) in vertices.iter().enumerate() {
+            assert!(vertices[i + 1..].iter().all(|b| (*a - *b).magnitude() > 1E-06f32));
+        }
+    }
+
+    fn grid(n: usize) -> (Vec<Vector2>, Vec<[usize; 3]>) {
+        let vertices = (0..=n).flat_map(|y| (0..=n).map(move |x| Vector2::new(x as f32, y as f32))).collect();
+        let mut triangles = Vec::new();
+        for y in 0..n {
+            for x in 0..n {
+                let i = y * (n + 1) + x;
+                triangles.push([i, i + 1, i + n + 2]);
+                triangles.push([i, i + n + 2, i + n + 1]);
+            }
+        }
+        (vertices, triangles)
+    }
+
+    #[test]
+    fn test_laplacian_smooth_converges() {
+        let (mut vertices, triangles) = grid(4);
+        let original = vertices.clone();
+        vertices[12] = Vector2::new(2.7f32, 1.4f32);
+        vertices[6] = Vector2::new(0.8f32, 1.3f32);
+        laplacian_smooth(&mut vertices, &triangles, 200, true);
+        for (i, v) in vertices.iter().enumerate() {
+            let (x, y) = (i % 5, i / 5);
+            if x == 0 || y == 0 || x == 4 || y == 4 {
+                assert_eq!(*v, original[i]);
+            }
+            // The regular grid is its own neighbour average, so the perturbation relaxes away.
+            assert!((*v - original[i]).magnitude() < 1E-04f32);
+        }
+        // At the fixed point every interior vertex sits at its neighbour average.
+        let before = vertices.clone();
+        laplacian_smooth(&mut vertices, &triangles, 1, true);
+        for (a, b) in vertices.iter().zip(&before) {
+            assert!((*a - *b).magnitude() < 1E-04f32);
+        }
+    }
+
+    #[test]
+    fn test_laplacian_smooth_single_step() {
+        let (mut vertices, triangles) = grid(2);
+        vertices[4] = Vector2::new(1.5f32, 1.5f32);
+        laplacian_smooth(&mut vertices, &triangles, 1, true);
+        // Centre neighbours in this diagonal split: 1, 3, 5, 7, 0 and 8, averaging to (1, 1).
+        assert!((vertices[4] - Vector2::one()).magnitude() < 1E-06f32);
+        assert_eq!(vertices[0], Vector2::zero());
+    }
+
+    #[test]
+    fn test_laplacian_smooth_free_boundary_shrinks() {
+        let (mut vertices, triangles) = grid(2);
+        laplacian_smooth(&mut vertices, &triangles, 1, false);
+        assert_ne!(vertices[0], Vector2::zero());
+        let (mut unchanged, _) = grid(2);
+        laplacian_smooth(&mut unchanged, &triangles, 0, false);
+        assert_eq!(unchanged, grid(2).0);
+    }
+
+    #[test]
+    fn test_find_degenerate_triangles() {
+        let vertices = [Vector2::zero(), Vector2::new(2f32, 0f32), Vector2::new(0f32, 2f32), Vector2::new(4f32, 1E-03f32), Vector2::new(1f32, 0f32)];
+        let triangles = [[0, 1, 2], [0, 1, 3], [0, 4, 1], [2, 1, 0], [1, 1, 2]];
+        assert_eq!(find_degenerate_triangles(&vertices, &triangles, 1E-02f32), vec![1, 2, 4]);
+        assert_eq!(remove_degenerate(&vertices, &triangles, 1E-02f32), vec![[0, 1, 2], [2, 1, 0]]);
+        assert_eq!(remove_degenerate(&vertices, &triangles, 0f32), triangles.to_vec());
+    }
+
+    #[test]
+    fn test_vertex_normals_flat_quad() {
+        let vertices = [Vector3::zero(), Vector3::new(1f32, 0f32, 0f32), Vector3::new(1f32, 0f32, 3f32), Vector3::new(0f32, 0f32, 3f32)];
+        let normals = compute_vertex_normals(&vertices, &[[0, 2, 1], [0, 3, 2]]);
+        assert!(normals.iter().all(|&n| (n - Vector3::up()).magnitude() < 1E-06f32));
+    }
+
+    #[test]
+    fn test_vertex_normals_crease() {
+        let vertices = [Vector3::zero(), Vector3::right(), Vector3::up(), Vector3::forward(), Vector3::new(5f32, 5f32, 5f32)];
+        let normals = compute_vertex_normals(&vertices, &[[0, 1, 2], [1, 0, 3]]);
+        let shared = Vector3::new(0f32, 1f32, 1f32).normalized();
+        assert!((normals[0] - shared).magnitude() < 1E-06f32);
+        assert!((normals[1] - shared).magnitude() < 1E-06f32);
+        assert!((normals[2] - Vector3::forward()).magnitude() < 1E-06f32);
+        assert!((normals[3] - Vector3::up()).magnitude() < 1E-06f32);
+        assert_eq!(normals[4], Vector3::zero());
+    }
+
+    #[test]
+    fn test_tangents_planar_quad() {
+        let vertices = [Vector3::zero(), Vector3::new(2f32, 0f32, 0f32), Vector3::new(2f32, 1f32, 0f32), Vector3::new(0f32, 1f32, 0f32)];
+        let uvs = [Vector2::zero(), Vector2::right(), Vector2::one(), Vector2::up()];
+        let tangents = compute_tangents(&vertices, &uvs, &[[0, 1, 2], [0, 2, 3]]);
+        assert!(tangents.iter().all(|&t| (t - Vector3::right()).magnitude() < 1E-06f32));
+        let flipped: Vec<Vector2> = uvs.iter().map(|uv| Vector2::new(1f32 - uv.x, uv.y)).collect();
+        let tangents = compute_tangents(&vertices, &flipped, &[[0, 1, 2], [0, 2, 3]]);
+        assert!(tangents.iter().all(|&t| (t + Vector3::right()).magnitude() < 1E-06f32));
+    }
+
+    #[test]
+    fn test_tangents_orthogonal_to_normals() {
+        let vertices = [Vector3::zero(), Vector3::right(), Vector3::up(), Vector3::new(0.3f32, 0.2f32, 1f32)];
+        let uvs = [Vector2::zero(), Vector2::new(1f32, 0.2f32), Vector2::new(0.1f32, 1f32), Vector2::new(0.6f32, 0.7f32)];
+        let triangles = [[0, 1, 2], [1, 3, 2], [0, 3, 1]];
+        let tangents = compute_tangents(&vertices, &uvs, &triangles);
+        let normals = compute_vertex_normals(&vertices, &triangles);
+        for (t, n) in tangents.iter().zip(&normals) {
+            assert!((t.magnitude() - 1f32).abs() < 1E-05f32);
+            assert!(Vector3::dot(*t, *n).abs() < 1E-05f32);
+        }
+    }
+}
@@ -1 +1,54 @@
-mod vector2;
\ No newline at end of file
+pub mod approx;
+pub mod ballistics;
+pub mod bezier;
+pub mod bvh;
+pub mod clipping;
+pub mod clustering;
+pub mod collision;
+pub mod contour;
+pub mod deadzone;
+pub mod delaunay;
+pub mod density;
+pub mod distance_field;
+pub mod fitting;
+pub mod fixed_step;
+pub mod flow_field;
+pub mod frames;
+pub mod gesture;
+mod heap;
+pub mod hull;
+pub mod integration;
+pub mod matrix2x2;
+pub mod matrix3x2;
+pub mod mesh;
+pub mod navmesh;
+pub mod network;
+pub mod orbit;
+pub mod points;
+pub mod polygon;
+pub mod polylabel;
+pub mod polyline;
+pub mod raster;
+pub mod ray2d;
+pub mod rect;
+pub mod registration;
+pub mod ribbon;
+pub mod routing;
+pub mod segment;
+pub mod similarity;
+pub mod skeleton;
+pub mod soa;
+pub mod space_filling;
+pub mod spline;
+pub mod tracking;
+pub mod transform2d;
+pub mod triangulation;
+mod validate;
+pub mod vector2;
+pub mod vector2_int;
+pub mod vector3;
+pub mod visibility;
+pub mod voronoi;
+
+#[cfg(test)]
+mod test_support;
@@ -0,0 +1,223 @@
+use std::collections::VecDeque;
+
+use super::triangulation::triangulate;
+use super::vector2::Vector2;
+
+/// How far apart two vertices may be and still count as the same corner of a shared edge.
+const EDGE_TOLERANCE: f32 = 1E-04f32;
+
+/// Triangles with their shared-edge adjacency. Triangles are stored counter-clockwise; edge `k`
+/// of a triangle runs from vertex `k` to vertex `k + 1`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NavMesh {
+    triangles: Vec<[Vector2; 3]>,
+    neighbours: Vec<[Option<usize>; 3]>,
+}
+
+impl NavMesh {
+    pub fn triangles(&self) -> &[[Vector2; 3]] { &self.triangles }
+    /// Neighbour across each edge of triangle `index`.
+    pub fn neighbours(&self, index: usize) -> [Option<usize>; 3] { self.neighbours[index] }
+
+    /// First triangle containing `point`, edges included.
+    pub fn find_containing_triangle(&self, point: Vector2) -> Option<usize> { self.triangles.iter().position(|t| contains(t, point)) }
+
+    /// Shared edge from triangle `from` into its neighbour `to` as `(left, right)` seen when
+    /// crossing it.
+    fn portal(&self, from: usize, to: usize) -> Option<(Vector2, Vector2)> {
+        let edge = self.neighbours[from].iter().position(|&n| n == Some(to))?;
+        let triangle = self.triangles[from];
+        // Leaving a counter-clockwise triangle through edge `k`, its end vertex is on the left.
+        Some((triangle[(edge + 1) % 3], triangle[edge]))
+    }
+}
+
+/// Links triangles whose edges coincide within a small tolerance. Clockwise triangles are
+/// flipped first.
+pub fn build_navmesh(triangles: &[[Vector2; 3]]) -> NavMesh {
+    let triangles: Vec<[Vector2; 3]> = triangles
+        .iter()
+        .map(|&[a, b, c]| if Vector2::cross(b - a, c - a) < 0f32 { [a, c, b] } else { [a, b, c] })
+        .collect();
+    let close = |p: Vector2, q: Vector2| (p - q).sqr_magnitude() <= EDGE_TOLERANCE * EDGE_TOLERANCE;
+    let mut neighbours = vec![[None; 3]; triangles.len()];
+    for i in 0..triangles.len() {
+        for j in i + 1..triangles.len() {
+            for k in 0..3 {
+                let (a, b) = (triangles[i][k], triangles[i][(k + 1) % 3]);
+                if let Some(m) = (0..3).find(|&m| close(triangles[j][m], b) && close(triangles[j][(m + 1) % 3], a)) {
+                    neighbours[i][k] = Some(j);
+                    neighbours[j][m] = Some(i);
+                }
+            }
+        }
+    }
+    NavMesh { triangles, neighbours }
+}
+
+/// Shortest path from `start` to `goal` staying inside a simple polygon: the polygon is
+/// triangulated into a [`NavMesh`], the triangle corridor between the endpoints found, and the
+/// path pulled taut through its portals with the funnel algorithm. `None` if either endpoint
+/// is outside.
+pub fn shortest_path_in_polygon(start: Vector2, goal: Vector2, polygon: &[Vector2]) -> Option<Vec<Vector2>> {
+    let triangles: Vec<[Vector2; 3]> = triangulate(polygon).iter().map(|t| t.map(|i| polygon[i])).collect();
+    let mesh = build_navmesh(&triangles);
+    let first = mesh.find_containing_triangle(start)?;
+    let last = mesh.find_containing_triangle(goal)?;
+    let corridor = corridor(&mesh.neighbours, first, last)?;
+
+    let mut portals = vec![(start, start)];
+    portals.extend(corridor.windows(2).map(|w| mesh.portal(w[0], w[1]).unwrap()));
+    portals.push((goal, goal));
+    Some(string_pull(&portals))
+}
+
+/// Triangles from `first` to `last` by breadth-first search over the adjacency.
+fn corridor(neighbours: &[[Option<usize>; 3]], first: usize, last: usize) -> Option<Vec<usize>> {
+    let mut previous = vec![None; neighbours.len()];
+    let mut queue = VecDeque::from([first]);
+    previous[first] = Some(first);
+    while let Some(current) = queue.pop_front() {
+        if current == last {
+            let mut path = vec![last];
+            while *path.last().unwrap() != first {
+                path.push(previous[*path.last().unwrap()].unwrap());
+            }
+            path.reverse();
+            return Some(path);
+        }
+        for next in neighbours[current].iter().flatten().copied() {
+            if previous[next].is_none() {
+                previous[next] = Some(current);
+                queue.push_back(next);
+            }
+        }
+    }
+    None
+}
+
+/// Simple stupid funnel over `(left, right)` portals; the first and last portals are the
+/// degenerate start and goal.
+fn string_pull(portals: &[(Vector2, Vector2)]) -> Vec<Vector2> {
+    let mut path = vec![portals[0].0];
+    let (mut apex, mut left, mut right) = (portals[0].0, portals[0].0, portals[0].1);
+    let (mut left_index, mut right_index) = (0, 0);
+    let mut i = 1;
+    while i < portals.len() {
+        let (portal_left, portal_right) = portals[i];
+
+        if Vector2::cross(right - apex, portal_right - apex) >= 0f32 {
+            if apex == right || Vector2::cross(left - apex, portal_right - apex) < 0f32 {
+                right = portal_right;
+                right_index = i;
+            } else {
+                // The right side crossed the left one: the left corner becomes the new apex.
+                path.push(left);
+                apex = left;
+                (right, right_index) = (apex, left_index);
+                i = left_index + 1;
+                continue;
+            }
+        }
+
+        if Vector2::cross(left - apex, portal_left - apex) <= 0f32 {
+            if apex == left || Vector2::cross(right - apex, portal_left - apex) > 0f32 {
+                left = portal_left;
+                left_index = i;
+            } else {
+                path.push(right);
+                apex = right;
+                (left, left_index) = (apex, right_index);
+                i = right_index + 1;
+                continue;
+            }
+        }
+        i += 1;
+    }
+    let goal = portals[portals.len() - 1].0;
+    if *path.last().unwrap() != goal {
+        path.push(goal);
+    }
+    path
+}
+
+/// Inclusive containment in a counter-clockwise triangle.
+fn contains(&[a, b, c]: &[Vector2; 3], p: Vector2) -> bool {
+    Vector2::cross(b - a, p - a) >= 0f32 && Vector2::cross(c - b, p - b) >= 0f32 && Vector2::cross(a - c, p - c) >= 0f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::test_support::l_shape;
+
+    #[test]
+    fn test_navmesh_adjacency() {
+        let triangles = [
+            [Vector2::zero(), Vector2::new(1f32, 0f32), Vector2::new(0f32, 1f32)],
+            [Vector2::new(1f32, 0f32), Vector2::new(1f32, 1f32), Vector2::new(0f32, 1f32)],
+            // Clockwise, and its shared corner is off by less than the tolerance.
+            [Vector2::new(1f32, 1f32), Vector2::new(1.00001f32, 0f32), Vector2::new(2f32, 0.5f32)],
+            [Vector2::new(5f32, 5f32), Vector2::new(6f32, 5f32), Vector2::new(5f32, 6f32)],
+        ];
+        let mesh = build_navmesh(&triangles);
+        assert_eq!(mesh.neighbours(0), [None, Some(1), None]);
+        assert_eq!(mesh.neighbours(3), [None; 3]);
+        for i in 0..4 {
+            for j in mesh.neighbours(i).iter().flatten() {
+                assert!(mesh.neighbours(*j).contains(&Some(i)));
+            }
+        }
+        assert!(mesh.neighbours(1).contains(&Some(2)));
+        let [a, b, c] = mesh.triangles()[2];
+        assert!(Vector2::cross(b - a, c - a) > 0f32);
+    }
+
+    #[test]
+    fn test_find_containing_triangle() {
+        let triangles = [[Vector2::zero(), Vector2::new(2f32, 0f32), Vector2::new(0f32, 2f32)], [Vector2::new(2f32, 0f32), Vector2::new(2f32, 2f32), Vector2::new(0f32, 2f32)]];
+        let mesh = build_navmesh(&triangles);
+        assert_eq!(mesh.find_containing_triangle(Vector2::new(0.5f32, 0.5f32)), Some(0));
+        assert_eq!(mesh.find_containing_triangle(Vector2::new(1.5f32, 1.5f32)), Some(1));
+        assert_eq!(mesh.find_containing_triangle(Vector2::new(3f32, 1f32)), None);
+    }
+
+    #[test]
+    fn test_straight_shot() {
+        let square = [Vector2::zero(), Vector2::new(4f32, 0f32), Vector2::new(4f32, 4f32), Vector2::new(0f32, 4f32)];
+        let (start, goal) = (Vector2::new(0.5f32, 0.5f32), Vector2::new(3.5f32, 3f32));
+        assert_eq!(shortest_path_in_polygon(start, goal, &square), Some(vec![start, goal]));
+        let shape = l_shape();
+        let (start, goal) = (Vector2::new(0.5f32, 3.5f32), Vector2::new(0.5f32, 0.25f32));
+        assert_eq!(shortest_path_in_polygon(start, goal, &shape), Some(vec![start, goal]));
+    }
+
+    #[test]
+    fn test_hugs_concave_corner() {
+        let shape = l_shape();
+        let (start, goal) = (Vector2::new(0.5f32, 3.5f32), Vector2::new(3.5f32, 0.5f32));
+        assert_eq!(shortest_path_in_polygon(start, goal, &shape), Some(vec![start, Vector2::new(1f32, 1f32), goal]));
+        assert_eq!(shortest_path_in_polygon(goal, start, &shape), Some(vec![goal, Vector2::new(1f32, 1f32), start]));
+    }
+
+    #[test]
+    fn test_u_turn_around_two_corners() {
+        let polygon: Vec<Vector2> = [(0f32, 0f32), (5f32, 0f32), (5f32, 5f32), (3f32, 5f32), (3f32, 1f32), (2f32, 1f32), (2f32, 5f32), (0f32, 5f32)]
+            .iter()
+            .map(|&(x, y)| Vector2::new(x, y))
+            .collect();
+        let (start, goal) = (Vector2::new(1f32, 4f32), Vector2::new(4f32, 4f32));
+        let expected = vec![start, Vector2::new(2f32, 1f32), Vector2::new(3f32, 1f32), goal];
+        assert_eq!(shortest_path_in_polygon(start, goal, &polygon), Some(expected));
+        let mut clockwise = polygon.clone();
+        clockwise.reverse();
+        assert_eq!(shortest_path_in_polygon(goal, start, &clockwise).unwrap().len(), 4);
+    }
+
+    #[test]
+    fn test_outside_endpoints() {
+        let shape = l_shape();
+        assert_eq!(shortest_path_in_polygon(Vector2::new(3f32, 3f32), Vector2::new(0.5f32, 0.5f32), &shape), None);
+        assert_eq!(shortest_path_in_polygon(Vector2::new(0.5f32, 0.5f32), Vector2::new(-1f32, 0f32), &shape), None);
+    }
+}
@@ -0,0 +1,193 @@
+use std::collections::HashMap;
+
+use super::delaunay::delaunay;
+use super::vector2::Vector2;
+
+/// Edges of the Euclidean minimum spanning tree, as index pairs with the smaller index first,
+/// in the order Kruskal's algorithm accepts them. The tree is a subgraph of the Delaunay
+/// triangulation, so only its edges are considered, plus zero-length edges joining repeated
+/// points to their first copy; collinear input falls back to every pair.
+pub fn euclidean_mst(points: &[Vector2]) -> Vec<(usize, usize)> {
+    let n = points.len();
+    let mut edges: Vec<(usize, usize)> = delaunay(points)
+        .iter()
+        .flat_map(|&[a, b, c]| [(a, b), (b, c), (c, a)])
+        .map(|(a, b)| (a.min(b), a.max(b)))
+        .collect();
+    if edges.is_empty() {
+        edges = (0..n).flat_map(|i| (i + 1..n).map(move |j| (i, j))).collect();
+    } else {
+        // The triangulation leaves out repeated points; tie each to its first copy instead.
+        let mut first_seen = HashMap::new();
+        for (i, p) in points.iter().enumerate() {
+            let first = *first_seen.entry((p.x.to_bits(), p.y.to_bits())).or_insert(i);
+            if first != i {
+                edges.push((first, i));
+            }
+        }
+    }
+    edges.sort_unstable();
+    edges.dedup();
+    let length = |&(a, b): &(usize, usize)| (points[a] - points[b]).sqr_magnitude();
+    edges.sort_by(|x, y| length(x).total_cmp(&length(y)));
+
+    let mut parent: Vec<usize> = (0..n).collect();
+    let mut tree = Vec::with_capacity(n.saturating_sub(1));
+    for (a, b) in edges {
+        let (ra, rb) = (root(&mut parent, a), root(&mut parent, b));
+        if ra != rb {
+            parent[ra] = rb;
+            tree.push((a, b));
+        }
+    }
+    tree
+}
+
+/// Closed tour built by always moving to the nearest unvisited point, beginning and ending at
+/// `start`, so it holds `points.len() + 1` indices. Ties go to the lower index.
+pub fn tsp_nearest_neighbor(points: &[Vector2], start: usize) -> Vec<usize> {
+    if points.is_empty() {
+        return Vec::new();
+    }
+    let mut visited = vec![false; points.len()];
+    let mut tour = Vec::with_capacity(points.len() + 1);
+    let mut current = start;
+    visited[start] = true;
+    tour.push(start);
+    for _ in 1..points.len() {
+        let next = (0..points.len())
+            .filter(|&i| !visited[i])
+            .min_by(|&a, &b| (points[a] - points[current]).sqr_magnitude().total_cmp(&(points[b] - points[current]).sqr_magnitude()))
+            .unwrap();
+        visited[next] = true;
+        tour.push(next);
+        current = next;
+    }
+    tour.push(start);
+    tour
+}
+
+/// Shortens a tour in place by reversing any stretch whose two end edges are longer than the
+/// edges a reversal would put in their place, until no such stretch remains. The first and last
+/// entries stay put, so a closed tour from [`tsp_nearest_neighbor`] keeps its start.
+pub fn two_opt_improve(points: &[Vector2], tour: &mut [usize]) {
+    let distance = |a: usize, b: usize| (points[a] - points[b]).magnitude();
+    let n = tour.len();
+    let mut improved = true;
+    while improved {
+        improved = false;
+        for i in 1..n.saturating_sub(2) {
+            for j in i + 1..n - 1 {
+                let (a, b, c, d) = (tour[i - 1], tour[i], tour[j], tour[j + 1]);
+                if distance(a, c) + distance(b, d) < distance(a, b) + distance(c, d) - 1E-06f32 {
+                    tour[i..=j].reverse();
+                    improved = true;
+                }
+            }
+        }
+    }
+}
+
+/// Union-find root with path halving.
+fn root(parent: &mut [usize], mut i: usize) -> usize {
+    while parent[i] != i {
+        parent[i] = parent[parent[i]];
+        i = parent[i];
+    }
+    i
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::test_support::Lcg;
+
+    fn total_length(points: &[Vector2], edges: &[(usize, usize)]) -> f32 { edges.iter().map(|&(a, b)| (points[a] - points[b]).magnitude()).sum() }
+
+    fn prim_length(points: &[Vector2]) -> f32 {
+        let n = points.len();
+        let mut in_tree = vec![false; n];
+        let mut best = vec![f32::INFINITY; n];
+        best[0] = 0f32;
+        let mut total = 0f32;
+        for _ in 0..n {
+            let next = (0..n).filter(|&i| !in_tree[i]).min_by(|&a, &b| best[a].total_cmp(&best[b])).unwrap();
+            in_tree[next] = true;
+            total += best[next];
+            for i in 0..n {
+                best[i] = best[i].min((points[i] - points[next]).magnitude());
+            }
+        }
+        total
+    }
+
+    #[test]
+    fn test_euclidean_mst_matches_prim() {
+        let mut rng = Lcg(13);
+        for count in [2, 5, 17, 60] {
+            let points: Vec<Vector2> = (0..count).map(|_| Vector2::new(rng.next() * 10f32, rng.next() * 10f32)).collect();
+            let tree = euclidean_mst(&points);
+            assert_eq!(tree.len(), count - 1);
+            // Connected: walking the tree from point 0 reaches everything.
+            let mut reached = vec![false; count];
+            reached[0] = true;
+            for _ in 0..count {
+                for &(a, b) in &tree {
+                    if reached[a] || reached[b] {
+                        reached[a] = true;
+                        reached[b] = true;
+                    }
+                }
+            }
+            assert!(reached.iter().all(|&r| r));
+            assert!((total_length(&points, &tree) - prim_length(&points)).abs() < 1E-03f32);
+        }
+    }
+
+    #[test]
+    fn test_euclidean_mst_collinear() {
+        let points = [Vector2::new(3f32, 0f32), Vector2::zero(), Vector2::new(1f32, 0f32), Vector2::new(7f32, 0f32)];
+        let tree = euclidean_mst(&points);
+        assert_eq!(tree, vec![(1, 2), (0, 2), (0, 3)]);
+        assert!(euclidean_mst(&[]).is_empty());
+        let repeated = [Vector2::zero(), Vector2::new(2f32, 0f32), Vector2::new(1f32, 2f32), Vector2::zero(), Vector2::new(1f32, 2f32), Vector2::zero()];
+        let tree = euclidean_mst(&repeated);
+        assert_eq!(tree.len(), repeated.len() - 1);
+        assert!([(0, 3), (0, 5), (2, 4)].iter().all(|e| tree.contains(e)));
+        assert!((total_length(&repeated, &tree) - prim_length(&repeated)).abs() < 1E-05f32);
+        assert!(euclidean_mst(&[Vector2::one()]).is_empty());
+    }
+
+    fn tour_length(points: &[Vector2], tour: &[usize]) -> f32 { tour.windows(2).map(|w| (points[w[0]] - points[w[1]]).magnitude()).sum() }
+
+    #[test]
+    fn test_tsp_nearest_neighbor_visits_all() {
+        let mut rng = Lcg(29);
+        let points: Vec<Vector2> = (0..40).map(|_| Vector2::new(rng.next() * 10f32, rng.next() * 10f32)).collect();
+        let tour = tsp_nearest_neighbor(&points, 7);
+        assert_eq!(tour.len(), 41);
+        assert_eq!((tour[0], tour[40]), (7, 7));
+        let mut seen = tour[..40].to_vec();
+        seen.sort_unstable();
+        assert_eq!(seen, (0..40).collect::<Vec<usize>>());
+
+        let mut improved = tour.clone();
+        two_opt_improve(&points, &mut improved);
+        assert_eq!((improved[0], improved[40]), (7, 7));
+        seen = improved[..40].to_vec();
+        seen.sort_unstable();
+        assert_eq!(seen, (0..40).collect::<Vec<usize>>());
+        assert!(tour_length(&points, &improved) <= tour_length(&points, &tour));
+    }
+
+    #[test]
+    fn test_two_opt_uncrosses() {
+        let square = [Vector2::zero(), Vector2::new(1f32, 0f32), Vector2::one(), Vector2::new(0f32, 1f32)];
+        let mut crossed = vec![0, 2, 1, 3, 0];
+        two_opt_improve(&square, &mut crossed);
+        assert!((tour_length(&square, &crossed) - 4f32).abs() < 1E-06f32);
+        assert_eq!(tsp_nearest_neighbor(&square, 0), vec![0, 1, 2, 3, 0]);
+        assert!(tsp_nearest_neighbor(&[], 0).is_empty());
+        assert_eq!(tsp_nearest_neighbor(&[Vector2::one()], 0), vec![0, 0]);
+    }
+}
@@ -0,0 +1,230 @@
+use std::f32::consts::PI;
+
+use super::matrix2x2::Matrix2x2;
+use super::vector2::Vector2;
+
+/// Shape and phase of a counter-clockwise orbit. Angles are in degrees; the argument of
+/// periapsis is measured from +x and the true anomaly from periapsis.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OrbitalElements {
+    pub semi_major: f32,
+    pub eccentricity: f32,
+    pub argument_of_periapsis: f32,
+    pub true_anomaly: f32,
+}
+
+impl OrbitalElements {
+    pub fn new(semi_major: f32, eccentricity: f32, argument_of_periapsis: f32, true_anomaly: f32) -> Self {
+        Self { semi_major, eccentricity, argument_of_periapsis, true_anomaly }
+    }
+
+    /// Position and velocity relative to the attractor.
+    pub fn to_state(&self, gm: f32) -> (Vector2, Vector2) {
+        let (pos, vel) = orbital_elements_to_state(self.semi_major, self.eccentricity, self.true_anomaly, gm);
+        let rotation = Matrix2x2::rotation(self.argument_of_periapsis);
+        (rotation * pos, rotation * vel)
+    }
+}
+
+/// Position and velocity relative to the attractor for an orbit with periapsis on +x, with
+/// `true_anomaly` in degrees.
+pub fn orbital_elements_to_state(semi_major: f32, eccentricity: f32, true_anomaly: f32, gm: f32) -> (Vector2, Vector2) {
+    let p = semi_major * (1f32 - eccentricity * eccentricity);
+    let (sin, cos) = true_anomaly.to_radians().sin_cos();
+    let r = p / (1f32 + eccentricity * cos);
+    let speed = (gm / p).sqrt();
+    (Vector2::new(r * cos, r * sin), Vector2::new(-sin * speed, (eccentricity + cos) * speed))
+}
+
+/// Inverse of [`OrbitalElements::to_state`]. Near-circular orbits have no defined periapsis,
+/// so their argument of periapsis is reported as zero.
+pub fn state_to_elements(position: Vector2, velocity: Vector2, gm: f32) -> OrbitalElements {
+    let r = position.magnitude();
+    let v2 = velocity.sqr_magnitude();
+    let energy = 0.5f32 * v2 - gm / r;
+    let semi_major = -gm / (2f32 * energy);
+    let e_vector = (position * (v2 - gm / r) - velocity * Vector2::dot(position, velocity)) / gm;
+    let eccentricity = e_vector.magnitude();
+    let argument_of_periapsis = if eccentricity > 1E-06f32 { e_vector.y.atan2(e_vector.x).to_degrees() } else { 0f32 };
+    let true_anomaly = (position.y.atan2(position.x).to_degrees() - argument_of_periapsis).rem_euclid(360f32);
+    OrbitalElements::new(semi_major, eccentricity, argument_of_periapsis, true_anomaly)
+}
+
+/// Time for one revolution of an elliptical orbit, by Kepler's third law. Units follow the
+/// inputs: with `semi_major` in metres and `gm` in m³/s² the period is in seconds.
+pub fn orbital_period(semi_major: f32, gm: f32) -> f32 {
+    2f32 * PI * (semi_major * semi_major * semi_major / gm).sqrt()
+}
+
+/// Position relative to the attractor `time` after the epoch at which the body sat at
+/// `elements.true_anomaly`. Kepler's equation is solved with Newton iteration on the
+/// eccentric anomaly. Only bound (elliptical) orbits are supported.
+pub fn orbit_position(elements: OrbitalElements, time: f32, gm: f32) -> Vector2 {
+    let a = elements.semi_major;
+    let e = elements.eccentricity;
+    let (half_sin, half_cos) = (elements.true_anomaly.to_radians() * 0.5f32).sin_cos();
+    let initial_eccentric = 2f32 * ((1f32 - e).sqrt() * half_sin).atan2((1f32 + e).sqrt() * half_cos);
+    let initial_mean = initial_eccentric - e * initial_eccentric.sin();
+    let mean_motion = (gm / (a * a * a)).sqrt();
+    let mean = (initial_mean + mean_motion * time).rem_euclid(2f32 * PI);
+
+    let mut eccentric = if e > 0.8f32 { PI } else { mean };
+    for _ in 0..32 {
+        let delta = (eccentric - e * eccentric.sin() - mean) / (1f32 - e * eccentric.cos());
+        eccentric -= delta;
+        if delta.abs() < 1E-07f32 {
+            break;
+        }
+    }
+
+    let (sin, cos) = eccentric.sin_cos();
+    let perifocal = Vector2::new(a * (cos - e), a * (1f32 - e * e).sqrt() * sin);
+    Matrix2x2::rotation(elements.argument_of_periapsis) * perifocal
+}
+
+fn gravity(pos: Vector2, attractor: Vector2, gm: f32) -> Vector2 {
+    let offset = attractor - pos;
+    let r2 = offset.sqr_magnitude();
+    if r2 == 0f32 {
+        return Vector2::zero();
+    }
+    offset * (gm / (r2 * r2.sqrt()))
+}
+
+/// Advances a body orbiting a fixed point mass by `dt` using a kick-drift-kick leapfrog
+/// step, which keeps orbital energy bounded over long runs.
+pub fn gravity_step(pos: &mut Vector2, vel: &mut Vector2, attractor: Vector2, gm: f32, dt: f32) {
+    let half_kick = gravity(*pos, attractor, gm) * (dt * 0.5f32);
+    *vel = *vel + half_kick;
+    *pos = *pos + *vel * dt;
+    *vel = *vel + gravity(*pos, attractor, gm) * (dt * 0.5f32);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn energy(pos: Vector2, vel: Vector2, attractor: Vector2, gm: f32) -> f32 {
+        0.5f32 * vel.sqr_magnitude() - gm / (pos - attractor).magnitude()
+    }
+
+    #[test]
+    fn test_circular_orbit_keeps_radius() {
+        let attractor = Vector2::new(2f32, -1f32);
+        let gm = 4f32;
+        let radius = 3f32;
+        let mut pos = attractor + Vector2::new(radius, 0f32);
+        let mut vel = Vector2::new(0f32, (gm / radius).sqrt());
+        let initial_energy = energy(pos, vel, attractor, gm);
+        for _ in 0..10000 {
+            gravity_step(&mut pos, &mut vel, attractor, gm, 0.01f32);
+            assert!(((pos - attractor).magnitude() - radius).abs() < 0.01f32);
+        }
+        let final_energy = energy(pos, vel, attractor, gm);
+        assert!((final_energy - initial_energy).abs() < 1E-03f32);
+    }
+
+    #[test]
+    fn test_eccentric_orbit_energy_is_bounded() {
+        let gm = 1f32;
+        let mut pos = Vector2::new(1f32, 0f32);
+        let mut vel = Vector2::new(0f32, 1.2f32);
+        let initial_energy = energy(pos, vel, Vector2::zero(), gm);
+        for _ in 0..20000 {
+            gravity_step(&mut pos, &mut vel, Vector2::zero(), gm, 0.005f32);
+        }
+        let final_energy = energy(pos, vel, Vector2::zero(), gm);
+        assert!((final_energy - initial_energy).abs() < 0.01f32 * initial_energy.abs());
+    }
+
+    fn angle_difference(a: f32, b: f32) -> f32 {
+        let d = (a - b).rem_euclid(360f32);
+        d.min(360f32 - d)
+    }
+
+    #[test]
+    fn test_elements_round_trip() {
+        let gm = 3f32;
+        for &(a, e, w, nu) in &[(2f32, 0.3f32, 40f32, 70f32), (5f32, 0.8f32, -115f32, 200f32), (1f32, 0.1f32, 0f32, 0f32)] {
+            let elements = OrbitalElements::new(a, e, w, nu);
+            let (pos, vel) = elements.to_state(gm);
+            let result = state_to_elements(pos, vel, gm);
+            assert!((result.semi_major - a).abs() < 1E-03f32 * a);
+            assert!((result.eccentricity - e).abs() < 1E-04f32);
+            assert!(angle_difference(result.argument_of_periapsis, w) < 0.05f32);
+            assert!(angle_difference(result.true_anomaly, nu) < 0.05f32);
+        }
+    }
+
+    #[test]
+    fn test_circular_orbit_state() {
+        let gm = 2f32;
+        for i in 0..8 {
+            let (pos, vel) = orbital_elements_to_state(4f32, 0f32, i as f32 * 45f32, gm);
+            assert!((pos.magnitude() - 4f32).abs() < 1E-05f32);
+            assert!((vel.magnitude() - (gm / 4f32).sqrt()).abs() < 1E-05f32);
+            assert!(Vector2::dot(pos, vel).abs() < 1E-05f32);
+        }
+        let (pos, vel) = orbital_elements_to_state(4f32, 0f32, 30f32, gm);
+        let elements = state_to_elements(pos, vel, gm);
+        assert!(elements.eccentricity < 1E-05f32);
+        assert!((elements.true_anomaly - 30f32).abs() < 1E-03f32);
+    }
+
+    #[test]
+    fn test_periapsis_state() {
+        let (pos, vel) = orbital_elements_to_state(2f32, 0.5f32, 0f32, 1f32);
+        assert_eq!(pos, Vector2::new(1f32, 0f32));
+        assert!(vel.x.abs() < 1E-06f32);
+        assert!((vel.y - 1.5f32.sqrt()).abs() < 1E-05f32);
+    }
+
+    #[test]
+    fn test_orbital_period() {
+        assert!((orbital_period(1f32, 1f32) - 2f32 * PI).abs() < 1E-05f32);
+        assert!((orbital_period(4f32, 2f32) - 2f32 * PI * 32f32.sqrt()).abs() < 1E-04f32);
+    }
+
+    #[test]
+    fn test_orbit_position_matches_state() {
+        let gm = 1.5f32;
+        let elements = OrbitalElements::new(3f32, 0.6f32, 25f32, 115f32);
+        let (pos, _) = elements.to_state(gm);
+        assert!((orbit_position(elements, 0f32, gm) - pos).magnitude() < 1E-04f32);
+    }
+
+    #[test]
+    fn test_orbit_position_is_periodic() {
+        let gm = 2f32;
+        let elements = OrbitalElements::new(2f32, 0.5f32, 60f32, 0f32);
+        let period = orbital_period(elements.semi_major, gm);
+        let start = orbit_position(elements, 0f32, gm);
+        let end = orbit_position(elements, period, gm);
+        assert!((start - end).magnitude() < 1E-04f32);
+        let apoapsis = orbit_position(elements, period * 0.5f32, gm);
+        assert!((apoapsis.magnitude() - 3f32).abs() < 1E-04f32);
+        assert!((start.magnitude() - 1f32).abs() < 1E-04f32);
+    }
+
+    #[test]
+    fn test_orbit_position_matches_integration() {
+        let gm = 1f32;
+        let elements = OrbitalElements::new(1.5f32, 0.3f32, 0f32, 30f32);
+        let (mut pos, mut vel) = elements.to_state(gm);
+        let dt = 0.001f32;
+        for _ in 0..3000 {
+            gravity_step(&mut pos, &mut vel, Vector2::zero(), gm, dt);
+        }
+        let expected = orbit_position(elements, 3f32, gm);
+        assert!((pos - expected).magnitude() < 1E-02f32);
+    }
+
+    #[test]
+    fn test_body_at_attractor_is_not_nan() {
+        let mut pos = Vector2::zero();
+        let mut vel = Vector2::zero();
+        gravity_step(&mut pos, &mut vel, Vector2::zero(), 1f32, 0.1f32);
+        assert_eq!(pos, Vector2::zero());
+        assert_eq!(vel, Vector2::zero());
+    }
+}
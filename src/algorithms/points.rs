@@ -0,0 +1,373 @@
+use std::collections::{HashMap, HashSet};
+use std::f32::consts::PI;
+
+use super::vector2::Vector2;
+
+/// Index of the point nearest to `query` and its distance; the first one wins ties.
+pub fn nearest(points: &[Vector2], query: Vector2) -> Option<(usize, f32)> {
+    let mut best: Option<(usize, f32)> = None;
+    for (i, &p) in points.iter().enumerate() {
+        let d = (p - query).sqr_magnitude();
+        if best.is_none_or(|(_, best_d)| d < best_d) {
+            best = Some((i, d));
+        }
+    }
+    best.map(|(i, d)| (i, d.sqrt()))
+}
+
+/// Sorts counter-clockwise around `center`, starting at +x. Points at the same angle keep
+/// their relative order.
+pub fn sort_by_angle(points: &mut [Vector2], center: Vector2) {
+    points.sort_by(|a, b| {
+        let angle_a = (*a - center).to_angle().rem_euclid(360f32);
+        let angle_b = (*b - center).to_angle().rem_euclid(360f32);
+        angle_a.total_cmp(&angle_b)
+    });
+}
+
+/// Hash grid of point indices, with cells as wide as the search radius so that every point
+/// within it lies in the 3 x 3 block of cells around the query.
+pub(crate) struct PointGrid {
+    cell_size: f32,
+    cells: HashMap<(i64, i64), Vec<usize>>,
+}
+
+impl PointGrid {
+    /// A non-positive radius falls back to unit cells, which still finds exact matches.
+    pub(crate) fn new(radius: f32) -> Self { Self { cell_size: if radius > 0f32 { radius } else { 1f32 }, cells: HashMap::new() } }
+
+    fn cell_of(&self, p: Vector2) -> (i64, i64) { ((p.x / self.cell_size).floor() as i64, (p.y / self.cell_size).floor() as i64) }
+
+    pub(crate) fn insert(&mut self, index: usize, p: Vector2) { self.cells.entry(self.cell_of(p)).or_default().push(index); }
+
+    /// Indices in the cells around `p`: a superset of those within the radius.
+    pub(crate) fn candidates(&self, p: Vector2) -> impl Iterator<Item = usize> + '_ {
+        let (cx, cy) = self.cell_of(p);
+        (-1..=1)
+            .flat_map(move |dy| (-1..=1).map(move |dx| (cx + dx, cy + dy)))
+            .filter_map(|cell| self.cells.get(&cell))
+            .flatten()
+            .copied()
+    }
+}
+
+/// Collapses points lying within `tolerance` of an earlier kept point onto it. Returns the kept
+/// points in first-seen order and, for every input index, the index of its kept point.
+pub fn dedup_points(points: &[Vector2], tolerance: f32) -> (Vec<Vector2>, Vec<usize>) {
+    let mut grid = PointGrid::new(tolerance);
+    let mut unique: Vec<Vector2> = Vec::new();
+    let mut remap = Vec::with_capacity(points.len());
+    let max_sqr_distance = tolerance.max(0f32) * tolerance.max(0f32);
+
+    for &point in points {
+        let existing = grid.candidates(point).filter(|&i| (unique[i] - point).sqr_magnitude() <= max_sqr_distance).min();
+        match existing {
+            Some(i) => remap.push(i),
+            None => {
+                grid.insert(unique.len(), point);
+                remap.push(unique.len());
+                unique.push(point);
+            }
+        }
+    }
+    (unique, remap)
+}
+
+/// Snaps every point to the centre of its `cell_size` grid cell and keeps the first point
+/// seen in each cell. A non-positive `cell_size` gives no points.
+pub fn snap_and_dedup(points: &[Vector2], cell_size: f32) -> Vec<Vector2> {
+    if cell_size <= 0f32 {
+        return Vec::new();
+    }
+    let mut seen = HashSet::new();
+    points
+        .iter()
+        .filter_map(|p| {
+            let cell = ((p.x / cell_size).floor() as i64, (p.y / cell_size).floor() as i64);
+            seen.insert(cell).then(|| Vector2::new((cell.0 as f32 + 0.5f32) * cell_size, (cell.1 as f32 + 0.5f32) * cell_size))
+        })
+        .collect()
+}
+
+/// `points` points on an Archimedean spiral starting at `center` and winding `turns` times
+/// counter-clockwise, with successive turns `spacing` apart. Points are evenly spaced in angle.
+pub fn spiral(center: Vector2, turns: f32, spacing: f32, points: usize) -> Vec<Vector2> {
+    let total_angle = turns * 2f32 * PI;
+    let growth = spacing / (2f32 * PI);
+    (0..points)
+        .map(|i| {
+            let t = if points > 1 { i as f32 / (points - 1) as f32 } else { 0f32 };
+            let angle = t * total_angle;
+            let (sin, cos) = angle.sin_cos();
+            center + Vector2::new(cos, sin) * (growth * angle)
+        })
+        .collect()
+}
+
+/// `count` points evenly spaced counter-clockwise around a circle, the first one at
+/// `start_angle_degrees` from +x.
+pub fn points_on_circle(center: Vector2, radius: f32, count: usize, start_angle_degrees: f32) -> Vec<Vector2> {
+    let step = 360f32 / count.max(1) as f32;
+    (0..count)
+        .map(|i| center + Vector2::from_angle(start_angle_degrees + step * i as f32) * radius)
+        .collect()
+}
+
+/// `nx` by `ny` grid of points starting at `origin`, row by row.
+pub fn square_lattice(origin: Vector2, spacing: Vector2, nx: usize, ny: usize) -> Vec<Vector2> {
+    lattice(origin, spacing, nx, ny, 0f32)
+}
+
+/// Like [`square_lattice`] but every odd row is shifted by half a cell along x. Use a row
+/// spacing of `spacing.x * sqrt(3) / 2` for equilateral triangles.
+pub fn triangular_lattice(origin: Vector2, spacing: Vector2, nx: usize, ny: usize) -> Vec<Vector2> {
+    lattice(origin, spacing, nx, ny, 0.5f32)
+}
+
+fn lattice(origin: Vector2, spacing: Vector2, nx: usize, ny: usize, odd_row_shift: f32) -> Vec<Vector2> {
+    let mut points = Vec::with_capacity(nx * ny);
+    for j in 0..ny {
+        let shift = if j % 2 == 1 { odd_row_shift } else { 0f32 };
+        for i in 0..nx {
+            points.push(origin + Vector2::new((i as f32 + shift) * spacing.x, j as f32 * spacing.y));
+        }
+    }
+    points
+}
+
+/// Tightest parallelogram with sides along `axis1` and `axis2` around `points`, corners in the
+/// order min-min, max-min, max-max, min-max of the coordinates along the axes. The axes need not
+/// be unit length or orthogonal, only independent. An empty slice gives four zero corners.
+///
+/// # Panics
+///
+/// If `points` is not empty and `axis1` and `axis2` are parallel.
+pub fn bounding_parallelogram(points: &[Vector2], axis1: Vector2, axis2: Vector2) -> [Vector2; 4] {
+    if points.is_empty() {
+        return [Vector2::zero(); 4];
+    }
+    let determinant = Vector2::cross(axis1, axis2);
+    assert!(determinant != 0f32, "axes must not be parallel");
+    // Solve p = s * axis1 + t * axis2 for each point.
+    let (mut s_range, mut t_range) = ((f32::INFINITY, f32::NEG_INFINITY), (f32::INFINITY, f32::NEG_INFINITY));
+    for &p in points {
+        let (s, t) = (Vector2::cross(p, axis2) / determinant, Vector2::cross(axis1, p) / determinant);
+        s_range = (s_range.0.min(s), s_range.1.max(s));
+        t_range = (t_range.0.min(t), t_range.1.max(t));
+    }
+    let corner = |s: f32, t: f32| axis1 * s + axis2 * t;
+    [corner(s_range.0, t_range.0), corner(s_range.1, t_range.0), corner(s_range.1, t_range.1), corner(s_range.0, t_range.1)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nearest() {
+        let points = [Vector2::new(0f32, 0f32), Vector2::new(3f32, 4f32), Vector2::new(-1f32, 1f32), Vector2::new(3f32, 4f32)];
+        assert_eq!(nearest(&points, Vector2::new(2f32, 3f32)), Some((1, 2f32.sqrt())));
+        assert_eq!(nearest(&points, Vector2::new(-1f32, 1f32)), Some((2, 0f32)));
+        assert_eq!(nearest(&[], Vector2::zero()), None);
+    }
+
+    #[test]
+    fn test_sort_by_angle() {
+        let center = Vector2::new(1f32, 1f32);
+        let mut points = [
+            center + Vector2::down(),
+            center + Vector2::left(),
+            center + Vector2::right(),
+            center + Vector2::up(),
+        ];
+        sort_by_angle(&mut points, center);
+        assert_eq!(points, [
+            center + Vector2::right(),
+            center + Vector2::up(),
+            center + Vector2::left(),
+            center + Vector2::down(),
+        ]);
+    }
+
+    #[test]
+    fn test_sort_by_angle_is_stable() {
+        let mut points = [
+            Vector2::new(0f32, 2f32),
+            Vector2::new(3f32, 0f32),
+            Vector2::new(0f32, 1f32),
+            Vector2::new(1f32, 0f32),
+            Vector2::new(-1f32, -1f32),
+        ];
+        sort_by_angle(&mut points, Vector2::zero());
+        assert_eq!(points, [
+            Vector2::new(3f32, 0f32),
+            Vector2::new(1f32, 0f32),
+            Vector2::new(0f32, 2f32),
+            Vector2::new(0f32, 1f32),
+            Vector2::new(-1f32, -1f32),
+        ]);
+    }
+
+    #[test]
+    fn test_dedup_points() {
+        let points = [
+            Vector2::new(0f32, 0f32),
+            Vector2::new(1f32, 0f32),
+            Vector2::new(0.005f32, -0.005f32),
+            Vector2::new(1f32, 1f32),
+            Vector2::new(0.995f32, 0.002f32),
+        ];
+        let (unique, remap) = dedup_points(&points, 0.01f32);
+        assert_eq!(unique, vec![points[0], points[1], points[3]]);
+        assert_eq!(remap, vec![0, 1, 0, 2, 1]);
+    }
+
+    #[test]
+    fn test_dedup_points_reindexes_polygon() {
+        let points = [
+            Vector2::new(0f32, 0f32),
+            Vector2::new(2f32, 0f32),
+            Vector2::new(2f32, 0f32),
+            Vector2::new(2f32, 2f32),
+            Vector2::new(1E-04f32, 2f32),
+            Vector2::new(0f32, 2f32),
+        ];
+        let polygon = [0, 1, 2, 3, 4, 5];
+        let (unique, remap) = dedup_points(&points, 1E-03f32);
+        let mut reindexed: Vec<usize> = polygon.iter().map(|&i| remap[i]).collect();
+        reindexed.dedup();
+        assert_eq!(reindexed, vec![0, 1, 2, 3]);
+        for (i, &p) in points.iter().enumerate() {
+            assert!((unique[remap[i]] - p).magnitude() <= 1E-03f32);
+        }
+    }
+
+    #[test]
+    fn test_dedup_points_across_cell_boundary() {
+        let points = [Vector2::new(0.999f32, 0f32), Vector2::new(1.001f32, 0f32), Vector2::new(-0.001f32, 0f32), Vector2::new(0.001f32, 0f32)];
+        let (unique, remap) = dedup_points(&points, 0.01f32);
+        assert_eq!(unique.len(), 2);
+        assert_eq!(remap, vec![0, 0, 1, 1]);
+    }
+
+    #[test]
+    fn test_dedup_points_exact() {
+        let points = [Vector2::one(), Vector2::new(1f32, 1.0001f32), Vector2::one()];
+        let (unique, remap) = dedup_points(&points, 0f32);
+        assert_eq!(unique.len(), 2);
+        assert_eq!(remap, vec![0, 1, 0]);
+    }
+
+    #[test]
+    fn test_snap_and_dedup() {
+        let points = [
+            Vector2::new(2.2f32, 0.1f32),
+            Vector2::new(0.4f32, 0.6f32),
+            Vector2::new(2.9f32, 0.9f32),
+            Vector2::new(-0.2f32, 0.5f32),
+            Vector2::new(0.1f32, 0.1f32),
+        ];
+        let result = snap_and_dedup(&points, 1f32);
+        assert_eq!(result, vec![Vector2::new(2.5f32, 0.5f32), Vector2::new(0.5f32, 0.5f32), Vector2::new(-0.5f32, 0.5f32)]);
+        assert_eq!(snap_and_dedup(&[Vector2::new(3f32, -1f32)], 2f32), vec![Vector2::new(3f32, -1f32)]);
+        assert!(snap_and_dedup(&[], 1f32).is_empty());
+    }
+
+    #[test]
+    fn test_snap_and_dedup_non_positive_cell_size() {
+        let points = [Vector2::new(2.2f32, 0.1f32), Vector2::new(0.4f32, 0.6f32)];
+        assert!(snap_and_dedup(&points, 0f32).is_empty());
+        assert!(snap_and_dedup(&points, -1f32).is_empty());
+    }
+
+    #[test]
+    fn test_spiral() {
+        let center = Vector2::new(1f32, -2f32);
+        let points = spiral(center, 3f32, 0.5f32, 61);
+        assert_eq!(points.len(), 61);
+        assert_eq!(points[0], center);
+        for (i, &p) in points.iter().enumerate() {
+            let angle = i as f32 / 60f32 * 3f32 * 2f32 * PI;
+            let radius = (p - center).magnitude();
+            assert!((radius - 0.5f32 * angle / (2f32 * PI)).abs() < 1E-04f32);
+        }
+        assert!(((points[60] - center).magnitude() - 1.5f32).abs() < 1E-04f32);
+        assert!(((points[20] - center).magnitude() - 0.5f32).abs() < 1E-04f32);
+        assert_eq!(spiral(center, 2f32, 1f32, 1), vec![center]);
+        assert!(spiral(center, 2f32, 1f32, 0).is_empty());
+    }
+
+    #[test]
+    fn test_points_on_circle() {
+        let center = Vector2::new(2f32, 3f32);
+        let points = points_on_circle(center, 5f32, 6, 30f32);
+        assert_eq!(points.len(), 6);
+        for (i, &p) in points.iter().enumerate() {
+            assert!(((p - center).magnitude() - 5f32).abs() < 1E-05f32);
+            let next = points[(i + 1) % 6];
+            assert!((Vector2::signed_angle(p - center, next - center) - 60f32).abs() < 1E-03f32);
+        }
+        assert!(Vector2::angle(points[0] - center, Vector2::from_angle(30f32)) < 1E-02f32);
+    }
+
+    #[test]
+    fn test_points_on_circle_single_point() {
+        let points = points_on_circle(Vector2::zero(), 2f32, 1, 90f32);
+        assert_eq!(points.len(), 1);
+        assert!((points[0] - Vector2::new(0f32, 2f32)).magnitude() < 1E-06f32);
+        assert!(points_on_circle(Vector2::zero(), 2f32, 0, 0f32).is_empty());
+    }
+
+    #[test]
+    fn test_square_lattice() {
+        let origin = Vector2::new(1f32, 1f32);
+        let points = square_lattice(origin, Vector2::new(2f32, 3f32), 3, 2);
+        assert_eq!(points, vec![
+            Vector2::new(1f32, 1f32), Vector2::new(3f32, 1f32), Vector2::new(5f32, 1f32),
+            Vector2::new(1f32, 4f32), Vector2::new(3f32, 4f32), Vector2::new(5f32, 4f32),
+        ]);
+        assert!(square_lattice(origin, Vector2::one(), 0, 5).is_empty());
+    }
+
+    #[test]
+    fn test_triangular_lattice() {
+        let points = triangular_lattice(Vector2::zero(), Vector2::new(2f32, 1f32), 2, 4);
+        assert_eq!(points.len(), 8);
+        for (j, row) in points.chunks(2).enumerate() {
+            let shift = if j % 2 == 1 { 1f32 } else { 0f32 };
+            assert_eq!(row[0], Vector2::new(shift, j as f32));
+            assert_eq!(row[1] - row[0], Vector2::new(2f32, 0f32));
+        }
+    }
+
+    #[test]
+    fn test_bounding_parallelogram_axis_aligned() {
+        let points = [Vector2::new(1f32, 5f32), Vector2::new(-2f32, 3f32), Vector2::new(0f32, 7f32)];
+        let corners = bounding_parallelogram(&points, Vector2::right(), Vector2::up());
+        assert_eq!(corners, [Vector2::new(-2f32, 3f32), Vector2::new(1f32, 3f32), Vector2::new(1f32, 7f32), Vector2::new(-2f32, 7f32)]);
+    }
+
+    #[test]
+    fn test_bounding_parallelogram_skewed() {
+        let points: Vec<Vector2> = spiral(Vector2::new(1f32, 2f32), 3f32, 0.5f32, 60);
+        let (axis1, axis2) = (Vector2::new(2f32, 0.5f32), Vector2::new(0.3f32, 1f32));
+        let corners = bounding_parallelogram(&points, axis1, axis2);
+        // Edge vectors follow the axes.
+        assert!(Vector2::cross(corners[1] - corners[0], axis1).abs() < 1E-04f32);
+        assert!(Vector2::cross(corners[3] - corners[0], axis2).abs() < 1E-04f32);
+        let inside = |p: Vector2| (0..4).all(|i| Vector2::cross(corners[(i + 1) % 4] - corners[i], p - corners[i]) >= -1E-04f32);
+        assert!(points.iter().all(|&p| inside(p)));
+        // Tight: every side is touched by some point.
+        for i in 0..4 {
+            let (a, b) = (corners[i], corners[(i + 1) % 4]);
+            let nearest = points.iter().map(|&p| Vector2::cross(b - a, p - a).abs() / (b - a).magnitude()).fold(f32::INFINITY, f32::min);
+            assert!(nearest < 1E-04f32);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "axes must not be parallel")]
+    fn test_bounding_parallelogram_parallel_axes() {
+        bounding_parallelogram(&[Vector2::one()], Vector2::right(), Vector2::new(-2f32, 0f32));
+    }
+}
@@ -0,0 +1,481 @@
+use super::rect::{grid_size, Rect};
+use super::segment::{closest_points_between_segments, distance_to_segment};
+use super::triangulation::triangulate;
+use super::vector2::Vector2;
+
+/// Shoelace area, positive for counter-clockwise polygons.
+pub fn signed_area(polygon: &[Vector2]) -> f32 {
+    let n = polygon.len();
+    (0..n).map(|i| Vector2::cross(polygon[i], polygon[(i + 1) % n])).sum::<f32>() * 0.5f32
+}
+
+/// Area-weighted centroid of a simple polygon, or `None` when its area is zero.
+pub fn polygon_centroid(polygon: &[Vector2]) -> Option<Vector2> {
+    let n = polygon.len();
+    let area = signed_area(polygon);
+    if area == 0f32 {
+        return None;
+    }
+    let sum = (0..n).fold(Vector2::zero(), |sum, i| {
+        let (a, b) = (polygon[i], polygon[(i + 1) % n]);
+        sum + (a + b) * Vector2::cross(a, b)
+    });
+    Some(sum / (6f32 * area))
+}
+
+/// Shoelace term `cross(p[i], p[i + 1])` of each edge, wrapping at the end. They sum to twice
+/// the signed area, and moving vertex `i` only changes the terms of edges `i - 1` and `i`.
+pub fn edge_area_contributions(polygon: &[Vector2]) -> Vec<f32> {
+    let n = polygon.len();
+    (0..n).map(|i| Vector2::cross(polygon[i], polygon[(i + 1) % n])).collect()
+}
+
+/// Vertex `vertex_index` moved `distance` along the bisector of its two edge normals, towards
+/// the inside of the polygon whichever way it winds. At a reflex vertex this still points into
+/// the polygon, between the two edges.
+pub fn inward_offset_point(polygon: &[Vector2], vertex_index: usize, distance: f32) -> Vector2 {
+    let n = polygon.len();
+    let point = polygon[vertex_index];
+    let (previous, next) = (polygon[(vertex_index + n - 1) % n], polygon[(vertex_index + 1) % n]);
+    let (incoming, outgoing) = ((point - previous).normalized().perpendicular(), (next - point).normalized().perpendicular());
+    let mut bisector = (incoming + outgoing).normalized();
+    if bisector == Vector2::zero() {
+        bisector = incoming;
+    }
+    // Left normals point inwards for counter-clockwise polygons.
+    let inward = if signed_area(polygon) < 0f32 { -1f32 } else { 1f32 };
+    point + bisector * (distance * inward)
+}
+
+/// Even-odd inside test that works for either winding. Points exactly on an edge may land on
+/// either side.
+pub fn point_in_polygon(point: Vector2, polygon: &[Vector2]) -> bool {
+    let n = polygon.len();
+    let mut inside = false;
+    for i in 0..n {
+        let (a, b) = (polygon[i], polygon[(i + 1) % n]);
+        if (a.y > point.y) != (b.y > point.y) && point.x < a.x + (point.y - a.y) * (b.x - a.x) / (b.y - a.y) {
+            inside = !inside;
+        }
+    }
+    inside
+}
+
+/// Signed number of times the closed `path` wraps around `point`: positive for
+/// counter-clockwise turns, negative for clockwise ones, zero outside. Unlike
+/// [`point_in_polygon`], overlapping and repeated loops add up. Points exactly on the path may
+/// count either way.
+pub fn winding_number(point: Vector2, path: &[Vector2]) -> i32 {
+    let n = path.len();
+    let mut winding = 0;
+    for i in 0..n {
+        let (a, b) = (path[i], path[(i + 1) % n]);
+        let side = Vector2::cross(b - a, point - a);
+        if a.y <= point.y && b.y > point.y && side > 0f32 {
+            winding += 1;
+        } else if a.y > point.y && b.y <= point.y && side < 0f32 {
+            winding -= 1;
+        }
+    }
+    winding
+}
+
+/// Total [`winding_number`] of all `paths` at the centre of every `cell_size` cell of
+/// `bounds`, indexed `[y][x]`. Cells with a nonzero total are filled under the nonzero rule.
+/// A non-positive `cell_size` gives an empty grid.
+pub fn nonzero_fill_regions(paths: &[Vec<Vector2>], bounds: Rect, cell_size: f32) -> Vec<Vec<i32>> {
+    let (width, height) = grid_size(bounds, cell_size);
+    (0..height)
+        .map(|y| {
+            (0..width)
+                .map(|x| {
+                    let center = bounds.min() + Vector2::new(x as f32 + 0.5f32, y as f32 + 0.5f32) * cell_size;
+                    paths.iter().map(|path| winding_number(center, path)).sum()
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Distance from `point` to the nearest edge of the closed `polygon`, whether inside or out.
+pub fn distance_to_boundary(point: Vector2, polygon: &[Vector2]) -> f32 {
+    let n = polygon.len();
+    (0..n).map(|i| distance_to_segment(point, polygon[i], polygon[(i + 1) % n])).fold(f32::INFINITY, f32::min)
+}
+
+/// True when every turn goes the same way, in either winding, and the boundary wraps around
+/// only once (ruling out stars). Collinear vertices are allowed; fewer than three points or a
+/// fully collinear polygon are not convex.
+pub fn is_convex(polygon: &[Vector2]) -> bool {
+    let n = polygon.len();
+    let (mut sign, mut total_turn) = (0f32, 0f32);
+    for i in 0..n {
+        let incoming = polygon[(i + 1) % n] - polygon[i];
+        let outgoing = polygon[(i + 2) % n] - polygon[(i + 1) % n];
+        let cross = Vector2::cross(incoming, outgoing);
+        if cross != 0f32 {
+            if sign * cross < 0f32 {
+                return false;
+            }
+            sign = cross.signum();
+        }
+        total_turn += Vector2::signed_angle(incoming, outgoing);
+    }
+    sign != 0f32 && (total_turn.abs() - 360f32).abs() < 1f32
+}
+
+/// Splits a simple polygon into convex pieces by triangulating it and then greedily merging
+/// neighbouring pieces while the union stays convex (Hertel-Mehlhorn). Pieces wind
+/// counter-clockwise; a convex input is returned unchanged as the only piece.
+pub fn decompose_convex(polygon: &[Vector2]) -> Vec<Vec<Vector2>> {
+    if is_convex(polygon) {
+        return vec![polygon.to_vec()];
+    }
+    let mut pieces: Vec<Vec<usize>> = triangulate(polygon).into_iter().map(|t| t.to_vec()).collect();
+    let mut merged = true;
+    while merged {
+        merged = false;
+        'search: for i in 0..pieces.len() {
+            for j in i + 1..pieces.len() {
+                let Some(union) = merge_along_shared_edge(&pieces[i], &pieces[j]) else {
+                    continue;
+                };
+                let points: Vec<Vector2> = union.iter().map(|&k| polygon[k]).collect();
+                if is_convex(&points) {
+                    pieces[i] = union;
+                    pieces.swap_remove(j);
+                    merged = true;
+                    break 'search;
+                }
+            }
+        }
+    }
+    pieces.iter().map(|piece| piece.iter().map(|&k| polygon[k]).collect()).collect()
+}
+
+/// Union of two counter-clockwise index cycles that share the edge `u -> v` in `a` and
+/// `v -> u` in `b`.
+fn merge_along_shared_edge(a: &[usize], b: &[usize]) -> Option<Vec<usize>> {
+    let (na, nb) = (a.len(), b.len());
+    for i in 0..na {
+        let (u, v) = (a[i], a[(i + 1) % na]);
+        if let Some(j) = (0..nb).find(|&j| b[j] == v && b[(j + 1) % nb] == u) {
+            // Walk `a` from `v` round to `u`, then `b` strictly between `u` and `v`.
+            let mut union: Vec<usize> = (0..na).map(|k| a[(i + 1 + k) % na]).collect();
+            union.extend((0..nb - 2).map(|k| b[(j + 2 + k) % nb]));
+            return Some(union);
+        }
+    }
+    None
+}
+
+/// Crossing data for one non-horizontal edge: its y range and the line `x = x0 + (y - y0) * slope`.
+struct CrossingEdge {
+    y0: f32,
+    y1: f32,
+    x0: f32,
+    slope: f32,
+}
+
+/// [`point_in_polygon`] for many points at once. Edge slopes and the polygon bounds are worked
+/// out once, and points outside the bounds are rejected without touching the edges.
+pub fn points_in_polygon(points: &[Vector2], polygon: &[Vector2]) -> Vec<bool> {
+    let Some(bounds) = Rect::from_points(polygon) else {
+        return vec![false; points.len()];
+    };
+    let n = polygon.len();
+    let edges: Vec<CrossingEdge> = (0..n)
+        .map(|i| (polygon[i], polygon[(i + 1) % n]))
+        .filter(|(a, b)| a.y != b.y)
+        .map(|(a, b)| CrossingEdge { y0: a.y, y1: b.y, x0: a.x, slope: (b.x - a.x) / (b.y - a.y) })
+        .collect();
+    points
+        .iter()
+        .map(|&p| {
+            bounds.contains(p)
+                && edges.iter().filter(|e| (e.y0 > p.y) != (e.y1 > p.y) && p.x < e.x0 + (p.y - e.y0) * e.slope).count() % 2 == 1
+        })
+        .collect()
+}
+
+/// Closest pair of points between the boundaries of two closed polygons, `(on_a, on_b,
+/// distance)`, found by testing every pair of edges. Crossing boundaries give distance zero;
+/// one polygon nested inside the other gives the gap between their boundaries.
+///
+/// # Panics
+///
+/// If either polygon is empty.
+pub fn shortest_bridge(a: &[Vector2], b: &[Vector2]) -> (Vector2, Vector2, f32) {
+    assert!(!a.is_empty() && !b.is_empty(), "shortest_bridge needs two non-empty polygons");
+    let (n, m) = (a.len(), b.len());
+    let mut best = (a[0], b[0], f32::INFINITY);
+    for i in 0..n {
+        for j in 0..m {
+            let (p, q) = closest_points_between_segments(a[i], a[(i + 1) % n], b[j], b[(j + 1) % m]);
+            let distance = (q - p).magnitude();
+            if distance < best.2 {
+                best = (p, q, distance);
+            }
+        }
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::test_support::{l_shape, Lcg};
+
+    #[test]
+    fn test_signed_area() {
+        let square = [Vector2::zero(), Vector2::new(2f32, 0f32), Vector2::new(2f32, 2f32), Vector2::new(0f32, 2f32)];
+        assert_eq!(signed_area(&square), 4f32);
+        let mut reversed = square;
+        reversed.reverse();
+        assert_eq!(signed_area(&reversed), -4f32);
+        assert_eq!(signed_area(&square[..2]), 0f32);
+    }
+
+    #[test]
+    fn test_edge_area_contributions() {
+        let mut shape = l_shape();
+        let mut terms = edge_area_contributions(&shape);
+        assert_eq!(terms.len(), shape.len());
+        assert_eq!(terms.iter().sum::<f32>(), 2f32 * signed_area(&shape));
+
+        // Move one vertex and refresh only its two edges.
+        let (i, n) = (3, shape.len());
+        shape[i] = Vector2::new(1.5f32, 2f32);
+        terms[i - 1] = Vector2::cross(shape[i - 1], shape[i]);
+        terms[i] = Vector2::cross(shape[i], shape[(i + 1) % n]);
+        assert_eq!(terms, edge_area_contributions(&shape));
+        assert!((terms.iter().sum::<f32>() * 0.5f32 - signed_area(&shape)).abs() < 1E-06f32);
+    }
+
+    #[test]
+    fn test_inward_offset_point() {
+        let square = [Vector2::zero(), Vector2::new(2f32, 0f32), Vector2::new(2f32, 2f32), Vector2::new(0f32, 2f32)];
+        let moved = inward_offset_point(&square, 0, 2f32.sqrt());
+        assert!((moved - Vector2::one()).magnitude() < 1E-05f32);
+        let mut clockwise = square;
+        clockwise.reverse();
+        let moved = inward_offset_point(&clockwise, 0, 2f32.sqrt());
+        assert!((moved - Vector2::new(1f32, 1f32)).magnitude() < 1E-05f32);
+    }
+
+    #[test]
+    fn test_inward_offset_reflex_vertex() {
+        let shape = l_shape();
+        let moved = inward_offset_point(&shape, 3, 0.5f32);
+        assert!(point_in_polygon(moved, &shape));
+        assert!((moved - (Vector2::one() - Vector2::one().normalized() * 0.5f32)).magnitude() < 1E-05f32);
+        let mut reversed = shape.clone();
+        reversed.reverse();
+        assert!((inward_offset_point(&reversed, 2, 0.5f32) - moved).magnitude() < 1E-05f32);
+        assert!(!point_in_polygon(inward_offset_point(&shape, 3, -0.5f32), &shape));
+    }
+
+    #[test]
+    fn test_polygon_centroid() {
+        let triangle = [Vector2::zero(), Vector2::new(3f32, 0f32), Vector2::new(0f32, 3f32)];
+        assert!((polygon_centroid(&triangle).unwrap() - Vector2::one()).magnitude() < 1E-06f32);
+        let centroid = polygon_centroid(&l_shape()).unwrap();
+        assert!((centroid - Vector2::one() * (19f32 / 14f32)).magnitude() < 1E-05f32);
+        assert_eq!(polygon_centroid(&[Vector2::zero(), Vector2::one()]), None);
+    }
+
+    #[test]
+    fn test_point_in_polygon() {
+        let shape = l_shape();
+        assert!(point_in_polygon(Vector2::new(0.5f32, 3f32), &shape));
+        assert!(point_in_polygon(Vector2::new(3f32, 0.5f32), &shape));
+        assert!(!point_in_polygon(Vector2::new(2f32, 2f32), &shape));
+        assert!(!point_in_polygon(Vector2::new(-1f32, 0.5f32), &shape));
+        let mut reversed = shape.clone();
+        reversed.reverse();
+        assert!(point_in_polygon(Vector2::new(0.5f32, 3f32), &reversed));
+        assert!(!point_in_polygon(Vector2::zero(), &[]));
+    }
+
+    #[test]
+    fn test_points_in_polygon_matches_single() {
+        let shape = l_shape();
+        let mut rng = Lcg(17);
+        let points: Vec<Vector2> = (0..10000).map(|_| Vector2::new(rng.next() * 6f32 - 1f32, rng.next() * 6f32 - 1f32)).collect();
+        let batch = points_in_polygon(&points, &shape);
+        assert_eq!(batch.len(), points.len());
+        for (&point, &inside) in points.iter().zip(&batch) {
+            assert_eq!(inside, point_in_polygon(point, &shape));
+        }
+        assert!(batch.iter().any(|&b| b) && batch.iter().any(|&b| !b));
+        assert_eq!(points_in_polygon(&points[..3], &[]), vec![false; 3]);
+    }
+
+    #[test]
+    fn test_winding_number() {
+        let square = [Vector2::zero(), Vector2::new(2f32, 0f32), Vector2::new(2f32, 2f32), Vector2::new(0f32, 2f32)];
+        let inside = Vector2::one();
+        assert_eq!(winding_number(inside, &square), 1);
+        let mut clockwise = square;
+        clockwise.reverse();
+        assert_eq!(winding_number(inside, &clockwise), -1);
+        // Going round the square twice.
+        let twice: Vec<Vector2> = square.iter().chain(square.iter()).copied().collect();
+        assert_eq!(winding_number(inside, &twice), 2);
+        let twice_clockwise: Vec<Vector2> = clockwise.iter().chain(clockwise.iter()).copied().collect();
+        assert_eq!(winding_number(inside, &twice_clockwise), -2);
+        assert_eq!(winding_number(Vector2::new(3f32, 1f32), &square), 0);
+        assert_eq!(winding_number(Vector2::new(3f32, 1f32), &twice), 0);
+        assert_eq!(winding_number(inside, &[]), 0);
+    }
+
+    #[test]
+    fn test_winding_number_self_overlap() {
+        // A five-pointed star drawn in one stroke wraps its core twice but its points once.
+        let star: Vec<Vector2> = (0..5).map(|i| Vector2::from_angle(90f32 + i as f32 * 144f32) * 2f32).collect();
+        assert_eq!(winding_number(Vector2::new(0.05f32, 0.1f32), &star), 2);
+        assert!(!point_in_polygon(Vector2::new(0.05f32, 0.1f32), &star));
+        let tip = Vector2::from_angle(90f32) * 1.5f32;
+        assert_eq!(winding_number(tip, &star), 1);
+        assert!(point_in_polygon(tip, &star));
+    }
+
+    #[test]
+    fn test_nonzero_fill_regions() {
+        let square = |x: f32, y: f32| vec![Vector2::new(x, y), Vector2::new(x + 2f32, y), Vector2::new(x + 2f32, y + 2f32), Vector2::new(x, y + 2f32)];
+        // Two counter-clockwise squares overlapping on [1, 2] x [1, 2].
+        let paths = [square(0f32, 0f32), square(1f32, 1f32)];
+        let grid = nonzero_fill_regions(&paths, Rect::new(0f32, 0f32, 4f32, 4f32), 0.5f32);
+        assert_eq!((grid.len(), grid[0].len()), (8, 8));
+        let at = |x: f32, y: f32| grid[(y / 0.5f32) as usize][(x / 0.5f32) as usize];
+        assert_eq!(at(0.25f32, 0.25f32), 1);
+        assert_eq!(at(1.25f32, 1.75f32), 2);
+        assert_eq!(at(2.75f32, 2.75f32), 1);
+        assert_eq!(at(3.75f32, 0.25f32), 0);
+        // Even-odd leaves the overlap empty where nonzero fills it.
+        let even_odd = |x: f32, y: f32| paths.iter().filter(|p| point_in_polygon(Vector2::new(x, y), p)).count() % 2 == 1;
+        assert!(!even_odd(1.25f32, 1.75f32) && at(1.25f32, 1.75f32) != 0);
+        let filled = grid.iter().flatten().filter(|&&w| w != 0).count();
+        let odd = grid.iter().flatten().filter(|&&w| w % 2 != 0).count();
+        assert_eq!((filled, odd), (28, 24));
+
+        // A clockwise copy cancels the first square out.
+        let mut reversed = square(0f32, 0f32);
+        reversed.reverse();
+        let cancelled = nonzero_fill_regions(&[square(0f32, 0f32), reversed], Rect::new(0f32, 0f32, 2f32, 2f32), 0.5f32);
+        assert!(cancelled.iter().flatten().all(|&w| w == 0));
+    }
+
+    #[test]
+    fn test_nonzero_fill_regions_non_positive_cell_size() {
+        let bounds = Rect::new(0f32, 0f32, 4f32, 4f32);
+        assert!(nonzero_fill_regions(&[l_shape()], bounds, 0f32).is_empty());
+        assert!(nonzero_fill_regions(&[l_shape()], bounds, -1f32).is_empty());
+    }
+
+    #[test]
+    fn test_is_convex() {
+        let square = [Vector2::zero(), Vector2::new(1f32, 0f32), Vector2::one(), Vector2::new(0f32, 1f32)];
+        assert!(is_convex(&square));
+        let pentagon: Vec<Vector2> = (0..5).map(|i| Vector2::from_angle(i as f32 * 72f32)).collect();
+        assert!(is_convex(&pentagon));
+        let mut clockwise = pentagon.clone();
+        clockwise.reverse();
+        assert!(is_convex(&clockwise));
+        assert!(!is_convex(&l_shape()));
+    }
+
+    #[test]
+    fn test_is_convex_degenerate() {
+        // A vertex in the middle of an edge does not break convexity.
+        let with_collinear = [Vector2::zero(), Vector2::new(1f32, 0f32), Vector2::new(2f32, 0f32), Vector2::new(2f32, 2f32), Vector2::new(0f32, 2f32)];
+        assert!(is_convex(&with_collinear));
+        let star: Vec<Vector2> = (0..5).map(|i| Vector2::from_angle(i as f32 * 144f32)).collect();
+        assert!(!is_convex(&star));
+        assert!(!is_convex(&[Vector2::zero(), Vector2::one(), Vector2::new(2f32, 2f32)]));
+        assert!(!is_convex(&[Vector2::zero(), Vector2::one()]));
+    }
+
+    #[test]
+    fn test_distance_to_boundary() {
+        let shape = l_shape();
+        assert_eq!(distance_to_boundary(Vector2::new(0.5f32, 3f32), &shape), 0.5f32);
+        assert_eq!(distance_to_boundary(Vector2::new(2f32, 2f32), &shape), 1f32);
+        assert_eq!(distance_to_boundary(Vector2::zero(), &[]), f32::INFINITY);
+    }
+
+    fn assert_decomposition(polygon: &[Vector2], pieces: &[Vec<Vector2>]) {
+        assert!(pieces.iter().all(|piece| is_convex(piece)));
+        let total: f32 = pieces.iter().map(|piece| signed_area(piece).abs()).sum();
+        assert!((total - signed_area(polygon).abs()).abs() < 1E-04f32);
+    }
+
+    #[test]
+    fn test_decompose_convex_l_shape() {
+        let shape = l_shape();
+        let pieces = decompose_convex(&shape);
+        assert!(pieces.len() >= 2 && pieces.len() <= 4);
+        assert_decomposition(&shape, &pieces);
+    }
+
+    #[test]
+    fn test_decompose_convex_comb() {
+        let mut comb = vec![Vector2::new(0f32, 0f32), Vector2::new(6f32, 0f32)];
+        for i in (0..3).rev() {
+            comb.push(Vector2::new(i as f32 * 2f32 + 2f32, 4f32));
+            comb.push(Vector2::new(i as f32 * 2f32 + 1f32, 1f32));
+        }
+        let pieces = decompose_convex(&comb);
+        assert!(pieces.len() >= 3);
+        assert_decomposition(&comb, &pieces);
+        comb.reverse();
+        assert_decomposition(&comb, &decompose_convex(&comb));
+    }
+
+    #[test]
+    fn test_decompose_convex_already_convex() {
+        let pentagon: Vec<Vector2> = (0..5).map(|i| Vector2::from_angle(i as f32 * 72f32)).collect();
+        assert_eq!(decompose_convex(&pentagon), vec![pentagon]);
+    }
+
+    #[test]
+    fn test_shortest_bridge_cases() {
+        let square = [Vector2::zero(), Vector2::new(2f32, 0f32), Vector2::new(2f32, 2f32), Vector2::new(0f32, 2f32)];
+        let offset = |dx: f32, dy: f32| square.map(|p| p + Vector2::new(dx, dy));
+        // Vertex to vertex across a diagonal gap.
+        let (p, q, d) = shortest_bridge(&square, &offset(3f32, 3f32));
+        assert_eq!((p, q), (Vector2::new(2f32, 2f32), Vector2::new(3f32, 3f32)));
+        assert!((d - 2f32.sqrt()).abs() < 1E-06f32);
+        // Vertex to edge: a diamond pointing at the square's right side.
+        let diamond = [Vector2::new(3f32, 1f32), Vector2::new(4f32, 0f32), Vector2::new(5f32, 1f32), Vector2::new(4f32, 2f32)];
+        let (p, q, d) = shortest_bridge(&square, &diamond);
+        assert_eq!((p, q, d), (Vector2::new(2f32, 1f32), Vector2::new(3f32, 1f32), 1f32));
+        // Edge to edge: parallel sides, any pair along the overlap has the same length.
+        let (p, q, d) = shortest_bridge(&square, &offset(3f32, 1f32));
+        assert_eq!(d, 1f32);
+        assert_eq!((p.x, q.x), (2f32, 3f32));
+        assert!((1f32..=2f32).contains(&p.y) && p.y == q.y);
+        assert_eq!(shortest_bridge(&square, &offset(1f32, 1f32)).2, 0f32);
+    }
+
+    #[test]
+    fn test_shortest_bridge_matches_sampling() {
+        let a = l_shape();
+        let b: Vec<Vector2> = (0..7).map(|i| Vector2::new(6f32, 2.5f32) + Vector2::from_angle(i as f32 * 360f32 / 7f32 + 10f32) * 1.3f32).collect();
+        let (p, q, d) = shortest_bridge(&a, &b);
+        assert!(((q - p).magnitude() - d).abs() < 1E-06f32 && d > 0.5f32);
+        let sample = |polygon: &[Vector2]| -> Vec<Vector2> {
+            let n = polygon.len();
+            (0..n).flat_map(|i| (0..200).map(move |k| Vector2::lerp(polygon[i], polygon[(i + 1) % n], k as f32 / 200f32))).collect()
+        };
+        let (sa, sb) = (sample(&a), sample(&b));
+        let reference = sa.iter().flat_map(|&x| sb.iter().map(move |&y| (x - y).magnitude())).fold(f32::INFINITY, f32::min);
+        assert!(d <= reference + 1E-05f32 && reference - d < 0.02f32, "{} vs {}", d, reference);
+    }
+
+    #[test]
+    #[should_panic(expected = "two non-empty polygons")]
+    fn test_shortest_bridge_empty() {
+        shortest_bridge(&l_shape(), &[]);
+    }
+}
@@ -0,0 +1,123 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use super::polygon::{distance_to_boundary, point_in_polygon, polygon_centroid};
+use super::rect::Rect;
+use super::vector2::Vector2;
+
+/// Square search cell, ordered by the best clearance any point inside it could have.
+struct Cell {
+    center: Vector2,
+    half: f32,
+    distance: f32,
+    potential: f32,
+}
+
+impl Cell {
+    fn new(center: Vector2, half: f32, polygon: &[Vector2]) -> Self {
+        let distance = signed_distance(center, polygon);
+        Self { center, half, distance, potential: distance + half * std::f32::consts::SQRT_2 }
+    }
+}
+
+impl PartialEq for Cell {
+    fn eq(&self, other: &Self) -> bool { self.potential == other.potential }
+}
+
+impl Eq for Cell {}
+
+impl PartialOrd for Cell {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) }
+}
+
+impl Ord for Cell {
+    fn cmp(&self, other: &Self) -> Ordering { self.potential.total_cmp(&other.potential) }
+}
+
+/// Distance to the boundary, negative outside.
+fn signed_distance(point: Vector2, polygon: &[Vector2]) -> f32 {
+    let distance = distance_to_boundary(point, polygon);
+    if point_in_polygon(point, polygon) { distance } else { -distance }
+}
+
+/// Interior point farthest from the boundary, to within `precision`, found with the polylabel
+/// quadtree search: cells that cannot beat the best clearance so far by more than `precision`
+/// are discarded, the rest are split in four. `precision` is raised to at least `1E-04` of the
+/// polygon's smaller side, below which f32 cells stop shrinking and the search would never
+/// end. Empty input gives zero.
+pub fn pole_of_inaccessibility(polygon: &[Vector2], precision: f32) -> Vector2 {
+    let Some(bounds) = Rect::from_points(polygon) else {
+        return Vector2::zero();
+    };
+    let cell_size = bounds.width.min(bounds.height);
+    if cell_size <= 0f32 {
+        return bounds.min();
+    }
+    let precision = precision.max(cell_size * 1E-04f32);
+    let half = cell_size * 0.5f32;
+    let mut queue = BinaryHeap::new();
+    let mut y = bounds.y;
+    while y < bounds.y_max() {
+        let mut x = bounds.x;
+        while x < bounds.x_max() {
+            queue.push(Cell::new(Vector2::new(x + half, y + half), half, polygon));
+            x += cell_size;
+        }
+        y += cell_size;
+    }
+
+    let mut best = Cell::new(polygon_centroid(polygon).unwrap_or(bounds.center()), 0f32, polygon);
+    let box_center = Cell::new(bounds.center(), 0f32, polygon);
+    if box_center.distance > best.distance {
+        best = box_center;
+    }
+    while let Some(cell) = queue.pop() {
+        if cell.distance > best.distance {
+            best = Cell::new(cell.center, 0f32, polygon);
+        }
+        if cell.potential - best.distance <= precision {
+            continue;
+        }
+        let quarter = cell.half * 0.5f32;
+        for offset in [Vector2::new(-1f32, -1f32), Vector2::new(1f32, -1f32), Vector2::new(-1f32, 1f32), Vector2::new(1f32, 1f32)] {
+            queue.push(Cell::new(cell.center + offset * quarter, quarter, polygon));
+        }
+    }
+    best.center
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::test_support::l_shape;
+
+    #[test]
+    fn test_pole_of_l_shape_beats_centroid() {
+        let shape = l_shape();
+        let pole = pole_of_inaccessibility(&shape, 0.01f32);
+        assert!(point_in_polygon(pole, &shape));
+        let centroid = polygon_centroid(&shape).unwrap();
+        assert!(!point_in_polygon(centroid, &shape));
+        assert!(signed_distance(pole, &shape) > signed_distance(centroid, &shape));
+        assert!(distance_to_boundary(pole, &shape) > 0.49f32);
+    }
+
+    #[test]
+    fn test_pole_of_rectangle_is_center() {
+        let rectangle = [Vector2::zero(), Vector2::new(6f32, 0f32), Vector2::new(6f32, 2f32), Vector2::new(0f32, 2f32)];
+        let pole = pole_of_inaccessibility(&rectangle, 1E-03f32);
+        assert!((pole.y - 1f32).abs() < 1E-02f32);
+        assert!((distance_to_boundary(pole, &rectangle) - 1f32).abs() < 1E-02f32);
+        // No precision at all still terminates, at the floor relative to the shape's size.
+        for precision in [0f32, -1f32] {
+            let pole = pole_of_inaccessibility(&rectangle, precision);
+            assert!((distance_to_boundary(pole, &rectangle) - 1f32).abs() < 1E-03f32);
+        }
+    }
+
+    #[test]
+    fn test_pole_degenerate() {
+        assert_eq!(pole_of_inaccessibility(&[], 1f32), Vector2::zero());
+        assert_eq!(pole_of_inaccessibility(&[Vector2::one(), Vector2::new(3f32, 1f32)], 1f32), Vector2::one());
+    }
+}
@@ -0,0 +1,579 @@
+use super::vector2::Vector2;
+
+/// Signed turn in degrees at each interior vertex, positive for left (counter-clockwise)
+/// turns. A polyline with `n` points yields `n - 2` angles.
+pub fn turn_angles(polyline: &[Vector2]) -> Vec<f32> {
+    polyline
+        .windows(3)
+        .map(|w| Vector2::signed_angle(w[1] - w[0], w[2] - w[1]))
+        .collect()
+}
+
+/// Signed curvature at each interior vertex: the inverse radius of the circle through it and
+/// its two neighbours, positive for left turns. Degenerate triples have zero curvature.
+pub fn curvature(points: &[Vector2]) -> Vec<f32> {
+    points
+        .windows(3)
+        .map(|w| {
+            let (a, b, c) = (w[1] - w[0], w[2] - w[1], w[2] - w[0]);
+            let lengths = a.magnitude() * b.magnitude() * c.magnitude();
+            if lengths == 0f32 { 0f32 } else { 2f32 * Vector2::cross(a, b) / lengths }
+        })
+        .collect()
+}
+
+pub fn polyline_length(points: &[Vector2]) -> f32 {
+    points.windows(2).map(|w| (w[1] - w[0]).magnitude()).sum()
+}
+
+/// Points every `spacing` units of arc length from the first point, always ending with the
+/// last point, so the final gap may be shorter. Inputs with fewer than two points or a
+/// non-positive spacing are returned as-is.
+pub fn resample_polyline(points: &[Vector2], spacing: f32) -> Vec<Vector2> {
+    if points.len() < 2 || spacing <= 0f32 {
+        return points.to_vec();
+    }
+    let total = polyline_length(points);
+    // Drop a sample that would land on top of the final point through rounding.
+    let limit = total - spacing * 1E-04f32;
+    let mut result = vec![points[0]];
+    let mut next = spacing;
+    let mut travelled = 0f32;
+    for w in points.windows(2) {
+        let length = (w[1] - w[0]).magnitude();
+        while next < limit && next <= travelled + length {
+            let t = if length > 0f32 { (next - travelled) / length } else { 0f32 };
+            result.push(Vector2::lerp(w[0], w[1], t));
+            next += spacing;
+        }
+        travelled += length;
+    }
+    result.push(points[points.len() - 1]);
+    result
+}
+
+/// Exactly `n` points evenly spaced by arc length, the first and last being the polyline's
+/// endpoints. A single requested point is the start; a single input point is repeated.
+pub fn resample_to_count(points: &[Vector2], n: usize) -> Vec<Vector2> {
+    if points.is_empty() || n == 0 {
+        return Vec::new();
+    }
+    if n == 1 {
+        return vec![points[0]];
+    }
+    let spacing = polyline_length(points) / (n - 1) as f32;
+    let mut result = Vec::with_capacity(n);
+    result.push(points[0]);
+    let mut travelled = 0f32;
+    for w in points.windows(2) {
+        let length = (w[1] - w[0]).magnitude();
+        while result.len() < n - 1 && result.len() as f32 * spacing <= travelled + length {
+            let t = if length > 0f32 { (result.len() as f32 * spacing - travelled) / length } else { 0f32 };
+            result.push(Vector2::lerp(w[0], w[1], t));
+        }
+        travelled += length;
+    }
+    // Rounding can leave interior samples short of the end; pad them onto the last point.
+    let last = points[points.len() - 1];
+    result.resize(n, last);
+    result[n - 1] = last;
+    result
+}
+
+/// Dashes of `dash` arc length separated by gaps of `gap`, starting with a dash at the first
+/// point. The pattern carries across vertices; a dash spanning a vertex is split there so every
+/// piece is straight.
+pub fn dash_polyline(points: &[Vector2], dash: f32, gap: f32) -> Vec<(Vector2, Vector2)> {
+    let mut dashes = Vec::new();
+    if dash <= 0f32 {
+        return dashes;
+    }
+    let (mut drawing, mut remaining) = (true, dash);
+    for w in points.windows(2) {
+        let length = (w[1] - w[0]).magnitude();
+        let mut position = 0f32;
+        while position < length {
+            let step = remaining.min(length - position);
+            if drawing && step > 0f32 {
+                dashes.push((Vector2::lerp(w[0], w[1], position / length), Vector2::lerp(w[0], w[1], (position + step) / length)));
+            }
+            position += step;
+            remaining -= step;
+            if remaining <= 0f32 {
+                drawing = !drawing;
+                remaining = if drawing { dash } else { gap.max(0f32) };
+            }
+        }
+    }
+    dashes
+}
+
+/// Unit normal at each vertex, on the left of the direction of travel, averaged from the
+/// adjacent edge normals so corners get the bisector. Open polylines use the single edge
+/// normal at their ends; closed ones wrap around.
+pub fn polyline_normals(points: &[Vector2], closed: bool) -> Vec<Vector2> {
+    let n = points.len();
+    if n < 2 {
+        return vec![Vector2::zero(); n];
+    }
+    let edge_normal = |i: usize| (points[(i + 1) % n] - points[i]).normalized().perpendicular();
+    (0..n)
+        .map(|i| {
+            let incoming = if i > 0 { Some(edge_normal(i - 1)) } else if closed { Some(edge_normal(n - 1)) } else { None };
+            let outgoing = if i < n - 1 || closed { Some(edge_normal(i)) } else { None };
+            match (incoming, outgoing) {
+                (Some(a), Some(b)) => {
+                    let average = (a + b).normalized();
+                    if average == Vector2::zero() { a } else { average }
+                }
+                (Some(a), None) => a,
+                (None, Some(b)) => b,
+                (None, None) => Vector2::zero(),
+            }
+        })
+        .collect()
+}
+
+/// Fillet circle of `radius` in the corner at `corner` between the edges towards `a` and `b`:
+/// `(center, tangent_on_a, tangent_on_b)`, with each tangent point `radius` from the centre on
+/// its edge. `None` when the tangent points would fall beyond `a` or `b`, the edges are
+/// straight or fully reversed, or `radius` is not positive.
+pub fn inscribed_circle_in_corner(a: Vector2, corner: Vector2, b: Vector2, radius: f32) -> Option<(Vector2, Vector2, Vector2)> {
+    let (back, ahead) = (a - corner, b - corner);
+    let (to_a, to_b) = (back.normalized(), ahead.normalized());
+    let half_angle = Vector2::angle(to_a, to_b).to_radians() * 0.5f32;
+    if radius <= 0f32 || Vector2::cross(to_a, to_b).abs() < 1E-06f32 || half_angle <= 0f32 {
+        return None;
+    }
+    let setback = radius / half_angle.tan();
+    if setback > back.magnitude() || setback > ahead.magnitude() {
+        return None;
+    }
+    let center = corner + (to_a + to_b).normalized() * (radius / half_angle.sin());
+    Some((center, corner + to_a * setback, corner + to_b * setback))
+}
+
+/// Replaces each interior vertex with a circular fillet of `radius` tangent to both edges,
+/// sampled with `segments_per_corner` segments. The tangent points are kept within half of
+/// each adjacent edge, shrinking the radius where edges are short so neighbouring fillets
+/// never overlap. Straight and fully reversed vertices are kept as they are.
+pub fn round_corners(polyline: &[Vector2], radius: f32, segments_per_corner: usize) -> Vec<Vector2> {
+    let n = polyline.len();
+    if n < 3 {
+        return polyline.to_vec();
+    }
+    let segments = segments_per_corner.max(1);
+    let mut result = vec![polyline[0]];
+    for w in polyline.windows(3) {
+        let (previous, vertex, next) = (w[0], w[1], w[2]);
+        let half_angle = Vector2::angle(previous - vertex, next - vertex).to_radians() * 0.5f32;
+        let max_setback = (previous - vertex).magnitude().min((next - vertex).magnitude()) * 0.5f32;
+        let Some((center, start, end)) = inscribed_circle_in_corner(previous, vertex, next, radius.min(max_setback * half_angle.tan())) else {
+            result.push(vertex);
+            continue;
+        };
+        let (start, fillet) = (start - center, (start - center).magnitude());
+        let sweep = Vector2::signed_angle(start, end - center);
+        for k in 0..=segments {
+            result.push(center + Vector2::from_angle(start.to_angle() + sweep * k as f32 / segments as f32) * fillet);
+        }
+    }
+    result.push(polyline[n - 1]);
+    result
+}
+
+/// Cuts each interior vertex with a straight bevel between the points `distance` back along
+/// both adjacent edges, clamped to half of the shorter edge. Straight vertices are kept.
+pub fn chamfer_corners(polyline: &[Vector2], distance: f32) -> Vec<Vector2> {
+    let n = polyline.len();
+    if n < 3 {
+        return polyline.to_vec();
+    }
+    let mut result = vec![polyline[0]];
+    for w in polyline.windows(3) {
+        let (back, ahead) = (w[0] - w[1], w[2] - w[1]);
+        if Vector2::cross(back, ahead).abs() < 1E-06f32 * back.magnitude() * ahead.magnitude() && Vector2::dot(back, ahead) < 0f32 {
+            result.push(w[1]);
+            continue;
+        }
+        let setback = distance.min(back.magnitude().min(ahead.magnitude()) * 0.5f32);
+        result.push(w[1] + back.normalized() * setback);
+        result.push(w[1] + ahead.normalized() * setback);
+    }
+    result.push(polyline[n - 1]);
+    result
+}
+
+/// Drops interior vertices where the path turns by at most `angle_tolerance_degrees`, measuring
+/// from the last vertex kept so a long gentle run cannot drift. Repeated points are dropped too;
+/// the endpoints always stay.
+pub fn merge_collinear(points: &[Vector2], angle_tolerance_degrees: f32) -> Vec<Vector2> {
+    let n = points.len();
+    if n < 3 {
+        return points.to_vec();
+    }
+    let mut result = vec![points[0]];
+    for i in 1..n - 1 {
+        let (last, current, next) = (*result.last().unwrap(), points[i], points[i + 1]);
+        if current == last || current == next {
+            continue;
+        }
+        if Vector2::angle(current - last, next - current) > angle_tolerance_degrees {
+            result.push(current);
+        }
+    }
+    result.push(points[n - 1]);
+    result
+}
+
+/// Facing angle in degrees at each waypoint, towards the point `look_ahead` further along the
+/// path (or the path's end, once closer than that), so units start turning before a corner
+/// instead of snapping at it. The last waypoint faces along the final segment, and a waypoint
+/// with nothing ahead of it keeps the previous heading.
+pub fn path_headings(waypoints: &[Vector2], look_ahead: f32) -> Vec<f32> {
+    let mut travelled = vec![0f32];
+    for w in waypoints.windows(2) {
+        travelled.push(travelled.last().unwrap() + (w[1] - w[0]).magnitude());
+    }
+    let total = *travelled.last().unwrap();
+    let point_at = |distance: f32| {
+        let i = travelled.partition_point(|&t| t <= distance).clamp(1, waypoints.len().max(2) - 1);
+        let length = travelled[i] - travelled[i - 1];
+        let t = if length > 0f32 { ((distance - travelled[i - 1]) / length).clamp(0f32, 1f32) } else { 1f32 };
+        Vector2::lerp(waypoints[i - 1], waypoints[i], t)
+    };
+    let mut heading = 0f32;
+    let n = waypoints.len();
+    (0..n)
+        .map(|i| {
+            let (from, to) = if i + 1 == n {
+                (waypoints[n.saturating_sub(2)], waypoints[i])
+            } else {
+                (waypoints[i], point_at((travelled[i] + look_ahead.max(0f32)).min(total)))
+            };
+            // Without any look-ahead, face the next distinct waypoint.
+            let to = if to == from { waypoints[i + 1..].iter().copied().find(|&p| p != from).unwrap_or(to) } else { to };
+            if to != from {
+                heading = (to - from).to_angle();
+            }
+            heading
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turn_angles_straight() {
+        let line = [Vector2::new(0f32, 0f32), Vector2::new(1f32, 1f32), Vector2::new(3f32, 3f32), Vector2::new(4f32, 4f32)];
+        assert_eq!(turn_angles(&line), vec![0f32, 0f32]);
+    }
+
+    #[test]
+    fn test_turn_angles_corners() {
+        let path = [
+            Vector2::new(0f32, 0f32),
+            Vector2::new(2f32, 0f32),
+            Vector2::new(2f32, 2f32),
+            Vector2::new(4f32, 2f32),
+            Vector2::new(3f32, 1f32),
+        ];
+        let angles = turn_angles(&path);
+        assert_eq!(angles.len(), 3);
+        assert_eq!(angles[0], 90f32);
+        assert_eq!(angles[1], -90f32);
+        assert!((angles[2] + 135f32).abs() < 1E-04f32);
+    }
+
+    #[test]
+    fn test_turn_angles_short_input() {
+        assert!(turn_angles(&[]).is_empty());
+        assert!(turn_angles(&[Vector2::zero(), Vector2::one()]).is_empty());
+    }
+
+    #[test]
+    fn test_curvature_straight() {
+        let line = [Vector2::new(0f32, 0f32), Vector2::new(1f32, 2f32), Vector2::new(2f32, 4f32), Vector2::new(5f32, 10f32)];
+        assert_eq!(curvature(&line), vec![0f32, 0f32]);
+    }
+
+    #[test]
+    fn test_curvature_circle() {
+        let radius = 4f32;
+        let circle: Vec<Vector2> = (0..20).map(|i| Vector2::from_angle(i as f32 * 7f32) * radius + Vector2::one()).collect();
+        for k in curvature(&circle) {
+            assert!((k - 1f32 / radius).abs() < 1E-03f32);
+        }
+        let reversed: Vec<Vector2> = circle.into_iter().rev().collect();
+        for k in curvature(&reversed) {
+            assert!((k + 1f32 / radius).abs() < 1E-03f32);
+        }
+    }
+
+    #[test]
+    fn test_curvature_degenerate() {
+        assert_eq!(curvature(&[Vector2::one(), Vector2::one(), Vector2::zero()]), vec![0f32]);
+        assert!(curvature(&[Vector2::one()]).is_empty());
+    }
+
+    #[test]
+    fn test_polyline_length() {
+        let path = [Vector2::new(0f32, 0f32), Vector2::new(3f32, 4f32), Vector2::new(3f32, 6f32)];
+        assert_eq!(polyline_length(&path), 7f32);
+        assert_eq!(polyline_length(&path[..1]), 0f32);
+    }
+
+    #[test]
+    fn test_resample_straight_segment() {
+        let line = [Vector2::new(0f32, 0f32), Vector2::new(10f32, 0f32)];
+        let result = resample_polyline(&line, 2f32);
+        assert_eq!(result.len(), 6);
+        for (i, p) in result.iter().enumerate() {
+            assert!((p.x - 2f32 * i as f32).abs() < 1E-05f32);
+        }
+    }
+
+    #[test]
+    fn test_resample_spacing_is_uniform() {
+        let path = [Vector2::new(0f32, 0f32), Vector2::new(3f32, 0f32), Vector2::new(3f32, 3.5f32), Vector2::new(0.5f32, 3.5f32)];
+        let result = resample_polyline(&path, 0.5f32);
+        assert_eq!(result.len(), 19);
+        assert_eq!(result[0], path[0]);
+        assert_eq!(result[18], path[3]);
+        for w in result.windows(2) {
+            assert!(((w[1] - w[0]).magnitude() - 0.5f32).abs() < 1E-04f32);
+        }
+    }
+
+    #[test]
+    fn test_resample_across_corner() {
+        let path = [Vector2::new(0f32, 0f32), Vector2::new(3f32, 0f32), Vector2::new(3f32, 2f32)];
+        let result = resample_polyline(&path, 2f32);
+        assert_eq!(result, vec![Vector2::new(0f32, 0f32), Vector2::new(2f32, 0f32), Vector2::new(3f32, 1f32), Vector2::new(3f32, 2f32)]);
+    }
+
+    #[test]
+    fn test_resample_degenerate_input() {
+        assert_eq!(resample_polyline(&[Vector2::one()], 1f32), vec![Vector2::one()]);
+        let line = [Vector2::zero(), Vector2::one()];
+        assert_eq!(resample_polyline(&line, 0f32), line.to_vec());
+        assert_eq!(resample_polyline(&line, 5f32), line.to_vec());
+    }
+
+    #[test]
+    fn test_polyline_normals_straight() {
+        let line = [Vector2::new(0f32, 0f32), Vector2::new(1f32, 0f32), Vector2::new(3f32, 0f32)];
+        assert_eq!(polyline_normals(&line, false), vec![Vector2::up(); 3]);
+    }
+
+    #[test]
+    fn test_polyline_normals_corner() {
+        let path = [Vector2::new(0f32, 0f32), Vector2::new(2f32, 0f32), Vector2::new(2f32, 2f32)];
+        let normals = polyline_normals(&path, false);
+        assert_eq!(normals[0], Vector2::up());
+        assert_eq!(normals[2], Vector2::left());
+        let d = 0.5f32.sqrt();
+        assert!((normals[1] - Vector2::new(-d, d)).magnitude() < 1E-06f32);
+    }
+
+    #[test]
+    fn test_polyline_normals_closed() {
+        let square = [Vector2::new(0f32, 0f32), Vector2::new(1f32, 0f32), Vector2::new(1f32, 1f32), Vector2::new(0f32, 1f32)];
+        let normals = polyline_normals(&square, true);
+        let d = 0.5f32.sqrt();
+        let expected = [Vector2::new(d, d), Vector2::new(-d, d), Vector2::new(-d, -d), Vector2::new(d, -d)];
+        for (normal, expected) in normals.iter().zip(expected) {
+            assert!((*normal - expected).magnitude() < 1E-06f32);
+        }
+        let open = polyline_normals(&square, false);
+        assert_eq!(open[0], Vector2::up());
+        assert_eq!(open[3], Vector2::down());
+    }
+
+    #[test]
+    fn test_polyline_normals_reversal() {
+        let path = [Vector2::new(0f32, 0f32), Vector2::new(1f32, 0f32), Vector2::new(0f32, 0f32)];
+        assert_eq!(polyline_normals(&path, false)[1], Vector2::up());
+        assert_eq!(polyline_normals(&path[..1], false), vec![Vector2::zero()]);
+    }
+
+    fn dash_length(&(a, b): &(Vector2, Vector2)) -> f32 { (b - a).magnitude() }
+
+    #[test]
+    fn test_dash_polyline_straight() {
+        let line = [Vector2::zero(), Vector2::new(10f32, 0f32)];
+        let dashes = dash_polyline(&line, 2f32, 1f32);
+        assert_eq!(dashes.len(), 4);
+        for (i, dash) in dashes.iter().enumerate().take(3) {
+            assert_eq!(dash.0, Vector2::new(i as f32 * 3f32, 0f32));
+            assert!((dash_length(dash) - 2f32).abs() < 1E-05f32);
+        }
+        assert_eq!(dashes[3], (Vector2::new(9f32, 0f32), Vector2::new(10f32, 0f32)));
+    }
+
+    #[test]
+    fn test_dash_polyline_across_vertices() {
+        let path = [Vector2::zero(), Vector2::new(4f32, 0f32), Vector2::new(4f32, 4f32)];
+        let dashes = dash_polyline(&path, 2f32, 1f32);
+        // The dash from arc length 3 to 5 wraps the corner and is split there.
+        assert_eq!(dashes[1], (Vector2::new(3f32, 0f32), Vector2::new(4f32, 0f32)));
+        assert_eq!(dashes[2], (Vector2::new(4f32, 0f32), Vector2::new(4f32, 1f32)));
+        assert_eq!(dashes[3], (Vector2::new(4f32, 2f32), Vector2::new(4f32, 4f32)));
+        let drawn: f32 = dashes.iter().map(dash_length).sum();
+        assert!((drawn - 6f32).abs() < 1E-05f32);
+    }
+
+    #[test]
+    fn test_dash_polyline_gap_spans_vertex() {
+        let path = [Vector2::zero(), Vector2::new(2.5f32, 0f32), Vector2::new(2.5f32, 5f32)];
+        let dashes = dash_polyline(&path, 2f32, 1f32);
+        assert_eq!(dashes[0], (Vector2::zero(), Vector2::new(2f32, 0f32)));
+        assert_eq!(dashes[1], (Vector2::new(2.5f32, 0.5f32), Vector2::new(2.5f32, 2.5f32)));
+        assert!(dash_polyline(&path, 0f32, 1f32).is_empty());
+    }
+
+    #[test]
+    fn test_resample_to_count() {
+        let path = [Vector2::zero(), Vector2::new(3f32, 0f32), Vector2::new(3f32, 4f32), Vector2::new(0f32, 4f32)];
+        for n in [2, 3, 7, 50, 101] {
+            let samples = resample_to_count(&path, n);
+            assert_eq!(samples.len(), n);
+            assert_eq!(samples[0], path[0]);
+            assert_eq!(samples[n - 1], path[3]);
+            let spacing = 10f32 / (n - 1) as f32;
+            // Chords cut corners, so only straight stretches match the arc spacing exactly.
+            assert!(samples.windows(2).all(|w| (w[1] - w[0]).magnitude() <= spacing + 1E-04f32));
+        }
+        let samples = resample_to_count(&path, 11);
+        assert!((samples[1] - Vector2::new(1f32, 0f32)).magnitude() < 1E-05f32);
+        assert!((samples[5] - Vector2::new(3f32, 2f32)).magnitude() < 1E-05f32);
+        assert!((samples[9] - Vector2::new(1f32, 4f32)).magnitude() < 1E-05f32);
+    }
+
+    #[test]
+    fn test_resample_to_count_degenerate() {
+        assert!(resample_to_count(&[], 5).is_empty());
+        assert!(resample_to_count(&[Vector2::one()], 0).is_empty());
+        assert_eq!(resample_to_count(&[Vector2::one()], 3), vec![Vector2::one(); 3]);
+        assert_eq!(resample_to_count(&[Vector2::zero(), Vector2::one()], 1), vec![Vector2::zero()]);
+    }
+
+    #[test]
+    fn test_inscribed_circle_in_corner() {
+        let (a, corner, b) = (Vector2::new(4f32, 0f32), Vector2::zero(), Vector2::new(3f32, 3f32));
+        let radius = 0.75f32;
+        let (center, on_a, on_b) = inscribed_circle_in_corner(a, corner, b, radius).unwrap();
+        for (tangent, edge) in [(on_a, a - corner), (on_b, b - corner)] {
+            assert!(((tangent - center).magnitude() - radius).abs() < 1E-05f32);
+            // On the edge, with the radius at right angles to it.
+            assert!(Vector2::cross(tangent - corner, edge).abs() < 1E-05f32);
+            assert!(Vector2::dot(tangent - center, edge).abs() < 1E-05f32);
+        }
+        assert!(((on_a - corner).magnitude() - (on_b - corner).magnitude()).abs() < 1E-05f32);
+        // The 45 degree corner needs a setback of r / tan(22.5) = 1.81, more than the 1.5 to a.
+        assert!(inscribed_circle_in_corner(Vector2::new(1.5f32, 0f32), corner, b, radius).is_none());
+        assert!(inscribed_circle_in_corner(a, corner, b, 5f32).is_none());
+        assert!(inscribed_circle_in_corner(a, corner, Vector2::new(-2f32, 0f32), radius).is_none());
+        assert!(inscribed_circle_in_corner(a, corner, b, 0f32).is_none());
+    }
+
+    #[test]
+    fn test_round_corners_straight_unchanged() {
+        let straight = [Vector2::zero(), Vector2::new(1f32, 0f32), Vector2::new(3f32, 0f32)];
+        assert_eq!(round_corners(&straight, 0.5f32, 4), straight.to_vec());
+        assert_eq!(round_corners(&straight[..2], 0.5f32, 4), straight[..2].to_vec());
+    }
+
+    #[test]
+    fn test_round_corners_tangent_arc() {
+        let corner = [Vector2::zero(), Vector2::new(4f32, 0f32), Vector2::new(4f32, 4f32)];
+        let rounded = round_corners(&corner, 1f32, 8);
+        assert_eq!(rounded.len(), 2 + 9);
+        let center = Vector2::new(3f32, 1f32);
+        assert!(rounded[1..10].iter().all(|&p| ((p - center).magnitude() - 1f32).abs() < 1E-05f32));
+        assert!((rounded[1] - Vector2::new(3f32, 0f32)).magnitude() < 1E-05f32);
+        assert!((rounded[9] - Vector2::new(4f32, 1f32)).magnitude() < 1E-05f32);
+        // Tangent: the arc leaves along the incoming edge and arrives along the outgoing one.
+        assert!(Vector2::angle(rounded[2] - rounded[1], Vector2::right()) < 6f32);
+        assert!(Vector2::angle(rounded[9] - rounded[8], Vector2::up()) < 6f32);
+    }
+
+    #[test]
+    fn test_round_corners_clamps_radius() {
+        let zigzag = [Vector2::zero(), Vector2::new(1f32, 0f32), Vector2::new(1f32, 1f32), Vector2::new(2f32, 1f32)];
+        let rounded = round_corners(&zigzag, 5f32, 4);
+        // Each fillet stops at the middle of the shared edge, so the path stays monotone.
+        assert!((rounded[5] - Vector2::new(1f32, 0.5f32)).magnitude() < 1E-05f32);
+        assert!((rounded[6] - Vector2::new(1f32, 0.5f32)).magnitude() < 1E-05f32);
+        assert!(rounded.windows(2).all(|w| w[1].x >= w[0].x - 1E-05f32 && w[1].y >= w[0].y - 1E-05f32));
+    }
+
+    #[test]
+    fn test_chamfer_corners() {
+        let path = [Vector2::zero(), Vector2::new(4f32, 0f32), Vector2::new(4f32, 4f32), Vector2::new(6f32, 4f32), Vector2::new(8f32, 4f32)];
+        let chamfered = chamfer_corners(&path, 1.5f32);
+        let expected = [
+            Vector2::zero(),
+            Vector2::new(2.5f32, 0f32),
+            Vector2::new(4f32, 1.5f32),
+            Vector2::new(4f32, 3f32),
+            Vector2::new(5f32, 4f32),
+            Vector2::new(6f32, 4f32),
+            Vector2::new(8f32, 4f32),
+        ];
+        assert_eq!(chamfered.len(), expected.len());
+        for (a, b) in chamfered.iter().zip(&expected) {
+            assert!((*a - *b).magnitude() < 1E-05f32, "{} != {}", a, b);
+        }
+        // The second corner's setback is clamped to half of its 2-unit outgoing edge.
+        assert!(((chamfered[4] - path[2]).magnitude() - 1f32).abs() < 1E-05f32);
+        assert_eq!(chamfer_corners(&path[..2], 1f32), path[..2].to_vec());
+    }
+
+    #[test]
+    fn test_merge_collinear() {
+        let run = [Vector2::zero(), Vector2::new(1f32, 0f32), Vector2::new(3f32, 0f32)];
+        assert_eq!(merge_collinear(&run, 0.1f32), vec![run[0], run[2]]);
+        let path = [
+            Vector2::zero(),
+            Vector2::new(1f32, 0f32),
+            Vector2::new(1f32, 0f32),
+            Vector2::new(2f32, 0.001f32),
+            Vector2::new(3f32, 0f32),
+            Vector2::new(3f32, 2f32),
+            Vector2::new(3f32, 4f32),
+        ];
+        assert_eq!(merge_collinear(&path, 0.5f32), vec![path[0], path[4], path[6]]);
+        assert_eq!(merge_collinear(&path, 0f32), vec![path[0], path[1], path[3], path[4], path[6]]);
+        // A backtrack is a 180-degree turn and is kept.
+        let back = [Vector2::zero(), Vector2::new(2f32, 0f32), Vector2::new(1f32, 0f32)];
+        assert_eq!(merge_collinear(&back, 1f32), back.to_vec());
+    }
+
+    #[test]
+    fn test_path_headings_straight() {
+        let path = resample_polyline(&[Vector2::new(1f32, 1f32), Vector2::new(5f32, 5f32)], 0.5f32);
+        let headings = path_headings(&path, 2f32);
+        assert_eq!(headings.len(), path.len());
+        assert!(headings.iter().all(|h| (h - 45f32).abs() < 1E-03f32), "{:?}", headings);
+        assert_eq!(path_headings(&[Vector2::zero(), Vector2::zero(), Vector2::up()], 0f32), vec![90f32; 3]);
+        assert!(path_headings(&[], 1f32).is_empty());
+        assert_eq!(path_headings(&[Vector2::one()], 1f32), vec![0f32]);
+    }
+
+    #[test]
+    fn test_path_headings_turn_gradually() {
+        // East for 5 units, then north for 5, sampled every quarter unit.
+        let path = resample_polyline(&[Vector2::zero(), Vector2::new(5f32, 0f32), Vector2::new(5f32, 5f32)], 0.25f32);
+        let headings = path_headings(&path, 2f32);
+        assert!((headings[0]).abs() < 1E-03f32 && (headings.last().unwrap() - 90f32).abs() < 1E-03f32);
+        assert!(headings.windows(2).all(|w| w[1] >= w[0] - 1E-03f32 && w[1] - w[0] < 20f32), "{:?}", headings);
+        let turning = headings.iter().filter(|&&h| h > 1f32 && h < 89f32).count();
+        assert!(turning >= 6, "{}", turning);
+        // Without look-ahead the heading flips in one step at the corner.
+        let snapped = path_headings(&path, 0f32);
+        assert!(snapped.iter().all(|&h| h.abs() < 1E-03f32 || (h - 90f32).abs() < 1E-03f32));
+    }
+}
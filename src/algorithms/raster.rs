@@ -0,0 +1,145 @@
+use super::rect::{grid_size, Rect};
+use super::vector2::Vector2;
+
+/// Cells of `bounds`, indexed `[y][x]`, whose centres are inside `polygon` under the even-odd
+/// rule, filled one scanline at a time. A partial cell at the far edges is still included.
+/// A non-positive `cell_size` gives an empty grid.
+pub fn rasterize_polygon(polygon: &[Vector2], bounds: Rect, cell_size: f32) -> Vec<Vec<bool>> {
+    let (width, height) = grid_size(bounds, cell_size);
+    (0..height)
+        .map(|y| {
+            let crossings = scanline_crossings(polygon, bounds.y + (y as f32 + 0.5f32) * cell_size);
+            (0..width)
+                .map(|x| {
+                    let center = bounds.x + (x as f32 + 0.5f32) * cell_size;
+                    crossings.iter().filter(|&&c| c < center).count() % 2 == 1
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Sub-samples per cell along each axis for [`rasterize_polygon_coverage`].
+const COVERAGE_SAMPLES: usize = 4;
+
+/// Fraction of each cell of `bounds` covered by `polygon` (even-odd rule), estimated from a
+/// regular grid of sub-samples per cell; indexed `[y][x]`. A non-positive `cell_size` gives an
+/// empty grid.
+pub fn rasterize_polygon_coverage(polygon: &[Vector2], bounds: Rect, cell_size: f32) -> Vec<Vec<f32>> {
+    let (width, height) = grid_size(bounds, cell_size);
+    let step = cell_size / COVERAGE_SAMPLES as f32;
+    let weight = 1f32 / (COVERAGE_SAMPLES * COVERAGE_SAMPLES) as f32;
+    let mut coverage = vec![vec![0f32; width]; height];
+    for (y, row) in coverage.iter_mut().enumerate() {
+        for sub_y in 0..COVERAGE_SAMPLES {
+            let crossings = scanline_crossings(polygon, bounds.y + y as f32 * cell_size + (sub_y as f32 + 0.5f32) * step);
+            for (x, cell) in row.iter_mut().enumerate() {
+                for sub_x in 0..COVERAGE_SAMPLES {
+                    let sample = bounds.x + x as f32 * cell_size + (sub_x as f32 + 0.5f32) * step;
+                    if crossings.iter().filter(|&&c| c < sample).count() % 2 == 1 {
+                        *cell += weight;
+                    }
+                }
+            }
+        }
+    }
+    coverage
+}
+
+/// Sorted x positions where the horizontal line at `y` crosses the polygon's edges; each edge
+/// counts its lower endpoint but not its upper one, so shared vertices are not doubled.
+fn scanline_crossings(polygon: &[Vector2], y: f32) -> Vec<f32> {
+    let n = polygon.len();
+    let mut crossings: Vec<f32> = (0..n)
+        .map(|i| (polygon[i], polygon[(i + 1) % n]))
+        .filter(|(a, b)| (a.y > y) != (b.y > y))
+        .map(|(a, b)| a.x + (y - a.y) * (b.x - a.x) / (b.y - a.y))
+        .collect();
+    crossings.sort_by(f32::total_cmp);
+    crossings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square(min: f32, max: f32) -> [Vector2; 4] {
+        [Vector2::new(min, min), Vector2::new(max, min), Vector2::new(max, max), Vector2::new(min, max)]
+    }
+
+    #[test]
+    fn test_rasterize_square() {
+        let grid = rasterize_polygon(&square(1f32, 3f32), Rect::new(0f32, 0f32, 4f32, 4f32), 1f32);
+        assert_eq!(grid.len(), 4);
+        for (y, row) in grid.iter().enumerate() {
+            assert_eq!(row.len(), 4);
+            for (x, &filled) in row.iter().enumerate() {
+                assert_eq!(filled, (1..3).contains(&x) && (1..3).contains(&y), "cell ({}, {})", x, y);
+            }
+        }
+    }
+
+    #[test]
+    fn test_rasterize_even_odd_hole() {
+        // Outer square, then a bridge into the inner square; the bridge is walked both ways.
+        let mut path = square(0f32, 6f32).to_vec();
+        path.push(Vector2::new(0f32, 0f32));
+        path.extend(square(2f32, 4f32));
+        path.push(Vector2::new(2f32, 2f32));
+        let grid = rasterize_polygon(&path, Rect::new(0f32, 0f32, 6f32, 6f32), 1f32);
+        let filled: usize = grid.iter().map(|row| row.iter().filter(|&&f| f).count()).sum();
+        assert_eq!(filled, 32);
+        assert!(!grid[2][2] && !grid[3][3]);
+        assert!(grid[0][0] && grid[5][5] && grid[1][3]);
+    }
+
+    #[test]
+    fn test_rasterize_offset_bounds() {
+        let grid = rasterize_polygon(&square(10f32, 11f32), Rect::new(9.5f32, 9.5f32, 2f32, 2.2f32), 0.5f32);
+        assert_eq!((grid[0].len(), grid.len()), (4, 5));
+        let filled: Vec<(usize, usize)> = (0..5).flat_map(|y| (0..4).map(move |x| (x, y))).filter(|&(x, y)| grid[y][x]).collect();
+        assert_eq!(filled, vec![(1, 1), (2, 1), (1, 2), (2, 2)]);
+    }
+
+    #[test]
+    fn test_rasterize_non_positive_cell_size() {
+        let bounds = Rect::new(0f32, 0f32, 4f32, 4f32);
+        assert!(rasterize_polygon(&square(1f32, 3f32), bounds, 0f32).is_empty());
+        assert!(rasterize_polygon(&square(1f32, 3f32), bounds, -1f32).is_empty());
+    }
+
+    #[test]
+    fn test_coverage_inside_outside_edge() {
+        let bounds = Rect::new(0f32, 0f32, 4f32, 4f32);
+        let coverage = rasterize_polygon_coverage(&square(0.5f32, 3f32), bounds, 1f32);
+        assert_eq!(coverage[1][1], 1f32);
+        assert_eq!(coverage[2][2], 1f32);
+        assert_eq!(coverage[3][3], 0f32);
+        assert_eq!(coverage[0][1], 0.5f32);
+        assert_eq!(coverage[0][0], 0.25f32);
+    }
+
+    #[test]
+    fn test_coverage_non_positive_cell_size() {
+        let bounds = Rect::new(0f32, 0f32, 4f32, 4f32);
+        assert!(rasterize_polygon_coverage(&square(1f32, 3f32), bounds, 0f32).is_empty());
+        assert!(rasterize_polygon_coverage(&square(1f32, 3f32), bounds, -0.5f32).is_empty());
+    }
+
+    #[test]
+    fn test_coverage_diagonal_edge() {
+        let triangle = [Vector2::zero(), Vector2::new(4f32, 0f32), Vector2::new(0f32, 4f32)];
+        let coverage = rasterize_polygon_coverage(&triangle, Rect::new(0f32, 0f32, 4f32, 4f32), 1f32);
+        for (y, row) in coverage.iter().enumerate() {
+            for (x, &value) in row.iter().enumerate() {
+                match (x + y).cmp(&3) {
+                    std::cmp::Ordering::Less => assert_eq!(value, 1f32),
+                    std::cmp::Ordering::Equal => assert!(value > 0.25f32 && value < 0.75f32),
+                    std::cmp::Ordering::Greater => assert_eq!(value, 0f32),
+                }
+            }
+        }
+        let total: f32 = coverage.iter().flatten().sum();
+        assert!((total - 8f32).abs() <= 0.5f32);
+    }
+}
@@ -0,0 +1,125 @@
+use super::rect::Rect;
+use super::vector2::Vector2;
+
+/// Half-line from `origin` along `direction`; distances along it are in multiples of
+/// `direction`, which need not be normalized.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ray2D {
+    pub origin: Vector2,
+    pub direction: Vector2,
+}
+
+impl Ray2D {
+    pub fn new(origin: Vector2, direction: Vector2) -> Self { Self { origin, direction } }
+    pub fn get_point(&self, t: f32) -> Vector2 { self.origin + self.direction * t }
+
+    /// Smallest `t >= 0` at which the ray is inside `rect`, or `None` if it misses.
+    pub fn intersect_rect(&self, rect: &Rect) -> Option<f32> {
+        let mut t_enter = 0f32;
+        let mut t_exit = f32::INFINITY;
+        for axis in 0..2 {
+            let (origin, direction) = (self.origin[axis], self.direction[axis]);
+            let (min, max) = (rect.min()[axis], rect.max()[axis]);
+            if direction == 0f32 {
+                if origin < min || origin > max {
+                    return None;
+                }
+                continue;
+            }
+            let t1 = (min - origin) / direction;
+            let t2 = (max - origin) / direction;
+            t_enter = t_enter.max(t1.min(t2));
+            t_exit = t_exit.min(t1.max(t2));
+        }
+        if t_enter <= t_exit { Some(t_enter) } else { None }
+    }
+
+    /// `t >= 0` at which the ray crosses the segment `a`-`b`, or `None` if it misses or runs
+    /// parallel to it.
+    pub fn intersect_segment(&self, a: Vector2, b: Vector2) -> Option<f32> {
+        let edge = b - a;
+        let denominator = Vector2::cross(self.direction, edge);
+        if denominator.abs() < 1E-12f32 {
+            return None;
+        }
+        let offset = a - self.origin;
+        let t = Vector2::cross(offset, edge) / denominator;
+        let u = Vector2::cross(offset, self.direction) / denominator;
+        if t >= 0f32 && (0f32..=1f32).contains(&u) { Some(t) } else { None }
+    }
+}
+
+/// Distance from `origin` to the nearest hit of each ray against the edges of the closed
+/// `polygon`, or `None` for rays that miss. Distances are true lengths, whatever the
+/// magnitude of each direction.
+pub fn cast_rays_polygon(origin: Vector2, directions: &[Vector2], polygon: &[Vector2]) -> Vec<Option<f32>> {
+    let n = polygon.len();
+    let edges: Vec<(Vector2, Vector2)> = (0..n).map(|i| (polygon[i], polygon[(i + 1) % n])).collect();
+    directions
+        .iter()
+        .map(|&direction| {
+            let ray = Ray2D::new(origin, direction);
+            let nearest = edges.iter().filter_map(|&(a, b)| ray.intersect_segment(a, b)).reduce(f32::min);
+            nearest.map(|t| t * direction.magnitude())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_point() {
+        let ray = Ray2D::new(Vector2::new(1f32, 1f32), Vector2::new(2f32, 0f32));
+        assert_eq!(ray.get_point(1.5f32), Vector2::new(4f32, 1f32));
+    }
+
+    #[test]
+    fn test_intersect_rect() {
+        let rect = Rect::new(2f32, -1f32, 2f32, 2f32);
+        let ray = Ray2D::new(Vector2::zero(), Vector2::right());
+        assert_eq!(ray.intersect_rect(&rect), Some(2f32));
+        let inside = Ray2D::new(Vector2::new(3f32, 0f32), Vector2::left());
+        assert_eq!(inside.intersect_rect(&rect), Some(0f32));
+        let behind = Ray2D::new(Vector2::new(5f32, 0f32), Vector2::right());
+        assert_eq!(behind.intersect_rect(&rect), None);
+        let above = Ray2D::new(Vector2::new(0f32, 2f32), Vector2::right());
+        assert_eq!(above.intersect_rect(&rect), None);
+        let diagonal = Ray2D::new(Vector2::new(0f32, -3f32), Vector2::new(1f32, 1f32));
+        assert_eq!(diagonal.intersect_rect(&rect), Some(2f32));
+    }
+
+    #[test]
+    fn test_intersect_segment() {
+        let ray = Ray2D::new(Vector2::zero(), Vector2::new(2f32, 0f32));
+        assert_eq!(ray.intersect_segment(Vector2::new(4f32, -1f32), Vector2::new(4f32, 1f32)), Some(2f32));
+        assert_eq!(ray.intersect_segment(Vector2::new(4f32, 1f32), Vector2::new(4f32, 3f32)), None);
+        assert_eq!(ray.intersect_segment(Vector2::new(-4f32, -1f32), Vector2::new(-4f32, 1f32)), None);
+        assert_eq!(ray.intersect_segment(Vector2::new(1f32, 0f32), Vector2::new(3f32, 0f32)), None);
+    }
+
+    #[test]
+    fn test_cast_rays_polygon() {
+        let square = [Vector2::new(2f32, -1f32), Vector2::new(4f32, -1f32), Vector2::new(4f32, 1f32), Vector2::new(2f32, 1f32)];
+        let hits = cast_rays_polygon(Vector2::zero(), &[Vector2::right(), Vector2::left(), Vector2::up(), Vector2::new(2f32, 1f32)], &square);
+        assert_eq!(hits[..3], [Some(2f32), None, None]);
+        assert!((hits[3].unwrap() - 5f32.sqrt()).abs() < 1E-06f32);
+        let inside = cast_rays_polygon(Vector2::new(3f32, 0f32), &[Vector2::down()], &square);
+        assert_eq!(inside, vec![Some(1f32)]);
+    }
+
+    #[test]
+    fn test_cast_rays_polygon_matches_single_rays() {
+        let polygon: Vec<Vector2> = (0..7).map(|i| Vector2::from_angle(i as f32 * 360f32 / 7f32) * (2f32 + (i % 3) as f32)).collect();
+        let origin = Vector2::new(0.5f32, -0.25f32);
+        let directions: Vec<Vector2> = (0..90).map(|i| Vector2::from_angle(i as f32 * 4f32) * 1.5f32).collect();
+        let batch = cast_rays_polygon(origin, &directions, &polygon);
+        for (&direction, &hit) in directions.iter().zip(&batch) {
+            let ray = Ray2D::new(origin, direction);
+            let single = (0..polygon.len()).filter_map(|i| ray.intersect_segment(polygon[i], polygon[(i + 1) % polygon.len()])).reduce(f32::min);
+            assert_eq!(hit, single.map(|t| t * direction.magnitude()));
+            assert!(hit.is_some());
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use super::vector2::Vector2;
+
+/// Axis-aligned rectangle given by its minimum corner and size.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rect {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl Rect {
+    pub fn new(x: f32, y: f32, width: f32, height: f32) -> Self { Self { x, y, width, height } }
+    pub fn min_max(min: Vector2, max: Vector2) -> Self { Self::new(min.x, min.y, max.x - min.x, max.y - min.y) }
+    pub fn x_min(&self) -> f32 { self.x }
+    pub fn y_min(&self) -> f32 { self.y }
+    pub fn x_max(&self) -> f32 { self.x + self.width }
+    pub fn y_max(&self) -> f32 { self.y + self.height }
+    pub fn min(&self) -> Vector2 { Vector2::new(self.x_min(), self.y_min()) }
+    pub fn max(&self) -> Vector2 { Vector2::new(self.x_max(), self.y_max()) }
+    pub fn center(&self) -> Vector2 { Vector2::new(self.x + self.width * 0.5f32, self.y + self.height * 0.5f32) }
+    pub fn size(&self) -> Vector2 { Vector2::new(self.width, self.height) }
+    pub fn area(&self) -> f32 { self.width * self.height }
+
+    /// Smallest rect containing all `points`, or `None` for an empty slice.
+    pub fn from_points(points: &[Vector2]) -> Option<Self> {
+        let first = *points.first()?;
+        let (min, max) = points.iter().fold((first, first), |(min, max), &p| {
+            (Vector2::combine(min, p, f32::min), Vector2::combine(max, p, f32::max))
+        });
+        Some(Self::min_max(min, max))
+    }
+
+    pub fn contains(&self, point: Vector2) -> bool {
+        point.x >= self.x_min() && point.x <= self.x_max() && point.y >= self.y_min() && point.y <= self.y_max()
+    }
+
+    /// True when the rects share any point, including touching edges.
+    pub fn overlaps(&self, other: &Rect) -> bool {
+        self.x_min() <= other.x_max() && other.x_min() <= self.x_max()
+            && self.y_min() <= other.y_max() && other.y_min() <= self.y_max()
+    }
+
+    pub fn closest_point(&self, point: Vector2) -> Vector2 {
+        Vector2::new(point.x.clamp(self.x_min(), self.x_max()), point.y.clamp(self.y_min(), self.y_max()))
+    }
+
+    pub fn union(&self, other: &Rect) -> Rect {
+        Rect::min_max(Vector2::combine(self.min(), other.min(), f32::min),
+                      Vector2::combine(self.max(), other.max(), f32::max))
+    }
+}
+
+/// Circle through the corners of `rect`: its centre and half-diagonal radius.
+pub fn bounding_circle(rect: Rect) -> (Vector2, f32) { (rect.center(), rect.size().magnitude() * 0.5f32) }
+
+/// Tightest rect around the circle at `center` with `radius`.
+pub fn bounding_rect(center: Vector2, radius: f32) -> Rect {
+    Rect::min_max(center - Vector2::one() * radius, center + Vector2::one() * radius)
+}
+
+/// Bounds covering `rect` at its start and after moving by `velocity`; it does not account
+/// for rotation or a curved path.
+pub fn swept_bounds(rect: Rect, velocity: Vector2) -> Rect {
+    let moved = Rect::new(rect.x + velocity.x, rect.y + velocity.y, rect.width, rect.height);
+    rect.union(&moved)
+}
+
+/// Columns and rows of `cell_size` cells covering `bounds`; none when `cell_size` is not positive.
+pub(crate) fn grid_size(bounds: Rect, cell_size: f32) -> (usize, usize) {
+    if cell_size <= 0f32 {
+        return (0, 0);
+    }
+    ((bounds.width / cell_size).ceil().max(0f32) as usize, (bounds.height / cell_size).ceil().max(0f32) as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rect_accessors() {
+        let r = Rect::new(1f32, 2f32, 3f32, 4f32);
+        assert_eq!(r.min(), Vector2::new(1f32, 2f32));
+        assert_eq!(r.max(), Vector2::new(4f32, 6f32));
+        assert_eq!(r.center(), Vector2::new(2.5f32, 4f32));
+        assert_eq!(r.size(), Vector2::new(3f32, 4f32));
+        assert_eq!(r.area(), 12f32);
+        assert_eq!(Rect::min_max(r.min(), r.max()), r);
+    }
+
+    #[test]
+    fn test_from_points() {
+        let points = [Vector2::new(1f32, 5f32), Vector2::new(-2f32, 3f32), Vector2::new(0f32, 7f32)];
+        assert_eq!(Rect::from_points(&points), Some(Rect::new(-2f32, 3f32, 3f32, 4f32)));
+        assert_eq!(Rect::from_points(&[]), None);
+    }
+
+    #[test]
+    fn test_contains_and_overlaps() {
+        let r = Rect::new(0f32, 0f32, 2f32, 2f32);
+        assert!(r.contains(Vector2::new(1f32, 1f32)));
+        assert!(r.contains(Vector2::new(2f32, 0f32)));
+        assert!(!r.contains(Vector2::new(2.1f32, 1f32)));
+        assert!(r.overlaps(&Rect::new(1f32, 1f32, 5f32, 5f32)));
+        assert!(r.overlaps(&Rect::new(2f32, 0f32, 1f32, 1f32)));
+        assert!(!r.overlaps(&Rect::new(2.5f32, 0f32, 1f32, 1f32)));
+    }
+
+    #[test]
+    fn test_closest_point() {
+        let r = Rect::new(0f32, 0f32, 2f32, 2f32);
+        assert_eq!(r.closest_point(Vector2::new(1f32, 1f32)), Vector2::new(1f32, 1f32));
+        assert_eq!(r.closest_point(Vector2::new(5f32, -1f32)), Vector2::new(2f32, 0f32));
+    }
+
+    #[test]
+    fn test_union() {
+        let a = Rect::new(0f32, 0f32, 1f32, 1f32);
+        let b = Rect::new(2f32, -1f32, 1f32, 1f32);
+        assert_eq!(a.union(&b), Rect::new(0f32, -1f32, 3f32, 2f32));
+    }
+
+    #[test]
+    fn test_bounding_circle() {
+        let r = Rect::new(-1f32, 2f32, 6f32, 8f32);
+        let (center, radius) = bounding_circle(r);
+        assert_eq!((center, radius), (Vector2::new(2f32, 6f32), 5f32));
+        for corner in [r.min(), r.max(), Vector2::new(r.x_min(), r.y_max()), Vector2::new(r.x_max(), r.y_min())] {
+            assert!((corner - center).magnitude() <= radius + 1E-05f32);
+        }
+    }
+
+    #[test]
+    fn test_bounding_rect() {
+        let r = bounding_rect(Vector2::new(1f32, -2f32), 1.5f32);
+        assert_eq!(r, Rect::new(-0.5f32, -3.5f32, 3f32, 3f32));
+        for angle in [0f32, 90f32, 180f32, 270f32] {
+            let extreme = Vector2::new(1f32, -2f32) + Vector2::from_angle(angle) * 1.5f32;
+            assert!(r.contains(extreme));
+            assert!(r.x_min() == extreme.x || r.x_max() == extreme.x || r.y_min() == extreme.y || r.y_max() == extreme.y);
+        }
+    }
+
+    #[test]
+    fn test_swept_bounds() {
+        let r = Rect::new(1f32, 1f32, 2f32, 1f32);
+        let swept = swept_bounds(r, Vector2::new(-4f32, 3f32));
+        assert_eq!(swept, Rect::new(-3f32, 1f32, 6f32, 4f32));
+        let moved = Rect::new(-3f32, 4f32, 2f32, 1f32);
+        for corner in [r.min(), r.max(), moved.min(), moved.max()] {
+            assert!(swept.contains(corner));
+        }
+        assert_eq!(swept_bounds(r, Vector2::zero()), r);
+    }
+
+    #[test]
+    fn test_grid_size() {
+        let bounds = Rect::new(1f32, 1f32, 4f32, 2.5f32);
+        assert_eq!(grid_size(bounds, 1f32), (4, 3));
+        assert_eq!(grid_size(bounds, 0f32), (0, 0));
+        assert_eq!(grid_size(bounds, -1f32), (0, 0));
+    }
+}
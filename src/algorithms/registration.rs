@@ -0,0 +1,139 @@
+use super::points::nearest;
+use super::transform2d::Transform2D;
+use super::vector2::Vector2;
+
+/// Similarity transform (rotation, uniform scale, translation) minimising the squared distances
+/// from each transformed `source[i]` to `target[i]`. `None` if the slices differ in length, are
+/// empty, or the source points all coincide.
+pub fn procrustes(source: &[Vector2], target: &[Vector2]) -> Option<Transform2D> { align(source, target, true) }
+
+/// [`procrustes`] with the scale held at 1.
+pub fn procrustes_rigid(source: &[Vector2], target: &[Vector2]) -> Option<Transform2D> { align(source, target, false) }
+
+/// Iterative closest point: pairs every transformed source point with its nearest target point,
+/// re-solves the rigid [`procrustes_rigid`] fit from the original source to those matches, and
+/// repeats until the mean match distance improves by less than `tolerance` or
+/// `max_iterations` is reached. Converges to the nearest local fit, so the sets should start
+/// roughly aligned. Empty inputs give the identity.
+pub fn icp(source: &[Vector2], target: &[Vector2], max_iterations: u32, tolerance: f32) -> Transform2D {
+    let mut transform = Transform2D::identity();
+    if source.is_empty() || target.is_empty() {
+        return transform;
+    }
+    let mut previous_error = f32::INFINITY;
+    for _ in 0..max_iterations {
+        let mut error = 0f32;
+        let matches: Vec<Vector2> = source
+            .iter()
+            .map(|&p| {
+                let (index, distance) = nearest(target, transform.transform_point(p)).unwrap();
+                error += distance;
+                target[index]
+            })
+            .collect();
+        error /= source.len() as f32;
+        if previous_error - error < tolerance {
+            break;
+        }
+        previous_error = error;
+        match procrustes_rigid(source, &matches) {
+            Some(next) => transform = next,
+            None => break,
+        }
+    }
+    transform
+}
+
+fn align(source: &[Vector2], target: &[Vector2], with_scale: bool) -> Option<Transform2D> {
+    if source.len() != target.len() {
+        return None;
+    }
+    let (source_center, target_center) = (Vector2::centroid(source)?, Vector2::centroid(target)?);
+    let (mut dot, mut cross, mut spread) = (0f32, 0f32, 0f32);
+    for (&s, &t) in source.iter().zip(target) {
+        let (s, t) = (s - source_center, t - target_center);
+        dot += Vector2::dot(s, t);
+        cross += Vector2::cross(s, t);
+        spread += s.sqr_magnitude();
+    }
+    if spread == 0f32 {
+        return None;
+    }
+    let rotation = cross.atan2(dot).to_degrees();
+    let scale = if with_scale { (dot * dot + cross * cross).sqrt() / spread } else { 1f32 };
+    let mut transform = Transform2D::new(Vector2::zero(), rotation, Vector2::one() * scale);
+    transform.position = target_center - transform.transform_point(source_center);
+    Some(transform)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::test_support::Lcg;
+
+    fn cloud() -> Vec<Vector2> {
+        (0..30).map(|i| Vector2::new((i as f32 * 0.7f32).sin() * 5f32 + i as f32 * 0.2f32, (i as f32 * 1.3f32).cos() * 3f32)).collect()
+    }
+
+    #[test]
+    fn test_procrustes_recovers_similarity() {
+        let expected = Transform2D::new(Vector2::new(4f32, -2f32), 37f32, Vector2::one() * 1.8f32);
+        let source = cloud();
+        let target: Vec<Vector2> = source.iter().map(|&p| expected.transform_point(p)).collect();
+        let transform = procrustes(&source, &target).unwrap();
+        assert!((transform.rotation - 37f32).abs() < 1E-03f32);
+        assert!((transform.scale - expected.scale).magnitude() < 1E-04f32);
+        assert!((transform.position - expected.position).magnitude() < 1E-03f32);
+        for (&s, &t) in source.iter().zip(&target) {
+            assert!((transform.transform_point(s) - t).magnitude() < 1E-03f32);
+        }
+    }
+
+    #[test]
+    fn test_procrustes_rigid_ignores_scale() {
+        let expected = Transform2D::new(Vector2::new(-1f32, 3f32), -120f32, Vector2::one());
+        let source = cloud();
+        let target: Vec<Vector2> = source.iter().map(|&p| expected.transform_point(p)).collect();
+        let transform = procrustes_rigid(&source, &target).unwrap();
+        assert!((transform.rotation + 120f32).abs() < 1E-03f32);
+        assert_eq!(transform.scale, Vector2::one());
+        assert!((transform.position - expected.position).magnitude() < 1E-03f32);
+    }
+
+    #[test]
+    fn test_procrustes_degenerate() {
+        let source = cloud();
+        assert_eq!(procrustes(&source, &source[1..]), None);
+        assert_eq!(procrustes(&[], &[]), None);
+        assert_eq!(procrustes(&[Vector2::one(); 3], &source[..3]), None);
+    }
+
+    #[test]
+    fn test_icp_without_correspondences() {
+        let mut rng = Lcg(21);
+        let mut next = || rng.next() * 10f32;
+        let target: Vec<Vector2> = (0..60).map(|_| Vector2::new(next(), next())).collect();
+        let offset = Transform2D::new(Vector2::new(0.3f32, -0.2f32), 12f32, Vector2::one());
+        // The source is the target moved away and shuffled, so correspondences are unknown.
+        let mut source: Vec<Vector2> = target.iter().map(|&p| p - offset.position).collect();
+        let inverse_rotation = crate::algorithms::matrix2x2::Matrix2x2::rotation(-12f32);
+        let center = Vector2::centroid(&target).unwrap();
+        for p in source.iter_mut() {
+            *p = center + inverse_rotation * (*p - center);
+        }
+        source.reverse();
+        source.rotate_left(7);
+
+        let transform = icp(&source, &target, 100, 1E-06f32);
+        let error: f32 = source.iter().map(|&p| nearest(&target, transform.transform_point(p)).unwrap().1).sum::<f32>() / source.len() as f32;
+        assert!(error < 1E-03f32, "mean error {}", error);
+        assert!((transform.rotation - 12f32).abs() < 0.05f32);
+    }
+
+    #[test]
+    fn test_icp_degenerate() {
+        assert_eq!(icp(&[], &cloud(), 10, 1E-03f32), Transform2D::identity());
+        let identical = icp(&cloud(), &cloud(), 10, 1E-03f32);
+        assert!(identical.rotation.abs() < 1E-04f32 && identical.position.magnitude() < 1E-04f32);
+    }
+}
@@ -0,0 +1,261 @@
+use std::error::Error;
+use std::fmt;
+
+use super::polygon::signed_area;
+use super::polyline::polyline_normals;
+use super::vector2::Vector2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RibbonError {
+    WidthCountMismatch { points: usize, widths: usize },
+}
+
+impl fmt::Display for RibbonError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RibbonError::WidthCountMismatch { points, widths } => {
+                write!(f, "expected one width per point ({}), got {}", points, widths)
+            }
+        }
+    }
+}
+
+impl Error for RibbonError {}
+
+/// Triangle strip of constant `width` centred on an open polyline. Vertex `2 * i` lies on the
+/// left of point `i` and `2 * i + 1` on its right; triangles wind counter-clockwise.
+pub fn build_ribbon(center_line: &[Vector2], width: f32) -> (Vec<Vector2>, Vec<[usize; 3]>) {
+    (ribbon_vertices(center_line, |_| width), strip_triangles(center_line.len()))
+}
+
+/// Like [`build_ribbon`] with a separate width at every point of the centre line.
+pub fn build_ribbon_variable(center_line: &[Vector2], widths: &[f32]) -> Result<(Vec<Vector2>, Vec<[usize; 3]>), RibbonError> {
+    if widths.len() != center_line.len() {
+        return Err(RibbonError::WidthCountMismatch { points: center_line.len(), widths: widths.len() });
+    }
+    Ok((ribbon_vertices(center_line, |i| widths[i]), strip_triangles(center_line.len())))
+}
+
+/// [`build_ribbon`] plus a UV per vertex. U is the arc length along the centre line divided
+/// by its total length, times `tiling`; V is 0 on the left edge and 1 on the right.
+pub fn build_ribbon_uv(center_line: &[Vector2], width: f32, tiling: f32) -> (Vec<Vector2>, Vec<[usize; 3]>, Vec<Vector2>) {
+    let (vertices, triangles) = build_ribbon(center_line, width);
+    let mut distances = Vec::with_capacity(center_line.len());
+    let mut travelled = 0f32;
+    for (i, &point) in center_line.iter().enumerate() {
+        if i > 0 {
+            travelled += (point - center_line[i - 1]).magnitude();
+        }
+        distances.push(travelled);
+    }
+    let scale = if travelled > 0f32 { tiling / travelled } else { 0f32 };
+    let uvs = distances
+        .iter()
+        .flat_map(|&d| [Vector2::new(d * scale, 0f32), Vector2::new(d * scale, 1f32)])
+        .collect();
+    (vertices, triangles, uvs)
+}
+
+/// Longest mitre [`outline_polygon`] allows, in multiples of the thickness.
+const MITER_LIMIT: f32 = 4f32;
+
+/// Border ring of `thickness` around a closed polygon, mitred at the corners. Vertex `2 * i` is
+/// polygon point `i` and `2 * i + 1` its outward offset; triangles wind counter-clockwise.
+/// Mitres at corners sharper than about 29 degrees are cut back to `MITER_LIMIT` (four) times
+/// the thickness, so the ring narrows there instead of spiking out.
+pub fn outline_polygon(polygon: &[Vector2], thickness: f32) -> (Vec<Vector2>, Vec<[usize; 3]>) {
+    let n = polygon.len();
+    if n < 3 {
+        return (Vec::new(), Vec::new());
+    }
+    // Normals point left of travel, which is inward for a counter-clockwise polygon.
+    let clockwise = signed_area(polygon) < 0f32;
+    let outward = if clockwise { 1f32 } else { -1f32 };
+    let normals = polyline_normals(polygon, true);
+    let mut vertices = Vec::with_capacity(n * 2);
+    for (i, (&point, &normal)) in polygon.iter().zip(&normals).enumerate() {
+        let edge_normal = (polygon[(i + 1) % n] - point).normalized().perpendicular();
+        let cos = Vector2::dot(normal, edge_normal);
+        let miter = if cos > 1E-06f32 { (thickness / cos).min(thickness * MITER_LIMIT) } else { thickness };
+        vertices.push(point);
+        vertices.push(point + normal * (miter * outward));
+    }
+    let triangles = (0..n)
+        .flat_map(|i| {
+            let (inner, outer) = (2 * i, 2 * i + 1);
+            let (next_inner, next_outer) = (2 * ((i + 1) % n), 2 * ((i + 1) % n) + 1);
+            if clockwise {
+                [[inner, next_inner, outer], [outer, next_inner, next_outer]]
+            } else {
+                [[inner, outer, next_inner], [outer, next_outer, next_inner]]
+            }
+        })
+        .collect();
+    (vertices, triangles)
+}
+
+fn ribbon_vertices(center_line: &[Vector2], width: impl Fn(usize) -> f32) -> Vec<Vector2> {
+    let normals = polyline_normals(center_line, false);
+    let mut vertices = Vec::with_capacity(center_line.len() * 2);
+    for (i, (&point, &normal)) in center_line.iter().zip(&normals).enumerate() {
+        let offset = normal * (width(i) * 0.5f32);
+        vertices.push(point + offset);
+        vertices.push(point - offset);
+    }
+    vertices
+}
+
+fn strip_triangles(count: usize) -> Vec<[usize; 3]> {
+    (0..count.saturating_sub(1))
+        .flat_map(|i| {
+            let (left, right) = (2 * i, 2 * i + 1);
+            [[left, right, left + 2], [right, right + 2, left + 2]]
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn triangle_area(vertices: &[Vector2], triangle: [usize; 3]) -> f32 {
+        let [a, b, c] = triangle.map(|i| vertices[i]);
+        Vector2::cross(b - a, c - a) * 0.5f32
+    }
+
+    #[test]
+    fn test_build_ribbon_counts() {
+        let line = [Vector2::new(0f32, 0f32), Vector2::new(2f32, 0f32), Vector2::new(2f32, 3f32), Vector2::new(5f32, 4f32)];
+        let (vertices, triangles) = build_ribbon(&line, 1f32);
+        assert_eq!(vertices.len(), 8);
+        assert_eq!(triangles.len(), 6);
+        for triangle in triangles {
+            assert!(triangle.iter().all(|&i| i < vertices.len()));
+            assert!(triangle_area(&vertices, triangle) > 0f32);
+        }
+    }
+
+    #[test]
+    fn test_build_ribbon_offsets() {
+        let line = [Vector2::new(0f32, 0f32), Vector2::new(2f32, 0f32), Vector2::new(2f32, 2f32)];
+        let (vertices, _) = build_ribbon(&line, 0.5f32);
+        assert_eq!(vertices[0], Vector2::new(0f32, 0.25f32));
+        assert_eq!(vertices[1], Vector2::new(0f32, -0.25f32));
+        for (i, &point) in line.iter().enumerate() {
+            assert!(((vertices[2 * i] - point).magnitude() - 0.25f32).abs() < 1E-06f32);
+            assert!(((vertices[2 * i + 1] - point).magnitude() - 0.25f32).abs() < 1E-06f32);
+        }
+    }
+
+    #[test]
+    fn test_build_ribbon_degenerate() {
+        let (vertices, triangles) = build_ribbon(&[], 1f32);
+        assert!(vertices.is_empty());
+        assert!(triangles.is_empty());
+        let (vertices, triangles) = build_ribbon(&[Vector2::one()], 1f32);
+        assert_eq!(vertices.len(), 2);
+        assert!(triangles.is_empty());
+    }
+
+    #[test]
+    fn test_build_ribbon_variable() {
+        let line = [Vector2::new(0f32, 0f32), Vector2::new(1f32, 0f32), Vector2::new(2f32, 0f32)];
+        let (vertices, triangles) = build_ribbon_variable(&line, &[2f32, 1f32, 0f32]).unwrap();
+        assert_eq!(vertices, vec![
+            Vector2::new(0f32, 1f32), Vector2::new(0f32, -1f32),
+            Vector2::new(1f32, 0.5f32), Vector2::new(1f32, -0.5f32),
+            Vector2::new(2f32, 0f32), Vector2::new(2f32, 0f32),
+        ]);
+        assert_eq!(triangles, build_ribbon(&line, 1f32).1);
+    }
+
+    #[test]
+    fn test_build_ribbon_variable_matches_constant() {
+        let line = [Vector2::new(0f32, 0f32), Vector2::new(2f32, 1f32), Vector2::new(3f32, 4f32)];
+        assert_eq!(build_ribbon_variable(&line, &[0.7f32; 3]).unwrap(), build_ribbon(&line, 0.7f32));
+    }
+
+    #[test]
+    fn test_build_ribbon_variable_rejects_mismatch() {
+        let line = [Vector2::zero(), Vector2::one()];
+        let error = build_ribbon_variable(&line, &[1f32]).unwrap_err();
+        assert_eq!(error, RibbonError::WidthCountMismatch { points: 2, widths: 1 });
+        assert_eq!(error.to_string(), "expected one width per point (2), got 1");
+    }
+
+    #[test]
+    fn test_build_ribbon_uv() {
+        let line = [Vector2::new(0f32, 0f32), Vector2::new(1f32, 0f32), Vector2::new(1f32, 3f32), Vector2::new(5f32, 3f32)];
+        let (vertices, triangles, uvs) = build_ribbon_uv(&line, 1f32, 2f32);
+        assert_eq!((vertices.clone(), triangles), build_ribbon(&line, 1f32));
+        assert_eq!(uvs.len(), vertices.len());
+        let expected_u = [0f32, 0.25f32, 1f32, 2f32];
+        for (i, &u) in expected_u.iter().enumerate() {
+            assert_eq!(uvs[2 * i], Vector2::new(u, 0f32));
+            assert_eq!(uvs[2 * i + 1], Vector2::new(u, 1f32));
+        }
+        assert!(uvs.windows(2).all(|w| w[1].x >= w[0].x));
+    }
+
+    #[test]
+    fn test_build_ribbon_uv_zero_length() {
+        let (_, _, uvs) = build_ribbon_uv(&[Vector2::one(), Vector2::one()], 1f32, 1f32);
+        assert_eq!(uvs, vec![Vector2::zero(), Vector2::up(), Vector2::zero(), Vector2::up()]);
+    }
+
+    #[test]
+    fn test_outline_polygon_square() {
+        let square = [Vector2::zero(), Vector2::new(2f32, 0f32), Vector2::new(2f32, 2f32), Vector2::new(0f32, 2f32)];
+        let (vertices, triangles) = outline_polygon(&square, 0.5f32);
+        assert_eq!(vertices.len(), 8);
+        assert_eq!(triangles.len(), 8);
+        let outer: Vec<Vector2> = vertices.iter().skip(1).step_by(2).copied().collect();
+        let expected = [Vector2::new(-0.5f32, -0.5f32), Vector2::new(2.5f32, -0.5f32), Vector2::new(2.5f32, 2.5f32), Vector2::new(-0.5f32, 2.5f32)];
+        for (&actual, &expected) in outer.iter().zip(&expected) {
+            assert!((actual - expected).magnitude() < 1E-05f32);
+        }
+        let mut total = 0f32;
+        for &triangle in &triangles {
+            let area = triangle_area(&vertices, triangle);
+            assert!(area > 0f32);
+            total += area;
+        }
+        assert!((total - 5f32).abs() < 1E-04f32);
+    }
+
+    #[test]
+    fn test_outline_polygon_clockwise() {
+        let triangle = [Vector2::zero(), Vector2::new(0f32, 3f32), Vector2::new(4f32, 0f32)];
+        let (vertices, triangles) = outline_polygon(&triangle, 0.25f32);
+        for i in 0..3 {
+            let outer = vertices[2 * i + 1];
+            // Each outer vertex sits `thickness` outside both edges meeting at its corner.
+            for (a, b) in [(triangle[(i + 2) % 3], triangle[i]), (triangle[i], triangle[(i + 1) % 3])] {
+                let distance = Vector2::cross((b - a).normalized(), outer - a);
+                assert!((distance - 0.25f32).abs() < 1E-05f32);
+            }
+        }
+        assert!(triangles.iter().all(|&t| triangle_area(&vertices, t) > 0f32));
+        let ring: f32 = triangles.iter().map(|&t| triangle_area(&vertices, t)).sum();
+        assert!(ring > 0.25f32 * 12f32);
+    }
+
+    #[test]
+    fn test_outline_polygon_sharp_corner_is_limited() {
+        // A sliver whose tip at the origin is under two degrees wide.
+        let sliver = [Vector2::zero(), Vector2::new(10f32, -0.15f32), Vector2::new(10f32, 0.15f32)];
+        let (vertices, _) = outline_polygon(&sliver, 0.5f32);
+        let tip = (vertices[1] - vertices[0]).magnitude();
+        assert!((tip - 0.5f32 * MITER_LIMIT).abs() < 1E-04f32, "{}", tip);
+        assert!(vertices[1].x < 0f32);
+        // Blunt corners keep the full mitre.
+        let square = [Vector2::zero(), Vector2::new(2f32, 0f32), Vector2::new(2f32, 2f32), Vector2::new(0f32, 2f32)];
+        let (vertices, _) = outline_polygon(&square, 0.5f32);
+        assert!(((vertices[1] - vertices[0]).magnitude() - 0.5f32 * 2f32.sqrt()).abs() < 1E-05f32);
+    }
+
+    #[test]
+    fn test_outline_polygon_degenerate() {
+        assert_eq!(outline_polygon(&[Vector2::zero(), Vector2::one()], 1f32), (Vec::new(), Vec::new()));
+    }
+}
@@ -0,0 +1,190 @@
+use std::collections::{BinaryHeap, HashMap};
+
+use super::heap::MinCost;
+use super::rect::Rect;
+use super::segment::closest_points_between_segments;
+use super::vector2::Vector2;
+use super::vector2_int::Vector2Int;
+
+const DIRECTIONS: [Vector2Int; 4] = [
+    Vector2Int { x: 1, y: 0 },
+    Vector2Int { x: 0, y: 1 },
+    Vector2Int { x: -1, y: 0 },
+    Vector2Int { x: 0, y: -1 },
+];
+
+/// A cell together with the direction it was entered from; `4` marks the start.
+type State = (Vector2Int, usize);
+
+/// Four-connected A* from `start` to `goal` where each step costs one and each change of
+/// direction adds `turn_penalty`, so among equally short routes the one with the fewest bends
+/// wins. Returns every cell along the way, both ends included, or `None` when the goal cannot be
+/// reached. `is_walkable` has to bound the grid, or an unreachable goal is searched for forever.
+pub fn orthogonal_route(start: Vector2Int, goal: Vector2Int, is_walkable: impl Fn(Vector2Int) -> bool, turn_penalty: f32) -> Option<Vec<Vector2Int>> {
+    if !is_walkable(start) || !is_walkable(goal) {
+        return None;
+    }
+    let heuristic = |cell: Vector2Int| Vector2Int::manhattan_distance(cell, goal) as f32;
+    let mut best: HashMap<State, f32> = HashMap::from([((start, 4), 0f32)]);
+    let mut came_from: HashMap<State, State> = HashMap::new();
+    let mut queue = BinaryHeap::from([MinCost { cost: heuristic(start), item: (0f32, (start, 4)) }]);
+    // Ordered by the A* estimate; the item carries the cost so far.
+    while let Some(MinCost { item: (cost, state), .. }) = queue.pop() {
+        if cost > best[&state] {
+            continue;
+        }
+        let (cell, entered) = state;
+        if cell == goal {
+            let mut path = vec![cell];
+            let mut current = state;
+            while let Some(&previous) = came_from.get(&current) {
+                path.push(previous.0);
+                current = previous;
+            }
+            path.reverse();
+            return Some(path);
+        }
+        for (direction, &step) in DIRECTIONS.iter().enumerate() {
+            // Stepping straight back is never useful and would only cost a turn.
+            if entered < 4 && direction == (entered + 2) % 4 {
+                continue;
+            }
+            let next = cell + step;
+            if !is_walkable(next) {
+                continue;
+            }
+            let turn = if entered < 4 && direction != entered { turn_penalty } else { 0f32 };
+            let next_state = (next, direction);
+            let next_cost = cost + 1f32 + turn;
+            if best.get(&next_state).is_none_or(|&known| next_cost < known) {
+                best.insert(next_state, next_cost);
+                came_from.insert(next_state, state);
+                queue.push(MinCost { cost: next_cost + heuristic(next), item: (next_cost, next_state) });
+            }
+        }
+    }
+    None
+}
+
+/// World-space waypoints for a path of grid cells `cell_size` across, where cell `(x, y)`
+/// spans `[x, x + 1) * cell_size` on each axis. Each cell centre is first pushed away from
+/// nearby unwalkable cells until it is `clearance` clear of them, as far as the corridor
+/// allows, then the points are string-pulled: each waypoint links straight to the furthest
+/// later point whose connecting segment keeps `clearance` from every unwalkable cell. A
+/// non-positive `cell_size` gives no waypoints.
+pub fn path_to_waypoints(grid_path: &[Vector2Int], cell_size: f32, clearance: f32, is_walkable: impl Fn(Vector2Int) -> bool) -> Vec<Vector2> {
+    if cell_size <= 0f32 {
+        return Vec::new();
+    }
+    let cell_rect = |cell: Vector2Int| Rect::new(cell.x as f32 * cell_size, cell.y as f32 * cell_size, cell_size, cell_size);
+    // Unwalkable cells overlapping the box around `a` and `b` grown by `clearance`.
+    let walls_near = |a: Vector2, b: Vector2| {
+        let reach = clearance.max(0f32);
+        let (low, high) = (Vector2::new(a.x.min(b.x) - reach, a.y.min(b.y) - reach), Vector2::new(a.x.max(b.x) + reach, a.y.max(b.y) + reach));
+        let (x0, y0, x1, y1) = ((low.x / cell_size).floor() as i32, (low.y / cell_size).floor() as i32, (high.x / cell_size).floor() as i32, (high.y / cell_size).floor() as i32);
+        (y0..=y1).flat_map(move |y| (x0..=x1).map(move |x| Vector2Int::new(x, y))).filter(|&cell| !is_walkable(cell)).map(cell_rect).collect::<Vec<Rect>>()
+    };
+
+    let points: Vec<Vector2> = grid_path
+        .iter()
+        .map(|&cell| {
+            let mut p = cell_rect(cell).center();
+            for _ in 0..4 {
+                for wall in walls_near(p, p) {
+                    let away = p - wall.closest_point(p);
+                    let distance = away.magnitude();
+                    if distance > 0f32 && distance < clearance {
+                        p = p + away * ((clearance - distance) / distance);
+                    }
+                }
+            }
+            p
+        })
+        .collect();
+
+    let clear = |a: Vector2, b: Vector2| {
+        walls_near(a, b).iter().all(|wall| {
+            let corners = [wall.min(), Vector2::new(wall.x_max(), wall.y_min()), wall.max(), Vector2::new(wall.x_min(), wall.y_max())];
+            !wall.contains(a)
+                && (0..4).all(|k| {
+                    let (on_segment, on_wall) = closest_points_between_segments(a, b, corners[k], corners[(k + 1) % 4]);
+                    (on_segment - on_wall).magnitude() >= clearance - 1E-05f32
+                })
+        })
+    };
+    let mut waypoints: Vec<Vector2> = points.first().copied().into_iter().collect();
+    let mut anchor = 0;
+    while anchor + 1 < points.len() {
+        let next = (anchor + 2..points.len()).rev().find(|&j| clear(points[anchor], points[j])).unwrap_or(anchor + 1);
+        waypoints.push(points[next]);
+        anchor = next;
+    }
+    waypoints
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn turns(path: &[Vector2Int]) -> usize { path.windows(3).filter(|w| w[1] - w[0] != w[2] - w[1]).count() }
+
+    fn inside(width: i32, height: i32) -> impl Fn(Vector2Int) -> bool {
+        move |c: Vector2Int| c.x >= 0 && c.y >= 0 && c.x < width && c.y < height
+    }
+
+    #[test]
+    fn test_orthogonal_route_prefers_fewer_turns() {
+        let (start, goal) = (Vector2Int::new(0, 0), Vector2Int::new(5, 4));
+        let path = orthogonal_route(start, goal, inside(8, 8), 0.5f32).unwrap();
+        assert_eq!(path.len(), 10);
+        assert_eq!((path[0], path[9]), (start, goal));
+        assert_eq!(turns(&path), 1);
+        assert!(path.windows(2).all(|w| Vector2Int::manhattan_distance(w[0], w[1]) == 1));
+        let straight = orthogonal_route(start, Vector2Int::new(6, 0), inside(8, 8), 0.5f32).unwrap();
+        assert_eq!(turns(&straight), 0);
+        assert_eq!(orthogonal_route(start, start, inside(8, 8), 1f32), Some(vec![start]));
+    }
+
+    #[test]
+    fn test_orthogonal_route_around_wall() {
+        // A wall at x = 3 from y = 0 to 5 forces a detour over its top in three straight runs.
+        let walkable = |c: Vector2Int| inside(8, 8)(c) && !(c.x == 3 && c.y <= 5);
+        let path = orthogonal_route(Vector2Int::new(0, 0), Vector2Int::new(6, 0), walkable, 1f32).unwrap();
+        assert!(path.iter().all(|&c| walkable(c)));
+        assert_eq!(path.len(), 6 + 2 * 6 + 1);
+        assert_eq!(turns(&path), 2);
+        let blocked = |c: Vector2Int| inside(8, 8)(c) && c.x != 3;
+        assert_eq!(orthogonal_route(Vector2Int::new(0, 0), Vector2Int::new(6, 0), blocked, 1f32), None);
+    }
+
+    #[test]
+    fn test_path_to_waypoints_pulls_and_keeps_clearance() {
+        // A 10 x 10 room with a wall at x = 4 for y < 7, walked around its top.
+        let walkable = |c: Vector2Int| inside(10, 10)(c) && !(c.x == 4 && c.y < 7);
+        let path = orthogonal_route(Vector2Int::new(1, 1), Vector2Int::new(8, 1), walkable, 0.5f32).unwrap();
+        let (cell_size, clearance) = (2f32, 1.5f32);
+        let waypoints = path_to_waypoints(&path, cell_size, clearance, walkable);
+        assert!(waypoints.len() < path.len() / 2, "{:?}", waypoints);
+        let walls: Vec<Rect> = (-1..11)
+            .flat_map(|y| (-1..11).map(move |x| Vector2Int::new(x, y)))
+            .filter(|&c| !walkable(c))
+            .map(|c| Rect::new(c.x as f32 * cell_size, c.y as f32 * cell_size, cell_size, cell_size))
+            .collect();
+        for p in &waypoints {
+            assert!(walls.iter().all(|w| (*p - w.closest_point(*p)).magnitude() >= clearance - 1E-04f32), "{:?}", p);
+        }
+        // The route still has to climb over the wall.
+        assert!(waypoints.iter().any(|p| p.y > 7f32 * cell_size));
+    }
+
+    #[test]
+    fn test_path_to_waypoints_open_straight() {
+        let path: Vec<Vector2Int> = (0..6).map(|x| Vector2Int::new(x, 0)).collect();
+        let waypoints = path_to_waypoints(&path, 1f32, 0.25f32, |_| true);
+        assert_eq!(waypoints, vec![Vector2::new(0.5f32, 0.5f32), Vector2::new(5.5f32, 0.5f32)]);
+        assert_eq!(path_to_waypoints(&path[..1], 1f32, 0.25f32, |_| true), vec![Vector2::new(0.5f32, 0.5f32)]);
+        assert!(path_to_waypoints(&[], 1f32, 0.25f32, |_| true).is_empty());
+        assert!(path_to_waypoints(&path, 0f32, 0.25f32, |_| true).is_empty());
+        assert!(path_to_waypoints(&path, -1f32, 0.25f32, |_| true).is_empty());
+    }
+}
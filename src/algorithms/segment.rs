@@ -0,0 +1,74 @@
+use super::vector2::Vector2;
+
+/// Point on segment `ab` nearest to `point`.
+pub fn closest_point_on_segment(point: Vector2, a: Vector2, b: Vector2) -> Vector2 {
+    let edge = b - a;
+    let length2 = edge.sqr_magnitude();
+    if length2 == 0f32 {
+        return a;
+    }
+    let t = (Vector2::dot(point - a, edge) / length2).clamp(0f32, 1f32);
+    a + edge * t
+}
+
+pub fn distance_to_segment(point: Vector2, a: Vector2, b: Vector2) -> f32 {
+    (point - closest_point_on_segment(point, a, b)).magnitude()
+}
+
+/// Closest pair of points between segments `a0a1` and `b0b1`, one on each. Crossing segments
+/// give their intersection twice.
+pub fn closest_points_between_segments(a0: Vector2, a1: Vector2, b0: Vector2, b1: Vector2) -> (Vector2, Vector2) {
+    let (da, db) = (a1 - a0, b1 - b0);
+    let denominator = Vector2::cross(da, db);
+    if denominator != 0f32 {
+        let t = Vector2::cross(b0 - a0, db) / denominator;
+        let u = Vector2::cross(b0 - a0, da) / denominator;
+        if (0f32..=1f32).contains(&t) && (0f32..=1f32).contains(&u) {
+            let crossing = a0 + da * t;
+            return (crossing, crossing);
+        }
+    }
+    // Otherwise one of the four endpoints is part of the closest pair.
+    [
+        (a0, closest_point_on_segment(a0, b0, b1)),
+        (a1, closest_point_on_segment(a1, b0, b1)),
+        (closest_point_on_segment(b0, a0, a1), b0),
+        (closest_point_on_segment(b1, a0, a1), b1),
+    ]
+    .into_iter()
+    .min_by(|x, y| (x.0 - x.1).sqr_magnitude().total_cmp(&(y.0 - y.1).sqr_magnitude()))
+    .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_closest_point_on_segment() {
+        let a = Vector2::new(0f32, 0f32);
+        let b = Vector2::new(4f32, 0f32);
+        assert_eq!(closest_point_on_segment(Vector2::new(1f32, 3f32), a, b), Vector2::new(1f32, 0f32));
+        assert_eq!(closest_point_on_segment(Vector2::new(-2f32, 1f32), a, b), a);
+        assert_eq!(closest_point_on_segment(Vector2::new(7f32, -1f32), a, b), b);
+        assert_eq!(closest_point_on_segment(Vector2::one(), a, a), a);
+    }
+
+    #[test]
+    fn test_distance_to_segment() {
+        let a = Vector2::new(0f32, 0f32);
+        let b = Vector2::new(0f32, 2f32);
+        assert_eq!(distance_to_segment(Vector2::new(3f32, 1f32), a, b), 3f32);
+        assert_eq!(distance_to_segment(Vector2::new(3f32, 6f32), a, b), 5f32);
+    }
+
+    #[test]
+    fn test_closest_points_between_segments() {
+        let (a, b) = closest_points_between_segments(Vector2::zero(), Vector2::new(4f32, 0f32), Vector2::new(1f32, 3f32), Vector2::new(3f32, 1f32));
+        assert_eq!((a, b), (Vector2::new(3f32, 0f32), Vector2::new(3f32, 1f32)));
+        let (a, b) = closest_points_between_segments(Vector2::zero(), Vector2::new(2f32, 2f32), Vector2::new(0f32, 2f32), Vector2::new(2f32, 0f32));
+        assert_eq!((a, b), (Vector2::one(), Vector2::one()));
+        let (a, b) = closest_points_between_segments(Vector2::zero(), Vector2::new(2f32, 0f32), Vector2::new(3f32, 1f32), Vector2::new(5f32, 1f32));
+        assert_eq!((a, b), (Vector2::new(2f32, 0f32), Vector2::new(3f32, 1f32)));
+    }
+}
@@ -0,0 +1,253 @@
+use super::segment::distance_to_segment;
+use super::vector2::Vector2;
+
+/// Symmetric Hausdorff distance between two polylines: the larger of the two directed
+/// distances, each taken from the vertices of one polyline to the segments of the other.
+/// Infinite when exactly one of them is empty.
+pub fn hausdorff_distance(a: &[Vector2], b: &[Vector2]) -> f32 {
+    if a.is_empty() && b.is_empty() {
+        return 0f32;
+    }
+    directed_hausdorff(a, b).max(directed_hausdorff(b, a))
+}
+
+/// Discrete Fréchet distance: the shortest leash that lets two walkers traverse the vertices of
+/// `a` and `b` in order, each only moving forward. Infinite when exactly one is empty.
+pub fn frechet_distance(a: &[Vector2], b: &[Vector2]) -> f32 {
+    if a.is_empty() || b.is_empty() {
+        return if a.len() == b.len() { 0f32 } else { f32::INFINITY };
+    }
+    let mut previous: Vec<f32> = Vec::with_capacity(b.len());
+    for (j, &q) in b.iter().enumerate() {
+        let d = (a[0] - q).magnitude();
+        previous.push(if j == 0 { d } else { d.max(previous[j - 1]) });
+    }
+    for &p in &a[1..] {
+        let mut row: Vec<f32> = Vec::with_capacity(b.len());
+        for (j, &q) in b.iter().enumerate() {
+            let reach = if j == 0 { previous[0] } else { previous[j].min(previous[j - 1]).min(row[j - 1]) };
+            row.push((p - q).magnitude().max(reach));
+        }
+        previous = row;
+    }
+    previous[b.len() - 1]
+}
+
+/// Dynamic time warping: the cheapest monotone alignment of `a` with `b`, costed as the sum of
+/// distances between aligned points. Returns the cost and the aligned index pairs from `(0, 0)`
+/// to the last pair. Infinite with no path when exactly one input is empty.
+pub fn dtw(a: &[Vector2], b: &[Vector2]) -> (f32, Vec<(usize, usize)>) {
+    if a.is_empty() || b.is_empty() {
+        return (if a.len() == b.len() { 0f32 } else { f32::INFINITY }, Vec::new());
+    }
+    let (n, m) = (a.len(), b.len());
+    let mut cost = vec![vec![f32::INFINITY; m]; n];
+    for i in 0..n {
+        for j in 0..m {
+            let previous = match (i, j) {
+                (0, 0) => 0f32,
+                (0, _) => cost[0][j - 1],
+                (_, 0) => cost[i - 1][0],
+                _ => cost[i - 1][j - 1].min(cost[i - 1][j]).min(cost[i][j - 1]),
+            };
+            cost[i][j] = previous + (a[i] - b[j]).magnitude();
+        }
+    }
+
+    let mut path = vec![(n - 1, m - 1)];
+    let (mut i, mut j) = (n - 1, m - 1);
+    while (i, j) != (0, 0) {
+        // Prefer the diagonal on ties so equal-length matches stay on it.
+        (i, j) = match (i, j) {
+            (0, _) => (0, j - 1),
+            (_, 0) => (i - 1, 0),
+            _ => {
+                let diagonal = cost[i - 1][j - 1];
+                if diagonal <= cost[i - 1][j] && diagonal <= cost[i][j - 1] {
+                    (i - 1, j - 1)
+                } else if cost[i - 1][j] <= cost[i][j - 1] {
+                    (i - 1, j)
+                } else {
+                    (i, j - 1)
+                }
+            }
+        };
+        path.push((i, j));
+    }
+    path.reverse();
+    (cost[n - 1][m - 1], path)
+}
+
+/// Turning function of a closed polygon: for each edge, the normalized arc length where it
+/// starts and its direction in degrees relative to the first edge, accumulated through the
+/// signed turns at the vertices. Independent of translation, rotation and scale; changing the
+/// starting vertex shifts it along the arc length. Fewer than two points, or all of them in
+/// one place, give an empty function.
+pub fn turning_function(polygon: &[Vector2]) -> Vec<(f32, f32)> {
+    let n = polygon.len();
+    if n < 2 {
+        return Vec::new();
+    }
+    let edge = |i: usize| polygon[(i + 1) % n] - polygon[i];
+    let perimeter: f32 = (0..n).map(|i| edge(i).magnitude()).sum();
+    if perimeter == 0f32 {
+        return Vec::new();
+    }
+    let (mut arc, mut angle) = (0f32, 0f32);
+    let mut function = Vec::with_capacity(n);
+    for i in 0..n {
+        if i > 0 {
+            angle += Vector2::signed_angle(edge(i - 1), edge(i));
+        }
+        function.push((arc / perimeter, angle));
+        arc += edge(i).magnitude();
+    }
+    function
+}
+
+fn directed_hausdorff(from: &[Vector2], to: &[Vector2]) -> f32 {
+    from.iter().map(|&p| distance_to_polyline(p, to)).fold(0f32, f32::max)
+}
+
+fn distance_to_polyline(point: Vector2, polyline: &[Vector2]) -> f32 {
+    match polyline {
+        [] => f32::INFINITY,
+        [single] => (point - *single).magnitude(),
+        _ => polyline.windows(2).map(|w| distance_to_segment(point, w[0], w[1])).fold(f32::INFINITY, f32::min),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::test_support::Lcg;
+
+    fn zigzag() -> Vec<Vector2> {
+        (0..10).map(|i| Vector2::new(i as f32, if i % 2 == 0 { 0f32 } else { 1f32 })).collect()
+    }
+
+    #[test]
+    fn test_hausdorff_identical() {
+        let path = zigzag();
+        assert_eq!(hausdorff_distance(&path, &path), 0f32);
+        assert_eq!(hausdorff_distance(&[], &[]), 0f32);
+        assert_eq!(hausdorff_distance(&path, &[]), f32::INFINITY);
+    }
+
+    #[test]
+    fn test_hausdorff_offset_copy() {
+        let line: Vec<Vector2> = (0..10).map(|i| Vector2::new(i as f32, 0f32)).collect();
+        let shifted: Vec<Vector2> = line.iter().map(|&p| p + Vector2::new(0f32, 0.5f32)).collect();
+        assert!((hausdorff_distance(&line, &shifted) - 0.5f32).abs() < 1E-06f32);
+        let denser: Vec<Vector2> = (0..=90).map(|i| Vector2::new(i as f32 * 0.1f32, -0.25f32)).collect();
+        assert!((hausdorff_distance(&line, &denser) - 0.25f32).abs() < 1E-05f32);
+    }
+
+    #[test]
+    fn test_hausdorff_is_symmetric() {
+        let line = [Vector2::zero(), Vector2::new(10f32, 0f32)];
+        let spike = [Vector2::zero(), Vector2::new(5f32, 3f32), Vector2::new(10f32, 0f32)];
+        assert_eq!(directed_hausdorff(&line, &spike), 0f32);
+        assert_eq!(directed_hausdorff(&spike, &line), 3f32);
+        assert_eq!(hausdorff_distance(&line, &spike), 3f32);
+        assert_eq!(hausdorff_distance(&spike, &line), 3f32);
+    }
+
+    fn brute_force_frechet(a: &[Vector2], b: &[Vector2], i: usize, j: usize) -> f32 {
+        let d = (a[i] - b[j]).magnitude();
+        match (i, j) {
+            (0, 0) => d,
+            (0, _) => d.max(brute_force_frechet(a, b, 0, j - 1)),
+            (_, 0) => d.max(brute_force_frechet(a, b, i - 1, 0)),
+            _ => d.max(
+                brute_force_frechet(a, b, i - 1, j)
+                    .min(brute_force_frechet(a, b, i - 1, j - 1))
+                    .min(brute_force_frechet(a, b, i, j - 1)),
+            ),
+        }
+    }
+
+    #[test]
+    fn test_frechet_identical_and_reversed() {
+        let path = zigzag();
+        assert_eq!(frechet_distance(&path, &path), 0f32);
+        let reversed: Vec<Vector2> = path.iter().rev().copied().collect();
+        assert_eq!(hausdorff_distance(&path, &reversed), 0f32);
+        assert!(frechet_distance(&path, &reversed) > 4f32);
+        assert_eq!(frechet_distance(&path, &[]), f32::INFINITY);
+    }
+
+    #[test]
+    fn test_frechet_matches_brute_force() {
+        let mut rng = Lcg(11);
+        let mut next = || rng.next() * 10f32;
+        for (n, m) in [(1, 1), (1, 4), (3, 5), (6, 6), (7, 4)] {
+            let a: Vec<Vector2> = (0..n).map(|_| Vector2::new(next(), next())).collect();
+            let b: Vec<Vector2> = (0..m).map(|_| Vector2::new(next(), next())).collect();
+            assert_eq!(frechet_distance(&a, &b), brute_force_frechet(&a, &b, n - 1, m - 1));
+        }
+    }
+
+    #[test]
+    fn test_dtw_identical() {
+        let path = zigzag();
+        let (cost, alignment) = dtw(&path, &path);
+        assert_eq!(cost, 0f32);
+        assert_eq!(alignment, (0..path.len()).map(|i| (i, i)).collect::<Vec<_>>());
+        assert_eq!(dtw(&[], &[]), (0f32, Vec::new()));
+        assert_eq!(dtw(&path, &[]).0, f32::INFINITY);
+    }
+
+    #[test]
+    fn test_dtw_time_stretched() {
+        let curve: Vec<Vector2> = (0..20).map(|i| Vector2::new(i as f32, (i as f32 * 0.5f32).sin())).collect();
+        // Every point repeated: the same motion played at half speed.
+        let stretched: Vec<Vector2> = curve.iter().flat_map(|&p| [p, p]).collect();
+        let (cost, alignment) = dtw(&curve, &stretched);
+        assert_eq!(cost, 0f32);
+        assert_eq!(alignment.len(), stretched.len());
+        assert_eq!(alignment[0], (0, 0));
+        assert_eq!(alignment[alignment.len() - 1], (19, 39));
+        assert!(alignment.windows(2).all(|w| w[1].0 >= w[0].0 && w[1].1 >= w[0].1));
+
+        let shifted: Vec<Vector2> = curve.iter().map(|&p| p + Vector2::new(0.5f32, 0f32)).collect();
+        let (unaligned, _) = dtw(&curve, &shifted);
+        assert!(unaligned > 1f32);
+    }
+
+    fn assert_same_function(a: &[(f32, f32)], b: &[(f32, f32)]) {
+        assert_eq!(a.len(), b.len());
+        for (&(s, t), &(s2, t2)) in a.iter().zip(b) {
+            assert!((s - s2).abs() < 1E-05f32 && (t - t2).abs() < 1E-03f32, "{:?} vs {:?}", a, b);
+        }
+    }
+
+    #[test]
+    fn test_turning_function_square() {
+        let square = [Vector2::zero(), Vector2::new(2f32, 0f32), Vector2::new(2f32, 2f32), Vector2::new(0f32, 2f32)];
+        let function = turning_function(&square);
+        assert_same_function(&function, &[(0f32, 0f32), (0.25f32, 90f32), (0.5f32, 180f32), (0.75f32, 270f32)]);
+
+        let rotation = crate::algorithms::matrix2x2::Matrix2x2::rotation(33f32);
+        let moved: Vec<Vector2> = square.iter().map(|&p| rotation * (p * 3f32) + Vector2::new(5f32, -1f32)).collect();
+        assert_same_function(&turning_function(&moved), &function);
+
+        // Starting one vertex later shifts the function by one edge, which maps a square onto
+        // itself.
+        let mut shifted = square;
+        shifted.rotate_left(1);
+        assert_same_function(&turning_function(&shifted), &function);
+    }
+
+    #[test]
+    fn test_turning_function_triangle_differs() {
+        let triangle: Vec<Vector2> = (0..3).map(|i| Vector2::from_angle(i as f32 * 120f32)).collect();
+        let function = turning_function(&triangle);
+        assert_eq!(function.len(), 3);
+        assert!(turning_function(&[Vector2::one(); 4]).is_empty());
+        assert!((function[1].0 - 1f32 / 3f32).abs() < 1E-05f32);
+        assert!((function[1].1 - 120f32).abs() < 1E-03f32);
+        assert!((function[2].1 - 240f32).abs() < 1E-03f32);
+        assert!(turning_function(&[Vector2::one()]).is_empty());
+    }
+}
@@ -0,0 +1,364 @@
+use super::polygon::{distance_to_boundary, point_in_polygon, signed_area};
+use super::rect::Rect;
+use super::vector2::Vector2;
+
+/// Rough medial axis of `polygon`: samples every `sample_spacing` inside it whose distance to
+/// the boundary is a ridge, i.e. no smaller than both neighbours and larger than one of them
+/// along some grid row, column or diagonal. Returned in row-major sample order.
+pub fn approximate_skeleton(polygon: &[Vector2], sample_spacing: f32) -> Vec<Vector2> {
+    let Some(bounds) = Rect::from_points(polygon) else {
+        return Vec::new();
+    };
+    if sample_spacing <= 0f32 {
+        return Vec::new();
+    }
+    let width = (bounds.width / sample_spacing).ceil() as usize;
+    let height = (bounds.height / sample_spacing).ceil() as usize;
+    let sample = |x: usize, y: usize| bounds.min() + Vector2::new(x as f32 + 0.5f32, y as f32 + 0.5f32) * sample_spacing;
+    let distances: Vec<Vec<f32>> = (0..height)
+        .map(|y| {
+            (0..width)
+                .map(|x| {
+                    let p = sample(x, y);
+                    if point_in_polygon(p, polygon) { distance_to_boundary(p, polygon) } else { 0f32 }
+                })
+                .collect()
+        })
+        .collect();
+    let at = |x: isize, y: isize| {
+        if x < 0 || y < 0 || x >= width as isize || y >= height as isize { 0f32 } else { distances[y as usize][x as usize] }
+    };
+
+    let mut skeleton = Vec::new();
+    for (y, row) in distances.iter().enumerate() {
+        for (x, &d) in row.iter().enumerate() {
+            if d <= 0f32 {
+                continue;
+            }
+            let (xi, yi) = (x as isize, y as isize);
+            let ridge = [(1, 0), (0, 1), (1, 1), (1, -1)].iter().any(|&(dx, dy)| {
+                let (a, b) = (at(xi - dx, yi - dy), at(xi + dx, yi + dy));
+                d >= a && d >= b && (d > a || d > b)
+            });
+            if ridge {
+                skeleton.push(sample(x, y));
+            }
+        }
+    }
+    skeleton
+}
+
+/// Upper bound on wavefront events handled per inset, in case of degenerate input.
+const MAX_EVENTS: usize = 10_000;
+
+/// Insets a simple polygon by `distance` by moving every edge inwards at unit speed along the
+/// straight skeleton: edges that shrink to nothing are removed (edge events), and a reflex
+/// vertex running into another edge splits the polygon in two (split events). Returns the
+/// remaining pieces counter-clockwise; an inset past the polygon's skeleton gives none.
+/// A non-positive distance returns the polygon unchanged.
+pub fn inset_polygon_straight_skeleton(polygon: &[Vector2], distance: f32) -> Vec<Vec<Vector2>> {
+    let mut start = polygon.to_vec();
+    if signed_area(&start) < 0f32 {
+        start.reverse();
+    }
+    if distance <= 0f32 {
+        return vec![start];
+    }
+    let mut pending = vec![(start, distance)];
+    let mut finished = Vec::new();
+    let mut events = 0;
+    while let Some((wavefront, remaining)) = pending.pop() {
+        let mut wavefront = remove_degenerate_vertices(wavefront);
+        if wavefront.len() < 3 || signed_area(&wavefront) <= 1E-09f32 {
+            continue;
+        }
+        let velocities = vertex_velocities(&wavefront);
+        let event = next_event(&wavefront, &velocities);
+        events += 1;
+        match event {
+            Some((time, event)) if time < remaining && events < MAX_EVENTS => {
+                for (point, velocity) in wavefront.iter_mut().zip(&velocities) {
+                    *point = *point + *velocity * time;
+                }
+                let left = remaining - time;
+                match event {
+                    WavefrontEvent::Edge(i) => {
+                        let next = (i + 1) % wavefront.len();
+                        wavefront[i] = Vector2::midpoint(wavefront[i], wavefront[next]);
+                        wavefront.remove(next);
+                        pending.push((wavefront, left));
+                    }
+                    WavefrontEvent::Split { vertex, edge } => {
+                        let n = wavefront.len();
+                        let (a, b) = (edge, (edge + 1) % n);
+                        let first: Vec<Vector2> = (0..).map(|k| (vertex + k) % n).take_while(|&k| k != b).map(|k| wavefront[k]).collect();
+                        let second: Vec<Vector2> = (0..).map(|k| (b + k) % n).take_while(|&k| k != vertex).map(|k| wavefront[k]).chain([wavefront[vertex]]).collect();
+                        debug_assert!(first.last() == Some(&wavefront[a]));
+                        pending.push((first, left));
+                        pending.push((second, left));
+                    }
+                }
+            }
+            _ => {
+                for (point, velocity) in wavefront.iter_mut().zip(&velocities) {
+                    *point = *point + *velocity * remaining;
+                }
+                finished.push(wavefront);
+            }
+        }
+    }
+    finished
+}
+
+/// One offset ring of `polygon` per entry of `distances`, counter-clockwise: positive
+/// distances push the boundary outwards with mitred corners, negative ones inset it along the
+/// straight skeleton. An inset that splits the polygon keeps its largest piece, and one past
+/// the skeleton collapses to an empty ring. Outward rings are exact for convex polygons; at a
+/// reflex corner they fold once the distance outgrows the neighbouring edges.
+pub fn offset_isolines(polygon: &[Vector2], distances: &[f32]) -> Vec<Vec<Vector2>> {
+    let mut ring = polygon.to_vec();
+    if signed_area(&ring) < 0f32 {
+        ring.reverse();
+    }
+    let n = ring.len();
+    let outward = |a: Vector2, b: Vector2| (b - a).normalized().perpendicular() * -1f32;
+    distances
+        .iter()
+        .map(|&distance| {
+            if distance < 0f32 {
+                return inset_polygon_straight_skeleton(&ring, -distance)
+                    .into_iter()
+                    .max_by(|a, b| signed_area(a).total_cmp(&signed_area(b)))
+                    .unwrap_or_default();
+            }
+            (0..n)
+                .map(|i| {
+                    let (before, after) = (outward(ring[(i + n - 1) % n], ring[i]), outward(ring[i], ring[(i + 1) % n]));
+                    ring[i] + (before + after) * (distance / (1f32 + Vector2::dot(before, after)))
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Drops repeated vertices and zero-width spikes, which events leave behind where parts of
+/// the wavefront meet.
+fn remove_degenerate_vertices(mut polygon: Vec<Vector2>) -> Vec<Vector2> {
+    let same = |a: Vector2, b: Vector2| (a - b).sqr_magnitude() <= 1E-10f32;
+    let mut changed = true;
+    while changed && polygon.len() >= 3 {
+        changed = false;
+        let n = polygon.len();
+        for i in 0..n {
+            let (previous, next) = (polygon[(i + n - 1) % n], polygon[(i + 1) % n]);
+            if same(polygon[i], next) {
+                polygon.remove(i);
+                changed = true;
+                break;
+            }
+            if same(previous, next) {
+                // A spike out to `i` and straight back: drop the tip and one copy of its base.
+                let (first, second) = (i.max((i + 1) % n), i.min((i + 1) % n));
+                polygon.remove(first);
+                polygon.remove(second);
+                changed = true;
+                break;
+            }
+        }
+    }
+    polygon
+}
+
+enum WavefrontEvent {
+    /// Edge `i` (from vertex `i` to `i + 1`) shrinks to zero length.
+    Edge(usize),
+    /// A reflex vertex reaches the interior of another edge.
+    Split { vertex: usize, edge: usize },
+}
+
+/// Velocity of each vertex that keeps both of its edges moving inwards at unit speed.
+fn vertex_velocities(polygon: &[Vector2]) -> Vec<Vector2> {
+    let n = polygon.len();
+    let inward = |i: usize| (polygon[(i + 1) % n] - polygon[i]).normalized().perpendicular();
+    (0..n)
+        .map(|i| {
+            let (a, b) = (inward((i + n - 1) % n), inward(i));
+            let denominator = 1f32 + Vector2::dot(a, b);
+            if denominator < 1E-06f32 { a } else { (a + b) / denominator }
+        })
+        .collect()
+}
+
+/// Earliest edge or split event of the moving wavefront, with its time.
+fn next_event(polygon: &[Vector2], velocities: &[Vector2]) -> Option<(f32, WavefrontEvent)> {
+    let n = polygon.len();
+    let mut best: Option<(f32, WavefrontEvent)> = None;
+    let mut consider = |time: f32, event: WavefrontEvent| {
+        if time >= 0f32 && best.as_ref().is_none_or(|(t, _)| time < *t) {
+            best = Some((time, event));
+        }
+    };
+    for i in 0..n {
+        let j = (i + 1) % n;
+        let edge = polygon[j] - polygon[i];
+        let shrink = Vector2::dot(velocities[j] - velocities[i], edge.normalized());
+        if shrink < 0f32 {
+            consider(edge.magnitude() / -shrink, WavefrontEvent::Edge(i));
+        }
+    }
+    for vertex in 0..n {
+        let (previous, next) = (polygon[(vertex + n - 1) % n], polygon[(vertex + 1) % n]);
+        if Vector2::cross(polygon[vertex] - previous, next - polygon[vertex]) >= 0f32 {
+            continue;
+        }
+        for edge in 0..n {
+            let end = (edge + 1) % n;
+            if edge == vertex || end == vertex {
+                continue;
+            }
+            let (a, b) = (polygon[edge], polygon[end]);
+            let normal = (b - a).normalized().perpendicular();
+            let closing = 1f32 - Vector2::dot(velocities[vertex], normal);
+            if closing <= 1E-06f32 {
+                continue;
+            }
+            let time = Vector2::dot(polygon[vertex] - a, normal) / closing;
+            if time <= 0f32 {
+                continue;
+            }
+            let hit = polygon[vertex] + velocities[vertex] * time;
+            let (moved_a, moved_b) = (a + velocities[edge] * time, b + velocities[end] * time);
+            let along = Vector2::dot(hit - moved_a, moved_b - moved_a);
+            // Endpoints count too, so a corridor pinching shut splits at the shared vertex.
+            let length2 = (moved_b - moved_a).sqr_magnitude();
+            if along >= -1E-04f32 * length2 && along <= length2 * (1f32 + 1E-04f32) {
+                consider(time, WavefrontEvent::Split { vertex, edge });
+            }
+        }
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::test_support::l_shape;
+
+    #[test]
+    fn test_rectangle_skeleton_follows_spine() {
+        let rectangle = [Vector2::zero(), Vector2::new(10f32, 0f32), Vector2::new(10f32, 2f32), Vector2::new(0f32, 2f32)];
+        let spacing = 0.25f32;
+        let skeleton = approximate_skeleton(&rectangle, spacing);
+        assert!(!skeleton.is_empty());
+        assert!(skeleton.iter().all(|&p| point_in_polygon(p, &rectangle)));
+        // Away from the corner branches every ridge sample sits on the horizontal spine.
+        let middle: Vec<Vector2> = skeleton.iter().copied().filter(|p| p.x > 1f32 + spacing && p.x < 9f32 - spacing).collect();
+        assert!(middle.len() >= 28);
+        assert!(middle.iter().all(|p| (p.y - 1f32).abs() <= spacing));
+        // Corner branches run along the bisectors.
+        assert!(skeleton.iter().all(|p| (p.y - 1f32).abs() <= spacing || (p.y - p.x.min(10f32 - p.x)).abs() <= spacing || (2f32 - p.y - p.x.min(10f32 - p.x)).abs() <= spacing));
+    }
+
+    #[test]
+    fn test_skeleton_degenerate() {
+        assert!(approximate_skeleton(&[], 1f32).is_empty());
+        assert!(approximate_skeleton(&[Vector2::zero(), Vector2::one(), Vector2::new(1f32, 0f32)], 0f32).is_empty());
+    }
+
+    fn assert_polygon_eq(actual: &[Vector2], expected: &[Vector2]) {
+        assert_eq!(actual.len(), expected.len(), "{:?}", actual);
+        let offset = (0..actual.len()).find(|&k| (actual[k] - expected[0]).magnitude() < 1E-04f32).expect("missing vertex");
+        for (i, &e) in expected.iter().enumerate() {
+            assert!((actual[(i + offset) % actual.len()] - e).magnitude() < 1E-04f32, "{:?}", actual);
+        }
+    }
+
+    #[test]
+    fn test_inset_convex_is_similar() {
+        let square = [Vector2::zero(), Vector2::new(4f32, 0f32), Vector2::new(4f32, 4f32), Vector2::new(0f32, 4f32)];
+        let pieces = inset_polygon_straight_skeleton(&square, 1f32);
+        assert_eq!(pieces.len(), 1);
+        assert_polygon_eq(&pieces[0], &[Vector2::one(), Vector2::new(3f32, 1f32), Vector2::new(3f32, 3f32), Vector2::new(1f32, 3f32)]);
+
+        let hexagon: Vec<Vector2> = (0..6).map(|i| Vector2::from_angle(i as f32 * 60f32) * 2f32).collect();
+        let apothem = 3f32.sqrt();
+        let pieces = inset_polygon_straight_skeleton(&hexagon, apothem * 0.5f32);
+        let expected: Vec<Vector2> = hexagon.iter().map(|&p| p * 0.5f32).collect();
+        assert_eq!(pieces.len(), 1);
+        assert_polygon_eq(&pieces[0], &expected);
+    }
+
+    #[test]
+    fn test_inset_collapses() {
+        let rectangle = [Vector2::zero(), Vector2::new(6f32, 0f32), Vector2::new(6f32, 2f32), Vector2::new(0f32, 2f32)];
+        let pieces = inset_polygon_straight_skeleton(&rectangle, 0.5f32);
+        assert_polygon_eq(&pieces[0], &[Vector2::new(0.5f32, 0.5f32), Vector2::new(5.5f32, 0.5f32), Vector2::new(5.5f32, 1.5f32), Vector2::new(0.5f32, 1.5f32)]);
+        assert!(inset_polygon_straight_skeleton(&rectangle, 1.5f32).is_empty());
+        let mut clockwise = rectangle;
+        clockwise.reverse();
+        assert_eq!(inset_polygon_straight_skeleton(&clockwise, 0f32), vec![rectangle.to_vec()]);
+    }
+
+    #[test]
+    fn test_inset_notch_splits() {
+        // Two 4x4 rooms joined by a corridor one unit wide.
+        let dumbbell: Vec<Vector2> = [(0f32, 0f32), (4f32, 0f32), (4f32, 1.5f32), (6f32, 1.5f32), (6f32, 0f32), (10f32, 0f32), (10f32, 4f32), (6f32, 4f32), (6f32, 2.5f32), (4f32, 2.5f32), (4f32, 4f32), (0f32, 4f32)]
+            .iter()
+            .map(|&(x, y)| Vector2::new(x, y))
+            .collect();
+        let connected = inset_polygon_straight_skeleton(&dumbbell, 0.25f32);
+        assert_eq!(connected.len(), 1);
+        assert!((signed_area(&connected[0]) - (3.5f32 * 3.5f32 * 2f32 + 2.5f32 * 0.5f32)).abs() < 1E-03f32);
+
+        let mut split = inset_polygon_straight_skeleton(&dumbbell, 1f32);
+        assert_eq!(split.len(), 2);
+        split.sort_by(|a, b| a[0].x.total_cmp(&b[0].x));
+        for (piece, x) in split.iter().zip([1f32, 7f32]) {
+            assert!(signed_area(piece) > 0f32);
+            assert!(piece.iter().all(|p| p.x >= x - 1E-03f32 && p.x <= x + 2f32 + 1E-03f32));
+            assert!((signed_area(piece) - 4f32).abs() < 1E-03f32);
+        }
+        assert!(inset_polygon_straight_skeleton(&dumbbell, 2.5f32).is_empty());
+    }
+
+    #[test]
+    fn test_inset_reflex_corner() {
+        let shape = l_shape();
+        let pieces = inset_polygon_straight_skeleton(&shape, 0.25f32);
+        assert_eq!(pieces.len(), 1);
+        assert!((signed_area(&pieces[0]) - 3.25f32).abs() < 1E-04f32);
+        assert!(pieces[0].iter().any(|p| (*p - Vector2::new(0.75f32, 0.75f32)).magnitude() < 1E-04f32));
+        assert!(inset_polygon_straight_skeleton(&shape, 0.6f32).is_empty());
+    }
+
+    #[test]
+    fn test_offset_isolines_nested() {
+        let shape = l_shape();
+        let distances = [-0.4f32, -0.2f32, 0f32, 0.3f32, 0.6f32];
+        let rings = offset_isolines(&shape, &distances);
+        assert_eq!(rings.len(), 5);
+        for pair in rings.windows(2) {
+            assert!(signed_area(&pair[0]) > 0f32 && signed_area(&pair[0]) < signed_area(&pair[1]));
+            assert!(pair[0].iter().all(|&p| point_in_polygon(p, &pair[1])));
+        }
+        assert_eq!(rings[2], shape);
+        for (ring, &distance) in rings.iter().zip(&distances) {
+            // Every edge sits `distance` from the matching original edge, so the square corner
+            // at the origin moves diagonally.
+            assert!((ring[0] - Vector2::one() * -distance).magnitude() < 1E-04f32, "{:?}", ring);
+        }
+        let mut clockwise = shape.clone();
+        clockwise.reverse();
+        assert_eq!(offset_isolines(&clockwise, &[0.3f32]), vec![rings[3].clone()]);
+    }
+
+    #[test]
+    fn test_offset_isolines_collapse() {
+        let square = [Vector2::zero(), Vector2::new(2f32, 0f32), Vector2::new(2f32, 2f32), Vector2::new(0f32, 2f32)];
+        let rings = offset_isolines(&square, &[-0.5f32, -1.5f32, 1f32]);
+        assert!((signed_area(&rings[0]) - 1f32).abs() < 1E-04f32);
+        assert!(rings[1].is_empty());
+        assert!((signed_area(&rings[2]) - 16f32).abs() < 1E-04f32);
+        assert!(offset_isolines(&square, &[]).is_empty());
+    }
+}
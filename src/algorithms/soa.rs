@@ -0,0 +1,159 @@
+use super::vector2::Vector2;
+
+/// Positions stored as separate x and y arrays, so bulk operations run over contiguous
+/// `f32` slices.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Vector2Soa {
+    xs: Vec<f32>,
+    ys: Vec<f32>,
+}
+
+impl Vector2Soa {
+    pub fn new() -> Self { Self::default() }
+    pub fn with_capacity(capacity: usize) -> Self { Self { xs: Vec::with_capacity(capacity), ys: Vec::with_capacity(capacity) } }
+    pub fn len(&self) -> usize { self.xs.len() }
+    pub fn is_empty(&self) -> bool { self.xs.is_empty() }
+    pub fn xs(&self) -> &[f32] { &self.xs }
+    pub fn ys(&self) -> &[f32] { &self.ys }
+
+    pub fn push(&mut self, v: Vector2) {
+        self.xs.push(v.x);
+        self.ys.push(v.y);
+    }
+
+    pub fn get(&self, index: usize) -> Option<Vector2> {
+        Some(Vector2::new(*self.xs.get(index)?, self.ys[index]))
+    }
+
+    pub fn set(&mut self, index: usize, v: Vector2) {
+        self.xs[index] = v.x;
+        self.ys[index] = v.y;
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Vector2> + '_ {
+        self.xs.iter().zip(&self.ys).map(|(&x, &y)| Vector2::new(x, y))
+    }
+
+    pub fn to_vec(&self) -> Vec<Vector2> { self.iter().collect() }
+
+    pub fn translate_all(&mut self, offset: Vector2) {
+        self.xs.iter_mut().for_each(|x| *x += offset.x);
+        self.ys.iter_mut().for_each(|y| *y += offset.y);
+    }
+
+    /// Multiplies every position component-wise by `scale`.
+    pub fn scale_all(&mut self, scale: Vector2) {
+        self.xs.iter_mut().for_each(|x| *x *= scale.x);
+        self.ys.iter_mut().for_each(|y| *y *= scale.y);
+    }
+
+    /// Index of the position nearest to `query` and its distance; the first one wins ties.
+    pub fn nearest(&self, query: Vector2) -> Option<(usize, f32)> {
+        if self.is_empty() {
+            return None;
+        }
+        // Keep a running minimum per lane so the inner loop has no cross-iteration dependency
+        // and can be vectorized, then reduce the lanes at the end.
+        const LANES: usize = 8;
+        let mut best = [f32::INFINITY; LANES];
+        let mut best_index = [0usize; LANES];
+        let chunks = self.xs.chunks_exact(LANES).zip(self.ys.chunks_exact(LANES));
+        for (chunk, (xs, ys)) in chunks.enumerate() {
+            for lane in 0..LANES {
+                let (dx, dy) = (xs[lane] - query.x, ys[lane] - query.y);
+                let d = dx * dx + dy * dy;
+                if d < best[lane] {
+                    best[lane] = d;
+                    best_index[lane] = chunk * LANES + lane;
+                }
+            }
+        }
+        let mut result = (usize::MAX, f32::INFINITY);
+        for lane in 0..LANES {
+            if best[lane] < result.1 || (best[lane] == result.1 && best_index[lane] < result.0) {
+                result = (best_index[lane], best[lane]);
+            }
+        }
+        let tail = self.len() - self.len() % LANES;
+        for i in tail..self.len() {
+            let (dx, dy) = (self.xs[i] - query.x, self.ys[i] - query.y);
+            let d = dx * dx + dy * dy;
+            if d < result.1 {
+                result = (i, d);
+            }
+        }
+        if result.0 == usize::MAX {
+            result.0 = 0;
+        }
+        Some((result.0, result.1.sqrt()))
+    }
+}
+
+impl From<&[Vector2]> for Vector2Soa {
+    fn from(points: &[Vector2]) -> Self {
+        Self { xs: points.iter().map(|p| p.x).collect(), ys: points.iter().map(|p| p.y).collect() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::points;
+
+    fn sample() -> Vec<Vector2> {
+        vec![Vector2::new(1f32, 2f32), Vector2::new(-3f32, 0.5f32), Vector2::new(4f32, -6f32)]
+    }
+
+    #[test]
+    fn test_push_and_get() {
+        let mut soa = Vector2Soa::new();
+        assert!(soa.is_empty());
+        for p in sample() {
+            soa.push(p);
+        }
+        assert_eq!(soa.len(), 3);
+        for (i, p) in sample().into_iter().enumerate() {
+            assert_eq!(soa.get(i), Some(p));
+        }
+        assert_eq!(soa.get(3), None);
+        assert_eq!(soa.xs(), &[1f32, -3f32, 4f32]);
+        assert_eq!(soa.ys(), &[2f32, 0.5f32, -6f32]);
+        soa.set(1, Vector2::zero());
+        assert_eq!(soa.get(1), Some(Vector2::zero()));
+    }
+
+    #[test]
+    fn test_from_slice_and_iter() {
+        let points = sample();
+        let soa = Vector2Soa::from(points.as_slice());
+        assert_eq!(soa.to_vec(), points);
+        assert_eq!(soa.iter().count(), 3);
+    }
+
+    #[test]
+    fn test_translate_and_scale_all() {
+        let points = sample();
+        let mut soa = Vector2Soa::from(points.as_slice());
+        let offset = Vector2::new(0.5f32, -1f32);
+        soa.translate_all(offset);
+        let translated: Vec<Vector2> = points.iter().map(|&p| p + offset).collect();
+        assert_eq!(soa.to_vec(), translated);
+        let scale = Vector2::new(2f32, 3f32);
+        soa.scale_all(scale);
+        let scaled: Vec<Vector2> = translated.iter().map(|&p| p * scale).collect();
+        assert_eq!(soa.to_vec(), scaled);
+    }
+
+    #[test]
+    fn test_nearest_matches_aos() {
+        let points: Vec<Vector2> = (0..500)
+            .map(|i| Vector2::new(((i * 37) % 101) as f32 * 0.7f32, ((i * 53) % 89) as f32 * 1.3f32))
+            .collect();
+        let soa = Vector2Soa::from(points.as_slice());
+        for j in 0..50 {
+            let query = Vector2::new(j as f32 * 1.9f32 - 10f32, 100f32 - j as f32 * 2.3f32);
+            assert_eq!(soa.nearest(query), points::nearest(&points, query));
+        }
+        assert_eq!(Vector2Soa::new().nearest(Vector2::zero()), None);
+    }
+}
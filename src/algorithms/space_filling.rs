@@ -0,0 +1,133 @@
+use super::rect::Rect;
+use super::vector2::Vector2;
+use super::vector2_int::Vector2Int;
+
+/// Indices of `points` ordered along a Hilbert curve of the given `order` laid over `bounds`,
+/// which splits it into `2^order` cells a side. Points outside are clamped to the border cells,
+/// and points sharing a cell keep their input order. `order` is capped at 31.
+pub fn hilbert_sort(points: &[Vector2], bounds: Rect, order: u32) -> Vec<usize> {
+    let side = 1u64 << order.min(31);
+    let cell = |value: f32, min: f32, extent: f32| {
+        let t = if extent > 0f32 { (value - min) / extent } else { 0f32 };
+        ((t * side as f32).floor().max(0f32) as u64).min(side - 1)
+    };
+    let keys: Vec<u64> = points
+        .iter()
+        .map(|p| hilbert_index(side, cell(p.x, bounds.x, bounds.width), cell(p.y, bounds.y, bounds.height)))
+        .collect();
+    let mut sorted: Vec<usize> = (0..points.len()).collect();
+    sorted.sort_by_key(|&i| keys[i]);
+    sorted
+}
+
+/// Distance of cell `(x, y)` along the Hilbert curve filling a `side`-by-`side` grid.
+fn hilbert_index(side: u64, mut x: u64, mut y: u64) -> u64 {
+    let mut index = 0;
+    let mut s = side / 2;
+    while s > 0 {
+        let (rx, ry) = ((x & s > 0) as u64, (y & s > 0) as u64);
+        index += s * s * ((3 * rx) ^ ry);
+        // Rotate the quadrant so the sub-curve inside it starts and ends in the right corners.
+        if ry == 0 {
+            if rx == 1 {
+                x = s - 1 - (x & (s - 1));
+                y = s - 1 - (y & (s - 1));
+            }
+            std::mem::swap(&mut x, &mut y);
+        }
+        s /= 2;
+    }
+    index
+}
+
+/// Z-order code of `v`, with the bits of `x` in the even positions and those of `y` in the odd
+/// ones. Coordinates are offset by `2^31` first, so negative cells sort before positive ones and
+/// every `i32` pair round-trips through [`morton_decode`].
+pub fn morton_encode(v: Vector2Int) -> u64 { spread_bits((v.x as u32 ^ 0x8000_0000) as u64) | (spread_bits((v.y as u32 ^ 0x8000_0000) as u64) << 1) }
+
+/// Inverse of [`morton_encode`].
+pub fn morton_decode(code: u64) -> Vector2Int {
+    Vector2Int::new((compact_bits(code) as u32 ^ 0x8000_0000) as i32, (compact_bits(code >> 1) as u32 ^ 0x8000_0000) as i32)
+}
+
+/// Moves bit `i` of the low 32 bits to bit `2i`.
+fn spread_bits(mut v: u64) -> u64 {
+    v &= 0xFFFF_FFFF;
+    v = (v | (v << 16)) & 0x0000_FFFF_0000_FFFF;
+    v = (v | (v << 8)) & 0x00FF_00FF_00FF_00FF;
+    v = (v | (v << 4)) & 0x0F0F_0F0F_0F0F_0F0F;
+    v = (v | (v << 2)) & 0x3333_3333_3333_3333;
+    (v | (v << 1)) & 0x5555_5555_5555_5555
+}
+
+/// Inverse of [`spread_bits`], reading the even bits.
+fn compact_bits(mut v: u64) -> u64 {
+    v &= 0x5555_5555_5555_5555;
+    v = (v | (v >> 1)) & 0x3333_3333_3333_3333;
+    v = (v | (v >> 2)) & 0x0F0F_0F0F_0F0F_0F0F;
+    v = (v | (v >> 4)) & 0x00FF_00FF_00FF_00FF;
+    v = (v | (v >> 8)) & 0x0000_FFFF_0000_FFFF;
+    (v | (v >> 16)) & 0xFFFF_FFFF
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::test_support::Lcg;
+
+    #[test]
+    fn test_hilbert_sort_walks_grid_cells() {
+        // One point per cell of an 8 x 8 grid, listed row by row.
+        let points: Vec<Vector2> = (0..64).map(|i| Vector2::new((i % 8) as f32 + 0.5f32, (i / 8) as f32 + 0.5f32)).collect();
+        let bounds = Rect::new(0f32, 0f32, 8f32, 8f32);
+        let sorted = hilbert_sort(&points, bounds, 3);
+        let mut indices = sorted.clone();
+        indices.sort_unstable();
+        assert_eq!(indices, (0..64).collect::<Vec<usize>>());
+        assert!(sorted.windows(2).all(|w| ((points[w[0]] - points[w[1]]).magnitude() - 1f32).abs() < 1E-06f32));
+        assert_eq!((points[sorted[0]], points[sorted[63]]), (Vector2::new(0.5f32, 0.5f32), Vector2::new(7.5f32, 0.5f32)));
+    }
+
+    #[test]
+    fn test_hilbert_sort_improves_locality() {
+        let mut rng = Lcg(17);
+        let mut next = || rng.next();
+        let points: Vec<Vector2> = (0..500).map(|_| Vector2::new(next() * 100f32, next() * 50f32 - 20f32)).collect();
+        let bounds = Rect::from_points(&points).unwrap();
+        let sorted = hilbert_sort(&points, bounds, 10);
+        let mut indices = sorted.clone();
+        indices.sort_unstable();
+        assert_eq!(indices, (0..500).collect::<Vec<usize>>());
+        let length = |order: &[usize]| order.windows(2).map(|w| (points[w[0]] - points[w[1]]).magnitude()).sum::<f32>();
+        let input_order: Vec<usize> = (0..500).collect();
+        assert!(length(&sorted) < 0.2f32 * length(&input_order));
+        // Outside points are clamped rather than lost.
+        let outside = [Vector2::new(-5f32, -5f32), Vector2::new(200f32, 5f32)];
+        assert_eq!(hilbert_sort(&outside, bounds, 4).len(), 2);
+        assert!(hilbert_sort(&[], bounds, 4).is_empty());
+    }
+
+    #[test]
+    fn test_morton_round_trip() {
+        for v in [Vector2Int::zero(), Vector2Int::new(1, 0), Vector2Int::new(0, 1), Vector2Int::new(-3, 7), Vector2Int::new(123456, -98765), Vector2Int::new(i32::MIN, i32::MAX)] {
+            assert_eq!(morton_decode(morton_encode(v)), v);
+        }
+        let origin = morton_encode(Vector2Int::zero());
+        assert_eq!(morton_encode(Vector2Int::new(1, 0)) - origin, 1);
+        assert_eq!(morton_encode(Vector2Int::new(0, 1)) - origin, 2);
+        assert_eq!(morton_encode(Vector2Int::one()) - origin, 3);
+        assert!(morton_encode(Vector2Int::new(-1, -1)) < origin);
+    }
+
+    #[test]
+    fn test_morton_neighbours_are_close() {
+        // Within an aligned 4 x 4 block all sixteen codes are consecutive.
+        let base = Vector2Int::new(8, 12);
+        let mut codes: Vec<u64> = (0..16).map(|i| morton_encode(base + Vector2Int::new(i % 4, i / 4))).collect();
+        codes.sort_unstable();
+        assert!(codes.windows(2).all(|w| w[1] - w[0] == 1));
+        // Most horizontal neighbours across a larger area differ by little.
+        let close = (0..64).flat_map(|y| (0..63).map(move |x| (x, y))).filter(|&(x, y)| morton_encode(Vector2Int::new(x + 1, y)) - morton_encode(Vector2Int::new(x, y)) <= 16).count();
+        assert!(close * 4 > 64 * 63 * 3);
+    }
+}
@@ -0,0 +1,141 @@
+use super::vector2::Vector2;
+use super::vector3::Vector3;
+
+/// Uniform B-spline of `degree` at `t` in `[0, 1]`, by De Boor's algorithm. The knot vector is
+/// clamped, so the curve starts and ends on the first and last control points. The degree is
+/// capped at one less than the number of control points; no control points give zero.
+pub fn bspline(control_points: &[Vector2], degree: usize, t: f32) -> Vector2 {
+    if control_points.is_empty() {
+        return Vector2::zero();
+    }
+    let degree = degree.min(control_points.len() - 1);
+    let spans = control_points.len() - degree;
+    let knots: Vec<f32> = (0..control_points.len() + degree + 1)
+        .map(|i| (i.saturating_sub(degree) as f32 / spans as f32).min(1f32))
+        .collect();
+    nurbs(control_points, &vec![1f32; control_points.len()], &knots, degree, t)
+}
+
+/// Rational B-spline of `degree` at parameter `t`, clamped to the valid knot range
+/// `[knots[degree], knots[n]]`. Evaluated with De Boor's algorithm in homogeneous coordinates.
+///
+/// # Panics
+///
+/// If `degree` is not less than the number of control points (so in particular when there are
+/// none), if there is not one weight per control point, or if `knots` does not hold
+/// `control_points.len() + degree + 1` values.
+pub fn nurbs(control_points: &[Vector2], weights: &[f32], knots: &[f32], degree: usize, t: f32) -> Vector2 {
+    let n = control_points.len();
+    assert!(degree < n, "degree {} needs {} or more control points, got {}", degree, degree + 1, n);
+    assert_eq!(weights.len(), n, "expected one weight per control point");
+    assert_eq!(knots.len(), n + degree + 1, "expected control_points.len() + degree + 1 knots");
+    let t = t.clamp(knots[degree], knots[n]);
+    let span = (degree..n).rev().find(|&k| knots[k] <= t && knots[k] < knots[n]).unwrap_or(degree);
+
+    let mut d: Vec<Vector3> = (0..=degree)
+        .map(|j| {
+            let (p, w) = (control_points[j + span - degree], weights[j + span - degree]);
+            Vector3::new(p.x * w, p.y * w, w)
+        })
+        .collect();
+    for r in 1..=degree {
+        for j in (r..=degree).rev() {
+            let (left, right) = (knots[j + span - degree], knots[j + 1 + span - r]);
+            let alpha = if right > left { (t - left) / (right - left) } else { 0f32 };
+            d[j] = d[j - 1] * (1f32 - alpha) + d[j] * alpha;
+        }
+    }
+    let point = d[degree];
+    Vector2::new(point.x / point.z, point.y / point.z)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::hull::convex_hull;
+
+    fn control_points() -> Vec<Vector2> {
+        [(0f32, 0f32), (1f32, 2f32), (3f32, 3f32), (4f32, 0f32), (6f32, 1f32), (7f32, 4f32)].iter().map(|&(x, y)| Vector2::new(x, y)).collect()
+    }
+
+    #[test]
+    fn test_degree_one_is_control_polyline() {
+        let points = control_points();
+        let segments = (points.len() - 1) as f32;
+        for i in 0..points.len() - 1 {
+            for f in [0f32, 0.25f32, 0.5f32, 0.9f32] {
+                let t = (i as f32 + f) / segments;
+                let expected = Vector2::lerp(points[i], points[i + 1], f);
+                assert!((bspline(&points, 1, t) - expected).magnitude() < 1E-05f32);
+            }
+        }
+        assert_eq!(bspline(&points, 1, 1f32), points[points.len() - 1]);
+    }
+
+    #[test]
+    fn test_cubic_stays_in_local_hull() {
+        let points = control_points();
+        assert!((bspline(&points, 3, 0f32) - points[0]).magnitude() < 1E-06f32);
+        assert!((bspline(&points, 3, 1f32) - points[5]).magnitude() < 1E-06f32);
+        let spans = points.len() - 3;
+        for i in 0..=60 {
+            let t = i as f32 / 60f32;
+            let span = ((t * spans as f32) as usize).min(spans - 1);
+            let hull = convex_hull(&points[span..span + 4]);
+            let p = bspline(&points, 3, t);
+            for k in 0..hull.len() {
+                let (a, b) = (hull[k], hull[(k + 1) % hull.len()]);
+                assert!(Vector2::cross(b - a, p - a) >= -1E-05f32, "t = {} outside local hull", t);
+            }
+        }
+    }
+
+    #[test]
+    fn test_bspline_degenerate() {
+        assert_eq!(bspline(&[], 3, 0.5f32), Vector2::zero());
+        let line = [Vector2::zero(), Vector2::new(2f32, 0f32)];
+        assert_eq!(bspline(&line, 3, 0.5f32), Vector2::new(1f32, 0f32));
+    }
+
+    #[test]
+    fn test_nurbs_unit_weights_match_bspline() {
+        let points = control_points();
+        let knots = [0f32, 0f32, 0f32, 0f32, 1f32, 2f32, 3f32, 3f32, 3f32, 3f32];
+        let weights = vec![1f32; points.len()];
+        for i in 0..=30 {
+            let t = i as f32 / 30f32;
+            let rational = nurbs(&points, &weights, &knots, 3, t * 3f32);
+            assert!((rational - bspline(&points, 3, t)).magnitude() < 1E-05f32);
+        }
+    }
+
+    #[test]
+    fn test_nurbs_exact_quarter_circle() {
+        let points = [Vector2::new(1f32, 0f32), Vector2::new(1f32, 1f32), Vector2::new(0f32, 1f32)];
+        let weights = [1f32, std::f32::consts::FRAC_1_SQRT_2, 1f32];
+        let knots = [0f32, 0f32, 0f32, 1f32, 1f32, 1f32];
+        for i in 0..=20 {
+            let p = nurbs(&points, &weights, &knots, 2, i as f32 / 20f32);
+            assert!((p.magnitude() - 1f32).abs() < 1E-06f32);
+        }
+        assert!((nurbs(&points, &weights, &knots, 2, 0.5f32) - Vector2::from_angle(45f32)).magnitude() < 1E-06f32);
+    }
+
+    #[test]
+    #[should_panic(expected = "one weight per control point")]
+    fn test_nurbs_weight_mismatch() {
+        nurbs(&[Vector2::zero(), Vector2::one()], &[1f32], &[0f32, 0f32, 1f32, 1f32], 1, 0.5f32);
+    }
+
+    #[test]
+    #[should_panic(expected = "needs 3 or more control points")]
+    fn test_nurbs_degree_too_high() {
+        nurbs(&[Vector2::zero(), Vector2::one()], &[1f32, 1f32], &[0f32, 0f32, 0f32, 1f32, 1f32, 1f32], 2, 0.5f32);
+    }
+
+    #[test]
+    #[should_panic(expected = "needs 1 or more control points")]
+    fn test_nurbs_empty() {
+        nurbs(&[], &[], &[0f32], 0, 0.5f32);
+    }
+}
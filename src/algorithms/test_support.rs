@@ -0,0 +1,21 @@
+//! Fixtures shared by the unit tests.
+
+use super::vector2::Vector2;
+
+/// Small deterministic generator for test data, so tests do not need the `rand` feature.
+pub struct Lcg(pub u64);
+
+impl Lcg {
+    /// Next value in `[0, 1)`.
+    pub fn next(&mut self) -> f32 {
+        self.0 = self.0.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (self.0 >> 40) as f32 / (1u64 << 24) as f32
+    }
+}
+
+
+/// L-shaped hexagon with its reflex corner at `(1, 1)`, spanning `[0, 4] x [0, 4]` with arms
+/// one unit wide.
+pub fn l_shape() -> Vec<Vector2> {
+    [(0f32, 0f32), (4f32, 0f32), (4f32, 1f32), (1f32, 1f32), (1f32, 4f32), (0f32, 4f32)].iter().map(|&(x, y)| Vector2::new(x, y)).collect()
+}
@@ -0,0 +1,132 @@
+use super::vector2::Vector2;
+
+/// Constant-velocity Kalman filter over a 2D position. Both axes share the same noise model and
+/// are filtered independently.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Kalman2D {
+    position: Vector2,
+    velocity: Vector2,
+    /// Covariance of (position, velocity) per axis: `[pp, pv, vv]`.
+    covariance: [f32; 3],
+    process_noise: f32,
+    measurement_noise: f32,
+}
+
+impl Kalman2D {
+    /// `process_noise` is the spectral density of the unmodelled acceleration, `measurement_noise`
+    /// the variance of each measured coordinate.
+    pub fn new(initial: Vector2, process_noise: f32, measurement_noise: f32) -> Self {
+        Self { position: initial, velocity: Vector2::zero(), covariance: [measurement_noise, 0f32, 1E+03f32], process_noise, measurement_noise }
+    }
+    pub fn position(&self) -> Vector2 { self.position }
+    pub fn velocity(&self) -> Vector2 { self.velocity }
+
+    pub fn predict(&mut self, dt: f32) {
+        self.position = self.position + self.velocity * dt;
+        let [pp, pv, vv] = self.covariance;
+        let q = self.process_noise;
+        self.covariance = [
+            pp + 2f32 * dt * pv + dt * dt * vv + q * dt * dt * dt / 3f32,
+            pv + dt * vv + q * dt * dt / 2f32,
+            vv + q * dt,
+        ];
+    }
+
+    /// Corrects the state with a measured position and returns the filtered position.
+    pub fn update(&mut self, measurement: Vector2) -> Vector2 {
+        let [pp, pv, vv] = self.covariance;
+        let s = pp + self.measurement_noise;
+        let (k_position, k_velocity) = (pp / s, pv / s);
+        let residual = measurement - self.position;
+        self.position = self.position + residual * k_position;
+        self.velocity = self.velocity + residual * k_velocity;
+        self.covariance = [(1f32 - k_position) * pp, (1f32 - k_position) * pv, vv - k_velocity * pv];
+        self.position
+    }
+}
+
+/// Fixed-gain alpha-beta tracker; a cheap stand-in for [`Kalman2D`] when many entities are
+/// tracked at once.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AlphaBeta2D {
+    alpha: f32,
+    beta: f32,
+    position: Vector2,
+    velocity: Vector2,
+}
+
+impl AlphaBeta2D {
+    pub fn new(initial: Vector2, alpha: f32, beta: f32) -> Self { Self { alpha, beta, position: initial, velocity: Vector2::zero() } }
+    pub fn position(&self) -> Vector2 { self.position }
+    pub fn velocity(&self) -> Vector2 { self.velocity }
+
+    /// Advances the estimate by `dt`, blends in the measured position and returns the filtered
+    /// position.
+    pub fn update(&mut self, measurement: Vector2, dt: f32) -> Vector2 {
+        let predicted = self.position + self.velocity * dt;
+        let residual = measurement - predicted;
+        self.position = predicted + residual * self.alpha;
+        if dt > 0f32 {
+            self.velocity = self.velocity + residual * (self.beta / dt);
+        }
+        self.position
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::test_support::Lcg;
+
+    #[test]
+    fn test_kalman_reduces_noise() {
+        let mut rng = Lcg(3);
+        let velocity = Vector2::new(2f32, -1f32);
+        let dt = 0.1f32;
+        let mut filter = Kalman2D::new(Vector2::zero(), 0.01f32, 0.25f32);
+        let (mut raw_error, mut filtered_error) = (0f32, 0f32);
+        for step in 1..=400 {
+            let truth = velocity * (step as f32 * dt);
+            let noise = Vector2::new(rng.next() - 0.5f32, rng.next() - 0.5f32) * 1.5f32;
+            filter.predict(dt);
+            let estimate = filter.update(truth + noise);
+            if step > 50 {
+                raw_error += noise.sqr_magnitude();
+                filtered_error += (estimate - truth).sqr_magnitude();
+            }
+        }
+        assert!(filtered_error < raw_error * 0.2f32);
+        assert!((filter.velocity() - velocity).magnitude() < 0.2f32);
+    }
+
+    #[test]
+    fn test_kalman_exact_measurements() {
+        let mut filter = Kalman2D::new(Vector2::new(3f32, 4f32), 1f32, 1E-06f32);
+        assert_eq!(filter.update(Vector2::new(3f32, 4f32)), Vector2::new(3f32, 4f32));
+        filter.predict(1f32);
+        let estimate = filter.update(Vector2::new(4f32, 4f32));
+        assert!((estimate - Vector2::new(4f32, 4f32)).magnitude() < 1E-04f32);
+        assert!((filter.velocity() - Vector2::new(1f32, 0f32)).magnitude() < 1E-02f32);
+    }
+
+    #[test]
+    fn test_alpha_beta_converges() {
+        let velocity = Vector2::new(-3f32, 1.5f32);
+        let start = Vector2::new(10f32, 10f32);
+        let dt = 0.05f32;
+        let mut filter = AlphaBeta2D::new(start, 0.5f32, 0.1f32);
+        let mut estimate = start;
+        for step in 1..=300 {
+            estimate = filter.update(start + velocity * (step as f32 * dt), dt);
+        }
+        assert!((estimate - (start + velocity * (300f32 * dt))).magnitude() < 1E-03f32);
+        assert!((filter.velocity() - velocity).magnitude() < 1E-03f32);
+    }
+
+    #[test]
+    fn test_alpha_beta_zero_dt() {
+        let mut filter = AlphaBeta2D::new(Vector2::zero(), 0.5f32, 0.1f32);
+        assert_eq!(filter.update(Vector2::new(2f32, 0f32), 0f32), Vector2::new(1f32, 0f32));
+        assert_eq!(filter.velocity(), Vector2::zero());
+    }
+}
@@ -0,0 +1,139 @@
+use super::matrix3x2::Matrix3x2;
+use super::vector2::Vector2;
+
+/// Translation, counter-clockwise rotation in degrees and scale, applied scale first.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform2D {
+    pub position: Vector2,
+    pub rotation: f32,
+    pub scale: Vector2,
+}
+
+impl Transform2D {
+    pub fn new(position: Vector2, rotation: f32, scale: Vector2) -> Self { Self { position, rotation, scale } }
+    pub fn identity() -> Self { Self::new(Vector2::zero(), 0f32, Vector2::one()) }
+
+    pub fn to_matrix(&self) -> Matrix3x2 {
+        Matrix3x2::translation(self.position) * Matrix3x2::rotation(self.rotation) * Matrix3x2::scale(self.scale)
+    }
+
+    /// Interpolates position and scale linearly and rotation along the shorter arc. `t` is
+    /// clamped to `[0, 1]`.
+    pub fn lerp(a: &Transform2D, b: &Transform2D, t: f32) -> Transform2D {
+        let t = t.clamp(0f32, 1f32);
+        let delta = (b.rotation - a.rotation + 180f32).rem_euclid(360f32) - 180f32;
+        Self::new(Vector2::lerp(a.position, b.position, t), a.rotation + delta * t, Vector2::lerp(a.scale, b.scale, t))
+    }
+
+    pub fn transform_point(&self, p: Vector2) -> Vector2 { self.to_matrix().transform_point(p) }
+
+    /// Recovers translation, rotation and scale from an affine matrix. The rotation follows
+    /// the transformed x axis and any mirroring, read from the sign of the determinant, is
+    /// carried by a negative y scale, so a negative x scale comes back as a half-turn with both
+    /// scales flipped. When the x axis has all but vanished next to the y axis, the rotation
+    /// follows the y axis instead. Shear cannot be represented and is dropped.
+    pub fn from_matrix(m: &Matrix3x2) -> Transform2D {
+        let (x_axis, y_axis) = (Vector2::new(m.m00, m.m10), Vector2::new(m.m01, m.m11));
+        let scale_x = x_axis.magnitude();
+        if scale_x <= 1E-06f32 * y_axis.magnitude() || scale_x == 0f32 {
+            let rotation = if y_axis == Vector2::zero() { 0f32 } else { y_axis.to_angle() - 90f32 };
+            return Self::new(m.get_translation(), rotation, Vector2::new(0f32, y_axis.magnitude()));
+        }
+        let rotation = x_axis.to_angle();
+        // The y axis length across the x axis, negative when the matrix mirrors.
+        let scale_y = m.determinant() / scale_x;
+        Self::new(m.get_translation(), rotation, Vector2::new(scale_x, scale_y))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::approx::ApproxEq;
+
+    fn assert_transform_eq(a: Transform2D, b: Transform2D) {
+        assert!(a.position.approx_eq(&b.position, 1E-04f32), "{:?} != {:?}", a, b);
+        assert!((a.rotation - b.rotation).approx_eq(&0f32, 1E-03f32), "{:?} != {:?}", a, b);
+        assert!(a.scale.approx_eq(&b.scale, 1E-04f32), "{:?} != {:?}", a, b);
+    }
+
+    #[test]
+    fn test_to_matrix() {
+        let t = Transform2D::new(Vector2::new(1f32, 2f32), 90f32, Vector2::new(2f32, 3f32));
+        let p = t.transform_point(Vector2::new(1f32, 1f32));
+        assert!(p.approx_eq(&Vector2::new(-2f32, 4f32), 1E-05f32));
+        assert_eq!(Transform2D::identity().to_matrix(), Matrix3x2::identity());
+    }
+
+    #[test]
+    fn test_from_matrix_round_trip() {
+        for t in [
+            Transform2D::new(Vector2::new(3f32, -4f32), 35f32, Vector2::new(2f32, 0.5f32)),
+            Transform2D::new(Vector2::zero(), -120f32, Vector2::new(1f32, 1f32)),
+            Transform2D::new(Vector2::new(-1f32, 7f32), 170f32, Vector2::new(0.3f32, -2f32)),
+        ] {
+            assert_transform_eq(Transform2D::from_matrix(&t.to_matrix()), t);
+        }
+    }
+
+    #[test]
+    fn test_from_matrix_negative_x_scale() {
+        let t = Transform2D::new(Vector2::new(1f32, 1f32), 30f32, Vector2::new(-2f32, 3f32));
+        let recovered = Transform2D::from_matrix(&t.to_matrix());
+        assert_transform_eq(recovered, Transform2D::new(t.position, -150f32, Vector2::new(2f32, -3f32)));
+        assert!(recovered.to_matrix().approx_eq(&t.to_matrix(), 1E-05f32));
+    }
+
+    #[test]
+    fn test_from_matrix_reflection() {
+        // Mirror across the line at 30 degrees: no scale, just a flip.
+        let (cos, sin) = (60f32.to_radians().cos(), 60f32.to_radians().sin());
+        let mirror = Matrix3x2::new(cos, sin, 2f32, sin, -cos, -1f32);
+        let recovered = Transform2D::from_matrix(&mirror);
+        assert!((recovered.scale.x - 1f32).abs() < 1E-05f32 && (recovered.scale.y + 1f32).abs() < 1E-05f32, "{:?}", recovered);
+        assert!(recovered.to_matrix().approx_eq(&mirror, 1E-05f32));
+        let flipped = Transform2D::new(Vector2::zero(), 0f32, Vector2::new(1f32, -1f32));
+        assert_transform_eq(Transform2D::from_matrix(&flipped.to_matrix()), flipped);
+    }
+
+    #[test]
+    fn test_from_matrix_collapsed_x_axis() {
+        for scale_x in [0f32, 1E-09f32] {
+            let t = Transform2D::new(Vector2::new(1f32, 2f32), 40f32, Vector2::new(scale_x, -2f32));
+            let recovered = Transform2D::from_matrix(&t.to_matrix());
+            assert!(recovered.to_matrix().approx_eq(&t.to_matrix(), 1E-05f32), "{:?}", recovered);
+        }
+        let zero = Transform2D::from_matrix(&Matrix3x2::scale(Vector2::zero()));
+        assert_eq!((zero.rotation, zero.scale), (0f32, Vector2::zero()));
+    }
+
+    #[test]
+    fn test_lerp_endpoints() {
+        let a = Transform2D::new(Vector2::new(0f32, 0f32), 10f32, Vector2::new(1f32, 1f32));
+        let b = Transform2D::new(Vector2::new(4f32, -2f32), 80f32, Vector2::new(3f32, 2f32));
+        assert_transform_eq(Transform2D::lerp(&a, &b, 0f32), a);
+        assert_transform_eq(Transform2D::lerp(&a, &b, 1f32), b);
+        let mid = Transform2D::lerp(&a, &b, 0.5f32);
+        assert_transform_eq(mid, Transform2D::new(Vector2::new(2f32, -1f32), 45f32, Vector2::new(2f32, 1.5f32)));
+        assert_transform_eq(Transform2D::lerp(&a, &b, 2f32), b);
+    }
+
+    #[test]
+    fn test_lerp_shortest_arc() {
+        let a = Transform2D::new(Vector2::zero(), 170f32, Vector2::one());
+        let b = Transform2D::new(Vector2::zero(), -170f32, Vector2::one());
+        let mid = Transform2D::lerp(&a, &b, 0.5f32);
+        assert!((mid.rotation - 180f32).abs() < 1E-04f32);
+        let back = Transform2D::lerp(&b, &a, 0.25f32);
+        assert!((back.rotation + 175f32).abs() < 1E-04f32);
+        let end = Transform2D::lerp(&a, &b, 1f32);
+        assert!(end.to_matrix().approx_eq(&b.to_matrix(), 1E-05f32));
+    }
+
+    #[test]
+    fn test_from_matrix_degenerate() {
+        let m = Matrix3x2::translation(Vector2::one()) * Matrix3x2::scale(Vector2::new(0f32, 2f32));
+        let t = Transform2D::from_matrix(&m);
+        assert_eq!(t, Transform2D::new(Vector2::one(), 0f32, Vector2::new(0f32, 2f32)));
+    }
+}
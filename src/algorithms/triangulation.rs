@@ -0,0 +1,86 @@
+use super::polygon::signed_area;
+use super::vector2::Vector2;
+
+/// Ear-clipping triangulation of a simple polygon in either winding. Triangles index into
+/// `polygon` and wind counter-clockwise.
+pub fn triangulate(polygon: &[Vector2]) -> Vec<[usize; 3]> {
+    let mut remaining: Vec<usize> = (0..polygon.len()).collect();
+    if signed_area(polygon) < 0f32 {
+        remaining.reverse();
+    }
+    let mut triangles = Vec::with_capacity(polygon.len().saturating_sub(2));
+    while remaining.len() > 3 {
+        let n = remaining.len();
+        let corner = |k: usize| (remaining[(k + n - 1) % n], remaining[k], remaining[(k + 1) % n]);
+        let ear = (0..n).find(|&k| {
+            let (a, b, c) = corner(k);
+            let (pa, pb, pc) = (polygon[a], polygon[b], polygon[c]);
+            Vector2::cross(pb - pa, pc - pb) > 0f32
+                && !remaining.iter().any(|&other| other != a && other != b && other != c && in_triangle(polygon[other], pa, pb, pc))
+        });
+        // Degenerate input (collinear runs, self-touching) may have no strict ear; clip the
+        // flattest corner so the loop always terminates.
+        let k = ear.unwrap_or_else(|| {
+            (0..n)
+                .min_by(|&i, &j| {
+                    let flatness = |k: usize| {
+                        let (a, b, c) = corner(k);
+                        Vector2::cross(polygon[b] - polygon[a], polygon[c] - polygon[b]).abs()
+                    };
+                    flatness(i).total_cmp(&flatness(j))
+                })
+                .unwrap()
+        });
+        let (a, b, c) = corner(k);
+        triangles.push([a, b, c]);
+        remaining.remove(k);
+    }
+    if remaining.len() == 3 {
+        triangles.push([remaining[0], remaining[1], remaining[2]]);
+    }
+    triangles
+}
+
+/// Inclusive test against a counter-clockwise triangle.
+fn in_triangle(p: Vector2, a: Vector2, b: Vector2, c: Vector2) -> bool {
+    Vector2::cross(b - a, p - a) >= 0f32 && Vector2::cross(c - b, p - b) >= 0f32 && Vector2::cross(a - c, p - c) >= 0f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn triangles_area(polygon: &[Vector2], triangles: &[[usize; 3]]) -> f32 {
+        triangles.iter().map(|&[a, b, c]| signed_area(&[polygon[a], polygon[b], polygon[c]])).sum()
+    }
+
+    #[test]
+    fn test_triangulate_concave() {
+        let arrow = [Vector2::zero(), Vector2::new(4f32, 2f32), Vector2::new(0f32, 4f32), Vector2::new(1f32, 2f32)];
+        let triangles = triangulate(&arrow);
+        assert_eq!(triangles.len(), 2);
+        assert!(triangles.iter().all(|&[a, b, c]| signed_area(&[arrow[a], arrow[b], arrow[c]]) > 0f32));
+        assert!((triangles_area(&arrow, &triangles) - signed_area(&arrow)).abs() < 1E-05f32);
+    }
+
+    #[test]
+    fn test_triangulate_clockwise() {
+        let mut comb: Vec<Vector2> = vec![Vector2::new(0f32, 0f32), Vector2::new(5f32, 0f32)];
+        for i in (0..5).rev() {
+            comb.push(Vector2::new(i as f32 + 1f32, 3f32));
+            comb.push(Vector2::new(i as f32 + 0.5f32, 1f32));
+        }
+        comb.reverse();
+        let triangles = triangulate(&comb);
+        assert_eq!(triangles.len(), comb.len() - 2);
+        assert!(triangles.iter().all(|&[a, b, c]| signed_area(&[comb[a], comb[b], comb[c]]) > 0f32));
+        assert!((triangles_area(&comb, &triangles) + signed_area(&comb)).abs() < 1E-04f32);
+    }
+
+    #[test]
+    fn test_triangulate_degenerate() {
+        assert!(triangulate(&[Vector2::zero(), Vector2::one()]).is_empty());
+        let with_collinear = [Vector2::zero(), Vector2::new(1f32, 0f32), Vector2::new(2f32, 0f32), Vector2::new(1f32, 1f32)];
+        assert_eq!(triangulate(&with_collinear).len(), 2);
+    }
+}
@@ -0,0 +1,10 @@
+/// `debug_assert!` that is only compiled in with the `validate` feature, for catching
+/// non-finite or otherwise invalid inputs at their source.
+macro_rules! debug_validate {
+    ($($arg:tt)+) => {
+        #[cfg(feature = "validate")]
+        debug_assert!($($arg)+);
+    };
+}
+
+pub(crate) use debug_validate;
@@ -1,8 +1,10 @@
 use std::fmt;
 use std::ops::{Add, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Sub};
 
+use super::validate::debug_validate;
+
 #[derive(Debug, Clone, Copy, PartialEq)]
-struct Vector2 {
+pub struct Vector2 {
     pub x: f32,
     pub y: f32,
 }
@@ -19,6 +21,50 @@ impl Vector2 {
     pub fn negative_infinity() -> Self { Self { x: f32::NEG_INFINITY, y: f32::NEG_INFINITY } }
     pub fn magnitude(&self) -> f32 { self.sqr_magnitude().sqrt() }
     pub fn sqr_magnitude(&self) -> f32 { self.x * self.x + self.y * self.y }
+    pub fn dot(a: Self, b: Self) -> f32 { a.x * b.x + a.y * b.y }
+    /// `dot(a, b) / (|a| |b|)` clamped to `[-1, 1]`; zero when either vector has zero length.
+    pub fn cosine_similarity(a: Self, b: Self) -> f32 {
+        let denominator = (a.sqr_magnitude() * b.sqr_magnitude()).sqrt();
+        if denominator == 0f32 {
+            return 0f32;
+        }
+        (Self::dot(a, b) / denominator).clamp(-1f32, 1f32)
+    }
+    /// Index of the candidate with the largest dot product against `reference`; the first one
+    /// wins ties.
+    pub fn most_aligned(reference: Self, candidates: &[Self]) -> Option<usize> {
+        let mut best: Option<(usize, f32)> = None;
+        for (i, &candidate) in candidates.iter().enumerate() {
+            let score = Self::dot(reference, candidate);
+            if best.is_none_or(|(_, best_score)| score > best_score) {
+                best = Some((i, score));
+            }
+        }
+        best.map(|(i, _)| i)
+    }
+    /// Z component of the 3D cross product; positive when `b` is counter-clockwise of `a`.
+    pub fn cross(a: Self, b: Self) -> f32 { a.x * b.y - a.y * b.x }
+    pub fn is_finite(&self) -> bool { self.x.is_finite() && self.y.is_finite() }
+    /// Unsigned angle in degrees between `from` and `to`; zero if either is near zero length.
+    pub fn angle(from: Self, to: Self) -> f32 {
+        debug_validate!(from.is_finite() && to.is_finite(), "Vector2::angle called with non-finite input {} {}", from, to);
+        let denominator = (from.sqr_magnitude() * to.sqr_magnitude()).sqrt();
+        if denominator < 1E-15f32 {
+            return 0f32;
+        }
+        (Self::dot(from, to) / denominator).clamp(-1f32, 1f32).acos().to_degrees()
+    }
+    /// Angle in degrees from `from` to `to`, positive counter-clockwise, in `[-180, 180]`.
+    pub fn signed_angle(from: Self, to: Self) -> f32 {
+        let angle = Self::angle(from, to);
+        if Self::cross(from, to) < 0f32 { -angle } else { angle }
+    }
+    /// Angle in degrees counter-clockwise from +x, in `(-180, 180]`.
+    pub fn to_angle(&self) -> f32 { self.y.atan2(self.x).to_degrees() }
+    pub fn from_angle(degrees: f32) -> Self {
+        let (sin, cos) = degrees.to_radians().sin_cos();
+        Self::new(cos, sin)
+    }
     pub fn set(&mut self, x: f32, y: f32) {
         self.x = x;
         self.y = y;
@@ -32,10 +78,34 @@ impl Vector2 {
         Self::new(a.x + (b.x - a.x) * t,
                   a.y + (b.y - a.y) * t)
     }
+    /// Rotated 90 degrees counter-clockwise.
+    pub fn perpendicular(&self) -> Self { Self::new(-self.y, self.x) }
+    pub fn map(&self, f: impl Fn(f32) -> f32) -> Self { Self::new(f(self.x), f(self.y)) }
+    pub fn combine(a: Self, b: Self, f: impl Fn(f32, f32) -> f32) -> Self { Self::new(f(a.x, b.x), f(a.y, b.y)) }
+    pub fn midpoint(a: Self, b: Self) -> Self { Self::new((a.x + b.x) * 0.5f32, (a.y + b.y) * 0.5f32) }
+    pub fn centroid(points: &[Self]) -> Option<Self> {
+        if points.is_empty() {
+            return None;
+        }
+        Some(Self::kahan_sum(points) / points.len() as f32)
+    }
+    /// Sum with Kahan compensation per component, which keeps the error from growing with the
+    /// number of points.
+    pub fn kahan_sum(points: &[Self]) -> Self {
+        let mut sum = Self::zero();
+        let mut compensation = Self::zero();
+        for &p in points {
+            let y = p - compensation;
+            let t = sum + y;
+            compensation = (t - sum) - y;
+            sum = t;
+        }
+        sum
+    }
     pub fn move_towards(current: Self, target: Self, max_distance_delta: f32) -> Self {
         let vector = target - current;
         let num = vector.magnitude();
-        return if num <= max_distance_delta || num == 0f32 {
+        if num <= max_distance_delta || num == 0f32 {
             target
         } else {
             current + vector / num * max_distance_delta
@@ -47,6 +117,7 @@ impl Vector2 {
     }
 
     pub fn normalize(&mut self) {
+        debug_validate!(self.is_finite(), "Vector2::normalize called on non-finite {}", self);
         let num = self.magnitude();
         if num > 1E-05f32 {
             *self /= num;
@@ -54,6 +125,51 @@ impl Vector2 {
             *self = Self::zero();
         }
     }
+
+    pub fn normalized(&self) -> Self {
+        let mut v = *self;
+        v.normalize();
+        v
+    }
+
+    /// Snaps onto the nearest of up/down/left/right when within `threshold_degrees` of it,
+    /// keeping the magnitude. Otherwise returns the vector unchanged.
+    pub fn snap_to_axis(&self, threshold_degrees: f32) -> Self {
+        self.snap_to_directions(&[Self::right(), Self::up(), Self::left(), Self::down()], threshold_degrees)
+    }
+
+    /// Like [`Vector2::snap_to_axis`] but also snaps onto the four diagonals.
+    pub fn snap_to_axis8(&self, threshold_degrees: f32) -> Self {
+        let d = 0.5f32.sqrt();
+        self.snap_to_directions(&[Self::right(), Self::new(d, d), Self::up(), Self::new(-d, d),
+                                  Self::left(), Self::new(-d, -d), Self::down(), Self::new(d, -d)], threshold_degrees)
+    }
+
+    /// Compass sector 0-7 counter-clockwise from 0 = right, each centred on its direction.
+    /// A direction exactly on a boundary belongs to the counter-clockwise sector.
+    pub fn octant(&self) -> u8 { self.sector(8) }
+
+    /// Sector 0-3 counter-clockwise from 0 = right, with the same boundary rule as
+    /// [`Vector2::octant`].
+    pub fn quadrant(&self) -> u8 { self.sector(4) }
+
+    fn sector(&self, count: u32) -> u8 {
+        let width = 360f32 / count as f32;
+        let angle = self.to_angle().rem_euclid(360f32);
+        (((angle + width * 0.5f32) / width).floor() as u32 % count) as u8
+    }
+
+    fn snap_to_directions(&self, directions: &[Self], threshold_degrees: f32) -> Self {
+        let magnitude = self.magnitude();
+        if magnitude == 0f32 {
+            return *self;
+        }
+        let nearest = directions.iter().map(|&direction| (direction, Self::angle(*self, direction))).min_by(|a, b| a.1.total_cmp(&b.1));
+        match nearest {
+            Some((direction, angle)) if angle <= threshold_degrees => direction * magnitude,
+            _ => *self,
+        }
+    }
 }
 
 impl Add for Vector2 {
@@ -213,6 +329,144 @@ mod tests {
         assert_eq!(v.sqr_magnitude(), 8f32);
     }
 
+    #[test]
+    fn test_dot() {
+        let a = Vector2::new(1f32, 2f32);
+        let b = Vector2::new(3f32, -4f32);
+        assert_eq!(Vector2::dot(a, b), -5f32);
+        assert_eq!(Vector2::dot(Vector2::up(), Vector2::right()), 0f32);
+    }
+
+    #[test]
+    fn test_cosine_similarity() {
+        let a = Vector2::new(2f32, 1f32);
+        assert!((Vector2::cosine_similarity(a, a * 3f32) - 1f32).abs() < 1E-06f32);
+        assert!((Vector2::cosine_similarity(a, a * -0.5f32) + 1f32).abs() < 1E-06f32);
+        assert!(Vector2::cosine_similarity(a, Vector2::new(-1f32, 2f32)).abs() < 1E-06f32);
+        assert_eq!(Vector2::cosine_similarity(a, Vector2::zero()), 0f32);
+        assert_eq!(Vector2::cosine_similarity(Vector2::zero(), Vector2::zero()), 0f32);
+    }
+
+    #[test]
+    fn test_most_aligned() {
+        let candidates = [Vector2::right(), Vector2::up(), Vector2::left(), Vector2::down()];
+        assert_eq!(Vector2::most_aligned(Vector2::new(0.2f32, 0.9f32), &candidates), Some(1));
+        assert_eq!(Vector2::most_aligned(Vector2::new(-1f32, -0.1f32), &candidates), Some(2));
+        assert_eq!(Vector2::most_aligned(Vector2::one(), &candidates), Some(0));
+        assert_eq!(Vector2::most_aligned(Vector2::one(), &[]), None);
+    }
+
+    #[test]
+    fn test_cross() {
+        assert_eq!(Vector2::cross(Vector2::right(), Vector2::up()), 1f32);
+        assert_eq!(Vector2::cross(Vector2::up(), Vector2::right()), -1f32);
+        assert_eq!(Vector2::cross(Vector2::new(2f32, 3f32), Vector2::new(4f32, 6f32)), 0f32);
+    }
+
+    #[test]
+    fn test_signed_angle() {
+        assert_eq!(Vector2::signed_angle(Vector2::right(), Vector2::up()), 90f32);
+        assert_eq!(Vector2::signed_angle(Vector2::right(), Vector2::down()), -90f32);
+        assert_eq!(Vector2::signed_angle(Vector2::up(), Vector2::up()), 0f32);
+    }
+
+    #[test]
+    fn test_angle() {
+        assert_eq!(Vector2::angle(Vector2::right(), Vector2::up()), 90f32);
+        assert_eq!(Vector2::angle(Vector2::right(), Vector2::left()), 180f32);
+        assert_eq!(Vector2::angle(Vector2::up(), Vector2::new(0f32, 3f32)), 0f32);
+        assert!((Vector2::angle(Vector2::right(), Vector2::new(1f32, -1f32)) - 45f32).abs() < 1E-04f32);
+        assert_eq!(Vector2::angle(Vector2::zero(), Vector2::up()), 0f32);
+    }
+
+    #[test]
+    fn test_to_and_from_angle() {
+        assert_eq!(Vector2::right().to_angle(), 0f32);
+        assert_eq!(Vector2::up().to_angle(), 90f32);
+        assert_eq!(Vector2::left().to_angle(), 180f32);
+        assert_eq!(Vector2::down().to_angle(), -90f32);
+        let v = Vector2::from_angle(30f32);
+        assert!((v.x - 0.75f32.sqrt()).abs() < 1E-06f32);
+        assert!((v.y - 0.5f32).abs() < 1E-06f32);
+        assert!((Vector2::from_angle(-135f32).to_angle() + 135f32).abs() < 1E-04f32);
+    }
+
+    #[test]
+    fn test_snap_to_axis() {
+        let near_up = Vector2::from_angle(92f32) * 3f32;
+        assert_eq!(near_up.snap_to_axis(5f32), Vector2::new(0f32, 3f32));
+        let far_from_up = Vector2::from_angle(100f32);
+        assert_eq!(far_from_up.snap_to_axis(5f32), far_from_up);
+        let near_left = Vector2::from_angle(183f32);
+        assert_eq!(near_left.snap_to_axis(5f32), Vector2::left());
+        assert_eq!(Vector2::zero().snap_to_axis(5f32), Vector2::zero());
+        // A wide threshold still picks the nearest direction, not the first within reach.
+        assert!((Vector2::from_angle(80f32).snap_to_axis(60f32) - Vector2::up()).magnitude() < 1E-06f32);
+        assert!((Vector2::from_angle(-100f32).snap_to_axis(90f32) - Vector2::down()).magnitude() < 1E-06f32);
+    }
+
+    #[test]
+    fn test_snap_to_axis8() {
+        let near_diagonal = Vector2::from_angle(47f32);
+        let d = 0.5f32.sqrt();
+        assert_eq!(near_diagonal.snap_to_axis8(5f32), Vector2::new(d, d));
+        assert_eq!(near_diagonal.snap_to_axis(5f32), near_diagonal);
+        assert_eq!(Vector2::from_angle(88f32).snap_to_axis8(5f32), Vector2::up());
+        let between = Vector2::from_angle(22f32);
+        assert_eq!(between.snap_to_axis8(5f32), between);
+        // Within reach of both right and the diagonal, the diagonal is nearer.
+        assert!((Vector2::from_angle(50f32).snap_to_axis8(50f32) - Vector2::new(d, d)).magnitude() < 1E-06f32);
+    }
+
+    #[test]
+    fn test_octant() {
+        assert_eq!(Vector2::right().octant(), 0);
+        assert_eq!(Vector2::one().octant(), 1);
+        assert_eq!(Vector2::up().octant(), 2);
+        assert_eq!(Vector2::left().octant(), 4);
+        assert_eq!(Vector2::down().octant(), 6);
+        assert_eq!(Vector2::new(1f32, -1f32).octant(), 7);
+        assert_eq!(Vector2::from_angle(-22f32).octant(), 0);
+        assert_eq!(Vector2::from_angle(22f32).octant(), 0);
+        assert_eq!(Vector2::from_angle(23f32).octant(), 1);
+        assert_eq!(Vector2::new(1f32, 1f32 / 3f32).octant(), 0);
+    }
+
+    #[test]
+    fn test_quadrant() {
+        assert_eq!(Vector2::right().quadrant(), 0);
+        assert_eq!(Vector2::up().quadrant(), 1);
+        assert_eq!(Vector2::left().quadrant(), 2);
+        assert_eq!(Vector2::down().quadrant(), 3);
+        assert_eq!(Vector2::new(1f32, 1f32).quadrant(), 1);
+        assert_eq!(Vector2::new(-1f32, 1f32).quadrant(), 2);
+        assert_eq!(Vector2::new(-1f32, -1f32).quadrant(), 3);
+        assert_eq!(Vector2::new(1f32, -1f32).quadrant(), 0);
+        assert_eq!(Vector2::from_angle(-44f32).quadrant(), 0);
+    }
+
+    #[test]
+    fn test_is_finite() {
+        assert!(Vector2::one().is_finite());
+        assert!(!Vector2::new(f32::NAN, 0f32).is_finite());
+        assert!(!Vector2::positive_infinity().is_finite());
+    }
+
+    #[cfg(all(feature = "validate", debug_assertions))]
+    #[test]
+    #[should_panic(expected = "Vector2::normalize called on non-finite")]
+    fn test_validate_normalize() {
+        let mut v = Vector2::new(f32::NAN, 1f32);
+        v.normalize();
+    }
+
+    #[cfg(all(feature = "validate", debug_assertions))]
+    #[test]
+    #[should_panic(expected = "Vector2::angle called with non-finite input")]
+    fn test_validate_angle() {
+        Vector2::angle(Vector2::new(1f32, f32::INFINITY), Vector2::up());
+    }
+
     #[test]
     fn test_with_index() {
         let v = Vector2::new(1f32, 2f32);
@@ -266,6 +520,59 @@ mod tests {
         assert_eq!(a / 2f32, Vector2::new(1f32,1f32));
     }
 
+    #[test]
+    fn test_perpendicular() {
+        assert_eq!(Vector2::right().perpendicular(), Vector2::up());
+        assert_eq!(Vector2::new(2f32, 3f32).perpendicular(), Vector2::new(-3f32, 2f32));
+    }
+
+    #[test]
+    fn test_map() {
+        let v = Vector2::new(-3f32, 2f32);
+        assert_eq!(v.map(|x| x * x), Vector2::new(9f32, 4f32));
+        assert_eq!(v.map(|x| x.clamp(0f32, 1f32)), Vector2::new(0f32, 1f32));
+        assert_eq!(v.map(f32::abs), Vector2::new(3f32, 2f32));
+    }
+
+    #[test]
+    fn test_combine() {
+        let a = Vector2::new(1f32, 5f32);
+        let b = Vector2::new(3f32, -2f32);
+        assert_eq!(Vector2::combine(a, b, f32::max), Vector2::new(3f32, 5f32));
+        assert_eq!(Vector2::combine(a, b, f32::min), Vector2::new(1f32, -2f32));
+        assert_eq!(Vector2::combine(a, b, |x, y| x + y), a + b);
+    }
+
+    #[test]
+    fn test_midpoint() {
+        assert_eq!(Vector2::midpoint(Vector2::new(1f32, 2f32), Vector2::new(3f32, -4f32)), Vector2::new(2f32, -1f32));
+        let a = Vector2::new(0.3f32, 7f32);
+        let b = Vector2::new(-5f32, 2.5f32);
+        assert!((Vector2::midpoint(a, b) - Vector2::lerp(a, b, 0.5f32)).magnitude() < 1E-06f32);
+    }
+
+    #[test]
+    fn test_centroid() {
+        let points = [Vector2::new(0f32, 0f32), Vector2::new(4f32, 0f32), Vector2::new(4f32, 2f32), Vector2::new(0f32, 2f32)];
+        assert_eq!(Vector2::centroid(&points), Some(Vector2::new(2f32, 1f32)));
+        assert_eq!(Vector2::centroid(&points[..1]), Some(Vector2::zero()));
+        assert_eq!(Vector2::centroid(&[]), None);
+    }
+
+    #[test]
+    fn test_kahan_sum() {
+        let points: Vec<Vector2> = (0..200000).map(|i| Vector2::new(0.1f32, 1E-03f32 * (i % 7) as f32)).collect();
+        let reference_x: f64 = points.iter().map(|p| p.x as f64).sum();
+        let reference_y: f64 = points.iter().map(|p| p.y as f64).sum();
+        let naive = points.iter().fold(Vector2::zero(), |sum, &p| sum + p);
+        let kahan = Vector2::kahan_sum(&points);
+        let naive_error = (naive.x as f64 - reference_x).abs() + (naive.y as f64 - reference_y).abs();
+        let kahan_error = (kahan.x as f64 - reference_x).abs() + (kahan.y as f64 - reference_y).abs();
+        assert!(kahan_error < naive_error);
+        assert!(kahan_error < 1E-02f64);
+        assert_eq!(Vector2::kahan_sum(&[]), Vector2::zero());
+    }
+
     #[test]
     fn test_move_towards() {
         let current = Vector2::new(0f32, 0f32);
@@ -301,4 +608,12 @@ mod tests {
         v2.normalize();
         assert_eq!(v2, Vector2::new(0f32.sqrt(),0f32.sqrt()));
     }
+
+    #[test]
+    fn test_normalized() {
+        let v = Vector2::new(3f32, 4f32);
+        assert_eq!(v.normalized(), Vector2::new(0.6f32, 0.8f32));
+        assert_eq!(v, Vector2::new(3f32, 4f32));
+        assert_eq!(Vector2::zero().normalized(), Vector2::zero());
+    }
 }
@@ -0,0 +1,107 @@
+use std::fmt;
+use std::ops::{Add, Index, IndexMut, Mul, Sub};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Vector2Int {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Vector2Int {
+    pub fn new(x: i32, y: i32) -> Self { Self { x, y } }
+    pub fn zero() -> Self { Self { x: 0, y: 0 } }
+    pub fn one() -> Self { Self { x: 1, y: 1 } }
+    pub fn up() -> Self { Self { x: 0, y: 1 } }
+    pub fn down() -> Self { Self { x: 0, y: -1 } }
+    pub fn left() -> Self { Self { x: -1, y: 0 } }
+    pub fn right() -> Self { Self { x: 1, y: 0 } }
+    pub fn manhattan_distance(a: Self, b: Self) -> i32 { (a.x - b.x).abs() + (a.y - b.y).abs() }
+}
+
+impl Add for Vector2Int {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Vector2Int {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Mul<i32> for Vector2Int {
+    type Output = Self;
+
+    fn mul(self, other: i32) -> Self {
+        Self::new(self.x * other, self.y * other)
+    }
+}
+
+impl Index<usize> for Vector2Int {
+    type Output = i32;
+
+    fn index(&self, index: usize) -> &i32 {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            _ => panic!("Index out of bounds"),
+        }
+    }
+}
+
+impl IndexMut<usize> for Vector2Int {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            _ => panic!("Index out of bounds"),
+        }
+    }
+}
+
+impl fmt::Display for Vector2Int {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vector2_int_creation() {
+        let v = Vector2Int::new(1, 2);
+        assert_eq!(v.x, 1);
+        assert_eq!(v.y, 2);
+        assert_eq!(Vector2Int::zero(), Vector2Int::new(0, 0));
+        assert_eq!(Vector2Int::one(), Vector2Int::new(1, 1));
+        assert_eq!(Vector2Int::up(), Vector2Int::new(0, 1));
+        assert_eq!(Vector2Int::down(), Vector2Int::new(0, -1));
+        assert_eq!(Vector2Int::left(), Vector2Int::new(-1, 0));
+        assert_eq!(Vector2Int::right(), Vector2Int::new(1, 0));
+    }
+
+    #[test]
+    fn test_operators() {
+        let a = Vector2Int::new(3, 4);
+        let b = Vector2Int::new(1, 2);
+        assert_eq!(a + b, Vector2Int::new(4, 6));
+        assert_eq!(a - b, Vector2Int::new(2, 2));
+        assert_eq!(a * 2, Vector2Int::new(6, 8));
+        assert_eq!(a[0], 3);
+        assert_eq!(a[1], 4);
+    }
+
+    #[test]
+    fn test_manhattan_distance() {
+        let a = Vector2Int::new(-1, 2);
+        let b = Vector2Int::new(3, -1);
+        assert_eq!(Vector2Int::manhattan_distance(a, b), 7);
+    }
+}
@@ -0,0 +1,120 @@
+use std::fmt;
+use std::ops::{Add, Index, IndexMut, Mul, Neg, Sub};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Vector3 {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+impl Vector3 {
+    pub fn new(x: f32, y: f32, z: f32) -> Self { Self { x, y, z } }
+    pub fn zero() -> Self { Self { x: 0f32, y: 0f32, z: 0f32 } }
+    pub fn one() -> Self { Self { x: 1f32, y: 1f32, z: 1f32 } }
+    pub fn right() -> Self { Self { x: 1f32, y: 0f32, z: 0f32 } }
+    pub fn up() -> Self { Self { x: 0f32, y: 1f32, z: 0f32 } }
+    pub fn forward() -> Self { Self { x: 0f32, y: 0f32, z: 1f32 } }
+    pub fn magnitude(&self) -> f32 { self.sqr_magnitude().sqrt() }
+    pub fn sqr_magnitude(&self) -> f32 { Self::dot(*self, *self) }
+    pub fn dot(a: Self, b: Self) -> f32 { a.x * b.x + a.y * b.y + a.z * b.z }
+    pub fn cross(a: Self, b: Self) -> Self { Self::new(a.y * b.z - a.z * b.y, a.z * b.x - a.x * b.z, a.x * b.y - a.y * b.x) }
+    /// Unit vector in the same direction, or zero for a near-zero vector.
+    pub fn normalized(&self) -> Self {
+        let magnitude = self.magnitude();
+        if magnitude > 1E-05f32 { *self * (1f32 / magnitude) } else { Self::zero() }
+    }
+}
+
+impl Add for Vector3 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl Sub for Vector3 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl Mul<f32> for Vector3 {
+    type Output = Self;
+
+    fn mul(self, other: f32) -> Self {
+        Self::new(self.x * other, self.y * other, self.z * other)
+    }
+}
+
+impl Neg for Vector3 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl Index<usize> for Vector3 {
+    type Output = f32;
+
+    fn index(&self, index: usize) -> &f32 {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            _ => panic!("Index out of bounds"),
+        }
+    }
+}
+
+impl IndexMut<usize> for Vector3 {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            _ => panic!("Index out of bounds"),
+        }
+    }
+}
+
+impl fmt::Display for Vector3 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dot_and_cross() {
+        let (a, b) = (Vector3::new(1f32, 2f32, 3f32), Vector3::new(4f32, 5f32, 6f32));
+        assert_eq!(Vector3::dot(a, b), 32f32);
+        assert_eq!(Vector3::cross(a, b), Vector3::new(-3f32, 6f32, -3f32));
+        assert_eq!(Vector3::cross(Vector3::right(), Vector3::up()), Vector3::forward());
+    }
+
+    #[test]
+    fn test_normalized() {
+        assert_eq!(Vector3::new(0f32, 3f32, 4f32).normalized(), Vector3::new(0f32, 0.6f32, 0.8f32));
+        assert_eq!(Vector3::zero().normalized(), Vector3::zero());
+    }
+
+    #[test]
+    fn test_operators_and_index() {
+        let mut v = Vector3::new(1f32, 2f32, 3f32);
+        assert_eq!(v + Vector3::one(), Vector3::new(2f32, 3f32, 4f32));
+        assert_eq!(v - Vector3::one(), Vector3::new(0f32, 1f32, 2f32));
+        assert_eq!(v * 2f32, Vector3::new(2f32, 4f32, 6f32));
+        assert_eq!(-v, Vector3::new(-1f32, -2f32, -3f32));
+        v[2] = 5f32;
+        assert_eq!(v[2], 5f32);
+        assert_eq!(v.to_string(), "(1, 2, 5)");
+    }
+}
@@ -0,0 +1,254 @@
+use super::ray2d::Ray2D;
+use super::rect::Rect;
+use super::vector2::Vector2;
+
+/// Angle offset of the extra rays cast either side of each endpoint, so the sweep sees past
+/// corners.
+const CORNER_EPSILON: f32 = 1E-04f32;
+
+/// Region visible from `viewpoint` among occluding `segments`, as a counter-clockwise polygon.
+/// The scene is closed by a box around the viewpoint and all segments, so an empty scene yields
+/// that box.
+pub fn visibility_polygon(viewpoint: Vector2, segments: &[(Vector2, Vector2)]) -> Vec<Vector2> {
+    let mut points = vec![viewpoint];
+    points.extend(segments.iter().flat_map(|&(a, b)| [a, b]));
+    let bounds = Rect::from_points(&points).unwrap();
+    let padding = bounds.width.max(bounds.height) * 0.5f32 + 1f32;
+    let (min, max) = (bounds.min() - Vector2::one() * padding, bounds.max() + Vector2::one() * padding);
+    let corners = [min, Vector2::new(max.x, min.y), max, Vector2::new(min.x, max.y)];
+
+    let mut walls = segments.to_vec();
+    walls.extend((0..4).map(|i| (corners[i], corners[(i + 1) % 4])));
+
+    let mut hits: Vec<(f32, Vector2)> = Vec::new();
+    for &(a, b) in &walls {
+        for endpoint in [a, b] {
+            let angle = (endpoint - viewpoint).to_angle().to_radians();
+            for offset in [-CORNER_EPSILON, 0f32, CORNER_EPSILON] {
+                let ray = Ray2D::new(viewpoint, Vector2::new((angle + offset).cos(), (angle + offset).sin()));
+                let nearest = walls.iter().filter_map(|&(a, b)| ray.intersect_segment(a, b)).fold(f32::INFINITY, f32::min);
+                if nearest.is_finite() {
+                    hits.push(((angle + offset).rem_euclid(std::f32::consts::TAU), ray.get_point(nearest)));
+                }
+            }
+        }
+    }
+    hits.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let mut polygon: Vec<Vector2> = Vec::with_capacity(hits.len());
+    for (_, point) in hits {
+        if polygon.last().is_none_or(|&last| (point - last).sqr_magnitude() > 1E-10f32) {
+            polygon.push(point);
+        }
+    }
+    remove_collinear(polygon, (max - min).magnitude() * 1E-06f32)
+}
+
+/// Shadow quad `[a, b, far_b, far_a]` behind each occluder segment, with the far edge projected
+/// radially away from `light` to `far` from it. Segments touching the light are skipped.
+pub fn shadow_segments(light: Vector2, occluder: &[(Vector2, Vector2)], far: f32) -> Vec<[Vector2; 4]> {
+    let project = |p: Vector2| {
+        let offset = p - light;
+        light + offset.normalized() * far.max(offset.magnitude())
+    };
+    occluder
+        .iter()
+        .filter(|&&(a, b)| a != light && b != light)
+        .map(|&(a, b)| [a, b, project(b), project(a)])
+        .collect()
+}
+
+/// True when no blocker touches the segment `a`-`b`. Contact at `a` or `b` themselves does not
+/// block, so a point standing on a wall end can still see along it; any other contact does,
+/// including grazing a blocker's endpoint or overlapping a collinear blocker.
+pub fn has_line_of_sight(a: Vector2, b: Vector2, blockers: &[(Vector2, Vector2)]) -> bool {
+    const ENDPOINT: f32 = 1E-06f32;
+    let sight = b - a;
+    let length2 = sight.sqr_magnitude();
+    blockers.iter().all(|&(p, q)| {
+        let edge = q - p;
+        let denominator = Vector2::cross(sight, edge);
+        let offset = p - a;
+        if denominator.abs() > 1E-12f32 * length2.max(edge.sqr_magnitude()) {
+            let t = Vector2::cross(offset, edge) / denominator;
+            let u = Vector2::cross(offset, sight) / denominator;
+            return !(t > ENDPOINT && t < 1f32 - ENDPOINT && (0f32..=1f32).contains(&u));
+        }
+        if length2 == 0f32 || Vector2::cross(offset, sight).abs() > 1E-06f32 * length2 {
+            return true;
+        }
+        // Collinear: blocked when the blocker's span along the sight line overlaps its interior.
+        let (tp, tq) = (Vector2::dot(offset, sight) / length2, Vector2::dot(q - a, sight) / length2);
+        tp.max(tq) <= ENDPOINT || tp.min(tq) >= 1f32 - ENDPOINT
+    })
+}
+
+/// Directions from `center`, as `(start, end)` angles in degrees within `[0, 360]`, that are
+/// blocked by at least one segment. Each segment blocks the arc it subtends, and overlapping or
+/// touching intervals are merged and sorted by start. An arc running through 0° comes back as a
+/// single wrapping range, last, whose start is greater than its end (say `(315, 45)`); a
+/// fully covered circle is `(0, 360)`. Segments passing through `center` are ignored.
+pub fn angular_coverage(center: Vector2, segments: &[(Vector2, Vector2)]) -> Vec<(f32, f32)> {
+    let mut intervals = Vec::new();
+    for &(a, b) in segments {
+        let (da, db) = (a - center, b - center);
+        let turn = Vector2::cross(da, db);
+        if turn == 0f32 {
+            continue;
+        }
+        let (from, to) = if turn > 0f32 { (da, db) } else { (db, da) };
+        let start = from.to_angle().rem_euclid(360f32);
+        let end = start + Vector2::signed_angle(from, to).abs();
+        if end > 360f32 {
+            intervals.push((start, 360f32));
+            intervals.push((0f32, end - 360f32));
+        } else {
+            intervals.push((start, end));
+        }
+    }
+    intervals.sort_by(|a, b| a.0.total_cmp(&b.0));
+    let mut merged: Vec<(f32, f32)> = Vec::new();
+    for (start, end) in intervals {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    if merged.len() > 1 && merged[0].0 == 0f32 && merged[merged.len() - 1].1 == 360f32 {
+        let first = merged.remove(0);
+        merged.last_mut().unwrap().1 = first.1;
+    }
+    merged
+}
+
+/// Drops points within `tolerance` of the chord between their neighbours, which mostly removes
+/// the extra hits of the corner rays along straight walls.
+fn remove_collinear(points: Vec<Vector2>, tolerance: f32) -> Vec<Vector2> {
+    let count = points.len();
+    let kept: Vec<Vector2> = (0..count)
+        .filter(|&i| {
+            let (previous, point, next) = (points[(i + count - 1) % count], points[i], points[(i + 1) % count]);
+            let chord = next - previous;
+            Vector2::cross(chord, point - previous).abs() > tolerance * chord.magnitude()
+        })
+        .map(|i| points[i])
+        .collect();
+    if kept.len() >= 3 { kept } else { points }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::polygon::signed_area;
+
+    fn visible_distance(polygon: &[Vector2], viewpoint: Vector2, degrees: f32) -> f32 {
+        let ray = Ray2D::new(viewpoint, Vector2::from_angle(degrees));
+        (0..polygon.len())
+            .filter_map(|i| ray.intersect_segment(polygon[i], polygon[(i + 1) % polygon.len()]))
+            .fold(f32::INFINITY, f32::min)
+    }
+
+    #[test]
+    fn test_empty_scene_is_surrounding_box() {
+        let polygon = visibility_polygon(Vector2::new(1f32, 2f32), &[]);
+        assert_eq!(polygon.len(), 4);
+        assert!((signed_area(&polygon) - 4f32).abs() < 1E-04f32);
+        for degrees in [0f32, 45f32, 130f32, 200f32, 300f32] {
+            assert!(visible_distance(&polygon, Vector2::new(1f32, 2f32), degrees) >= 1f32 - 1E-04f32);
+        }
+    }
+
+    #[test]
+    fn test_single_wall_casts_shadow() {
+        let wall = (Vector2::new(2f32, -1f32), Vector2::new(2f32, 1f32));
+        let polygon = visibility_polygon(Vector2::zero(), &[wall]);
+        assert!(signed_area(&polygon) > 0f32);
+        assert!((visible_distance(&polygon, Vector2::zero(), 0f32) - 2f32).abs() < 1E-04f32);
+        assert!((visible_distance(&polygon, Vector2::zero(), 20f32) - 2f32 / 20f32.to_radians().cos()).abs() < 1E-03f32);
+        assert!((visible_distance(&polygon, Vector2::zero(), -20f32) - 2f32 / 20f32.to_radians().cos()).abs() < 1E-03f32);
+        // Outside the wall's angular span the view reaches the scene bounds.
+        assert!(visible_distance(&polygon, Vector2::zero(), 35f32) > 2.5f32);
+        assert!(visible_distance(&polygon, Vector2::zero(), 180f32) > 1.5f32);
+    }
+
+    #[test]
+    fn test_shadow_segments() {
+        let light = Vector2::new(1f32, 1f32);
+        let occluders = [(Vector2::new(3f32, 0f32), Vector2::new(3f32, 2f32)), (Vector2::new(0f32, 4f32), Vector2::new(2f32, 4f32))];
+        let quads = shadow_segments(light, &occluders, 10f32);
+        assert_eq!(quads.len(), 2);
+        for quad in &quads {
+            for (near, far) in [(quad[0], quad[3]), (quad[1], quad[2])] {
+                assert!(((far - light).magnitude() - 10f32).abs() < 1E-05f32);
+                assert!(Vector2::cross(near - light, far - light).abs() < 1E-04f32);
+                assert!(Vector2::dot(far - near, near - light) > 0f32);
+            }
+        }
+        assert_eq!(quads[0][0], Vector2::new(3f32, 0f32));
+        assert_eq!(quads[0][1], Vector2::new(3f32, 2f32));
+    }
+
+    #[test]
+    fn test_shadow_segments_touching_light() {
+        assert!(shadow_segments(Vector2::zero(), &[(Vector2::zero(), Vector2::one())], 5f32).is_empty());
+    }
+
+    #[test]
+    fn test_angular_coverage_single_wall() {
+        let wall = (Vector2::new(1f32, -1f32), Vector2::new(1f32, 1f32));
+        let coverage = angular_coverage(Vector2::zero(), &[wall]);
+        // The wall straddles 0°, so it comes back as one range wrapping round through 360°.
+        assert_eq!(coverage.len(), 1);
+        assert!((coverage[0].0 - 315f32).abs() < 1E-04f32 && (coverage[0].1 - 45f32).abs() < 1E-04f32);
+        // Walls on all four sides close the circle.
+        let sides = [Vector2::new(1f32, -1f32), Vector2::one(), Vector2::new(-1f32, 1f32), Vector2::new(-1f32, -1f32)];
+        let around: Vec<(Vector2, Vector2)> = (0..4).map(|i| (sides[i], sides[(i + 1) % 4])).collect();
+        let full = angular_coverage(Vector2::zero(), &around);
+        assert_eq!(full.len(), 1);
+        assert!(full[0].0.abs() < 1E-04f32 && (full[0].1 - 360f32).abs() < 1E-04f32, "{:?}", full);
+        let above = angular_coverage(Vector2::zero(), &[(Vector2::new(-1f32, 1f32), Vector2::new(1f32, 1f32))]);
+        assert_eq!(above.len(), 1);
+        assert!((above[0].0 - 45f32).abs() < 1E-04f32 && (above[0].1 - 135f32).abs() < 1E-04f32);
+    }
+
+    #[test]
+    fn test_angular_coverage_merges() {
+        let walls = [
+            (Vector2::new(-1f32, 1f32), Vector2::new(1f32, 1f32)),
+            (Vector2::new(-2f32, 0.5f32), Vector2::new(-0.5f32, 3f32)),
+            (Vector2::new(-1f32, -2f32), Vector2::new(-1f32, -3f32)),
+            (Vector2::new(2f32, 0f32), Vector2::new(4f32, 0f32)),
+        ];
+        let coverage = angular_coverage(Vector2::zero(), &walls);
+        assert_eq!(coverage.len(), 2);
+        let (start, end) = coverage[0];
+        assert!((start - 45f32).abs() < 1E-04f32);
+        assert!((end - Vector2::new(-2f32, 0.5f32).to_angle()).abs() < 1E-04f32);
+        assert!((coverage[1].0 - Vector2::new(-1f32, -2f32).to_angle().rem_euclid(360f32)).abs() < 1E-04f32);
+        assert!((coverage[1].1 - Vector2::new(-1f32, -3f32).to_angle().rem_euclid(360f32)).abs() < 1E-04f32);
+        assert!(angular_coverage(Vector2::zero(), &[]).is_empty());
+    }
+
+    #[test]
+    fn test_line_of_sight() {
+        let wall = (Vector2::new(2f32, -1f32), Vector2::new(2f32, 1f32));
+        assert!(has_line_of_sight(Vector2::zero(), Vector2::new(4f32, 3f32), &[wall]));
+        assert!(!has_line_of_sight(Vector2::zero(), Vector2::new(4f32, 0f32), &[wall]));
+        assert!(has_line_of_sight(Vector2::zero(), Vector2::new(1.5f32, 0f32), &[wall]));
+        assert!(has_line_of_sight(Vector2::zero(), Vector2::new(4f32, 0f32), &[]));
+    }
+
+    #[test]
+    fn test_line_of_sight_touching_rules() {
+        let wall = (Vector2::new(2f32, -1f32), Vector2::new(2f32, 1f32));
+        // Standing on the wall, or looking at a point on it, is not blocked.
+        assert!(has_line_of_sight(Vector2::new(2f32, 1f32), Vector2::new(0f32, 3f32), &[wall]));
+        assert!(has_line_of_sight(Vector2::zero(), Vector2::new(2f32, 0.5f32), &[wall]));
+        // Grazing the wall's end on the way through is.
+        assert!(!has_line_of_sight(Vector2::new(0f32, 1f32), Vector2::new(4f32, 1f32), &[wall]));
+        // Collinear blockers block only when they overlap the interior of the sight line.
+        let along = (Vector2::new(1f32, 0f32), Vector2::new(3f32, 0f32));
+        assert!(!has_line_of_sight(Vector2::zero(), Vector2::new(2f32, 0f32), &[along]));
+        assert!(has_line_of_sight(Vector2::new(3f32, 0f32), Vector2::new(5f32, 0f32), &[along]));
+    }
+}
@@ -0,0 +1,163 @@
+use super::delaunay::delaunay;
+use super::fitting::circumcircle;
+use super::rect::Rect;
+use super::vector2::Vector2;
+
+/// Centre within `bounds` that is farthest from every point, with that distance. Candidates are
+/// the Voronoi vertices inside `bounds`, the Voronoi edges' crossings with its sides and its
+/// corners. The circle may extend past `bounds`; only its centre is held inside. With no points
+/// the centre of `bounds` comes back with an infinite radius.
+pub fn largest_empty_circle(points: &[Vector2], bounds: Rect) -> (Vector2, f32) {
+    if points.is_empty() {
+        return (bounds.center(), f32::INFINITY);
+    }
+    let triangles = delaunay(points);
+    let mut candidates = vec![bounds.min(), bounds.max(), Vector2::new(bounds.x_min(), bounds.y_max()), Vector2::new(bounds.x_max(), bounds.y_min())];
+    let mut pairs = Vec::new();
+    for &[a, b, c] in &triangles {
+        if let Some((center, _)) = circumcircle(points[a], points[b], points[c]) {
+            candidates.push(center);
+        }
+        pairs.extend([(a, b), (b, c), (c, a)]);
+    }
+    if triangles.is_empty() {
+        pairs = (0..points.len()).flat_map(|i| (i + 1..points.len()).map(move |j| (i, j))).collect();
+    }
+    for (i, j) in pairs {
+        let (midpoint, normal) = ((points[i] + points[j]) * 0.5f32, points[j] - points[i]);
+        // The bisector is every x with dot(x - midpoint, normal) = 0; cut it with each side.
+        for axis in 0..2 {
+            let other = 1 - axis;
+            if normal[other] == 0f32 {
+                continue;
+            }
+            for side in [bounds.min()[axis], bounds.max()[axis]] {
+                let mut crossing = Vector2::zero();
+                crossing[axis] = side;
+                crossing[other] = midpoint[other] - (side - midpoint[axis]) * normal[axis] / normal[other];
+                candidates.push(crossing);
+            }
+        }
+    }
+    candidates
+        .into_iter()
+        .filter(|&c| bounds.contains(c))
+        .map(|c| (c, points.iter().map(|&p| (p - c).magnitude()).fold(f32::INFINITY, f32::min)))
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .unwrap()
+}
+
+/// Power-diagram cell of `sites[site_index]` clipped to `bounds`, counter-clockwise. A point
+/// belongs to the site minimising `|x - site|^2 - weight`, so weights act as squared radii and
+/// equal weights give the ordinary Voronoi cell. A site dominated everywhere in `bounds` gets
+/// an empty cell.
+pub fn power_cell(site_index: usize, sites: &[Vector2], weights: &[f32], bounds: Rect) -> Vec<Vector2> {
+    assert_eq!(sites.len(), weights.len(), "one weight per site");
+    let mut cell = vec![bounds.min(), Vector2::new(bounds.x_max(), bounds.y_min()), bounds.max(), Vector2::new(bounds.x_min(), bounds.y_max())];
+    let (site, weight) = (sites[site_index], weights[site_index]);
+    for (j, (&other, &other_weight)) in sites.iter().zip(weights).enumerate() {
+        if j == site_index || cell.is_empty() {
+            continue;
+        }
+        // Keep x with dot(x, normal) <= offset, the side where this site's power is lower.
+        let normal = (other - site) * 2f32;
+        let offset = other.sqr_magnitude() - site.sqr_magnitude() - (other_weight - weight);
+        cell = clip_half_plane(&cell, normal, offset);
+    }
+    cell
+}
+
+/// Part of a convex polygon where `dot(p, normal) <= offset`.
+fn clip_half_plane(polygon: &[Vector2], normal: Vector2, offset: f32) -> Vec<Vector2> {
+    let side = |p: Vector2| offset - Vector2::dot(p, normal);
+    let mut output = Vec::with_capacity(polygon.len() + 1);
+    for (k, &current) in polygon.iter().enumerate() {
+        let previous = polygon[(k + polygon.len() - 1) % polygon.len()];
+        let (side_current, side_previous) = (side(current), side(previous));
+        if (side_current >= 0f32) != (side_previous >= 0f32) {
+            output.push(Vector2::lerp_unclamped(previous, current, side_previous / (side_previous - side_current)));
+        }
+        if side_current >= 0f32 {
+            output.push(current);
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::polygon::{point_in_polygon, signed_area};
+    use crate::algorithms::test_support::Lcg;
+
+    #[test]
+    fn test_largest_empty_circle_square_corners() {
+        let bounds = Rect::new(0f32, 0f32, 4f32, 4f32);
+        let corners = [bounds.min(), Vector2::new(4f32, 0f32), bounds.max(), Vector2::new(0f32, 4f32)];
+        let (center, radius) = largest_empty_circle(&corners, bounds);
+        assert!((center - Vector2::new(2f32, 2f32)).magnitude() < 1E-05f32);
+        assert!((radius - 8f32.sqrt()).abs() < 1E-05f32);
+    }
+
+    #[test]
+    fn test_largest_empty_circle_boundary_and_degenerate() {
+        let bounds = Rect::new(0f32, 0f32, 10f32, 2f32);
+        // Two points near the left end leave the best spot on the right side.
+        let (center, radius) = largest_empty_circle(&[Vector2::new(1f32, 1f32), Vector2::new(2f32, 1f32)], bounds);
+        assert!(center.x == 10f32 && (radius - (64f32 + 1f32).sqrt()).abs() < 1E-04f32);
+        // A lone point has no bisectors, so only the corners are candidates.
+        let (center, radius) = largest_empty_circle(&[Vector2::new(5f32, 1f32)], bounds);
+        assert!(center.x == 0f32 || center.x == 10f32);
+        assert!((radius - 26f32.sqrt()).abs() < 1E-05f32);
+        assert_eq!(largest_empty_circle(&[], bounds), (Vector2::new(5f32, 1f32), f32::INFINITY));
+    }
+
+    #[test]
+    fn test_largest_empty_circle_is_empty() {
+        let mut rng = Lcg(9);
+        let mut next = || rng.next() * 10f32;
+        let points: Vec<Vector2> = (0..30).map(|_| Vector2::new(next(), next())).collect();
+        let bounds = Rect::new(0f32, 0f32, 10f32, 10f32);
+        let (center, radius) = largest_empty_circle(&points, bounds);
+        assert!(bounds.contains(center));
+        assert!(points.iter().all(|&p| (p - center).magnitude() >= radius - 1E-04f32));
+        // No sampled centre does better.
+        for y in 0..=50 {
+            for x in 0..=50 {
+                let c = Vector2::new(x as f32 * 0.2f32, y as f32 * 0.2f32);
+                let nearest = points.iter().map(|&p| (p - c).magnitude()).fold(f32::INFINITY, f32::min);
+                assert!(nearest <= radius + 1E-04f32);
+            }
+        }
+    }
+
+    #[test]
+    fn test_power_cell_equal_weights_is_voronoi() {
+        let sites = [Vector2::new(1f32, 1f32), Vector2::new(3f32, 1.5f32), Vector2::new(2f32, 3.5f32), Vector2::new(0.5f32, 3f32)];
+        let bounds = Rect::new(0f32, 0f32, 4f32, 4f32);
+        let cells: Vec<Vec<Vector2>> = (0..4).map(|i| power_cell(i, &sites, &[0.7f32; 4], bounds)).collect();
+        let total: f32 = cells.iter().map(|c| signed_area(c)).sum();
+        assert!((total - 16f32).abs() < 1E-04f32);
+        for y in 0..20 {
+            for x in 0..20 {
+                let p = Vector2::new(x as f32 * 0.2f32 + 0.1f32, y as f32 * 0.2f32 + 0.1f32);
+                let nearest = (0..4).min_by(|&a, &b| (sites[a] - p).magnitude().total_cmp(&(sites[b] - p).magnitude())).unwrap();
+                assert!(point_in_polygon(p, &cells[nearest]));
+            }
+        }
+        let two = power_cell(0, &sites[..2], &[0f32, 0f32], bounds);
+        assert!(two.iter().all(|&p| (p - sites[0]).magnitude() <= (p - sites[1]).magnitude() + 1E-05f32));
+    }
+
+    #[test]
+    fn test_power_cell_weight_grows_cell() {
+        let sites = [Vector2::new(1f32, 2f32), Vector2::new(3f32, 2f32)];
+        let bounds = Rect::new(0f32, 0f32, 4f32, 4f32);
+        let even = power_cell(0, &sites, &[1f32, 1f32], bounds);
+        assert!((signed_area(&even) - 8f32).abs() < 1E-05f32);
+        // Raising the weight by 2 moves the dividing line half a unit toward the other site.
+        let heavier = power_cell(0, &sites, &[3f32, 1f32], bounds);
+        assert!((signed_area(&heavier) - 10f32).abs() < 1E-05f32);
+        assert!(power_cell(0, &sites, &[-20f32, 1f32], bounds).is_empty());
+    }
+}
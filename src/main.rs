@@ -1,5 +1,3 @@
-mod algorithms;
-
 fn main() {
     println!("Hello, world!");
 }